@@ -65,14 +65,14 @@ pub fn map_item() -> impl Parser<Out = (String, Value)> {
 pub fn p_value<'a>(it: &LCChars<'a>) -> ParseRes<'a, Value> {
     let p = or6(
         "null".map(|_| Value::Null),
-        common::Bool.map(|b| Value::Bool(b)),
+        common::Bool.map(Value::Bool),
         or(
-            common::Float.map(|f| Value::Num(f)),
+            common::Float.map(Value::Num),
             common::Int.map(|i| Value::Num(i as f64)),
         ),
-        json_string().map(|s| Value::Str(s)),
+        json_string().map(Value::Str),
         "[".ig_then(sep_until_ig(wsn_(p_value), ",", "]"))
-            .map(|a| Value::Array(a)),
+            .map(Value::Array),
         "{".ig_then(sep_until_ig(wsn_(map_item()), ",", "}"))
             .map(|a| {
                 let mut m = HashMap::new();
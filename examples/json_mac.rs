@@ -64,14 +64,14 @@ parser!(
     (JsonValue->Value)
     or!(
         "null".map(|_| Value::Null),
-        common::Bool.map(|b| Value::Bool(b)),
+        common::Bool.map(Value::Bool),
         or(
-            common::Float.map(|f| Value::Num(f)),
+            common::Float.map(Value::Num),
             common::Int.map(|i| Value::Num(i as f64)),
         ),
-        JsonString.map(|s| Value::Str(s)),
+        JsonString.map(Value::Str),
         "[".ig_then(sep_until_ig(wsn_(JsonValue), ",", "]"))
-            .map(|a| Value::Array(a)),
+            .map(Value::Array),
         "{".ig_then(sep_until_ig(wsn_(MapItem), ",", "}")).map(|a| {
             let mut m = HashMap::new();
             for (k, v) in a {
@@ -0,0 +1,100 @@
+//! Compares parsing deeply nested json with and without memo
+//! run with "cargo run --release --example memo_bench -- 20"
+//!
+//! The array rule here reads "values followed by commas" and then a last value.
+//! The last value in each array is parsed once by the star, which fails on the missing comma,
+//! and then again as the last value, so every level of nesting doubles the work.
+use gobble::*;
+use std::time::Instant;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Num(f64),
+    Str(String),
+    Array(Vec<Value>),
+}
+
+fn wsn_<P: Parser>(p: P) -> impl Parser<Out = P::Out> {
+    middle(WSL.istar(), p, WSL.istar())
+}
+
+parser! {
+    (Array->Value)
+    or(
+        ("[", star(first(Plain, ",")), Plain, "]").map(|(_, mut v, l, _)| {
+            v.push(l);
+            Value::Array(v)
+        }),
+        ("[", wsn_("]")).map(|_| Value::Array(Vec::new())),
+    )
+}
+
+parser! {
+    (Plain->Value)
+    wsn_(or!(
+        "null".map(|_| Value::Null),
+        common::Float.map(Value::Num),
+        common::Int.map(|i| Value::Num(i as f64)),
+        common::Quoted.map(Value::Str),
+        Array,
+    ))
+}
+
+parser! {
+    (MArray->Value)
+    or(
+        ("[", star(first(Memoed, ",")), Memoed, "]").map(|(_, mut v, l, _)| {
+            v.push(l);
+            Value::Array(v)
+        }),
+        ("[", wsn_("]")).map(|_| Value::Array(Vec::new())),
+    )
+}
+
+parser! {
+    (Memoed->Value)
+    memo(MValue)
+}
+
+parser! {
+    (MValue->Value)
+    wsn_(or!(
+        "null".map(|_| Value::Null),
+        common::Float.map(Value::Num),
+        common::Int.map(|i| Value::Num(i as f64)),
+        common::Quoted.map(Value::Str),
+        MArray,
+    ))
+}
+
+fn nested(depth: usize) -> String {
+    let mut s = String::new();
+    for i in 0..depth {
+        s.push_str(&format!("[{}, \"a\", ", i));
+    }
+    s.push_str("null");
+    for _ in 0..depth {
+        s.push(']');
+    }
+    s
+}
+
+fn main() {
+    let depth = std::env::args()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(18);
+    let s = nested(depth);
+
+    let t = Instant::now();
+    let plain = Plain.parse_s(&s).unwrap();
+    println!("depth {}: plain  {:?}", depth, t.elapsed());
+
+    let t = Instant::now();
+    let sess = MemoSession::new();
+    let memoed = sess.parse_s(&Memoed, &s).unwrap();
+    println!("depth {}: memo   {:?}", depth, t.elapsed());
+
+    assert_eq!(plain, memoed);
+}
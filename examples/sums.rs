@@ -35,21 +35,30 @@ pub enum Expr {
 //   })
 // }
 
+impl InfixOp<Expr> for Op {
+    fn power(&self) -> (usize, Assoc) {
+        match self {
+            Op::Add | Op::Sub => (1, Assoc::Left),
+            Op::Mul | Op::Div => (2, Assoc::Left),
+        }
+    }
+    fn build(self, l: Expr, r: Expr) -> Expr {
+        Expr::Oper(self, Box::new(l), Box::new(r))
+    }
+}
+
 parser! {
     (LtExpr->Expr)
     or(
-        common::Int.map(|i| Expr::Val(i)),
+        common::Int.map(Expr::Val),
         middle("(", RtExpr, ")").map(|e| Expr::Parenth(Box::new(e)))
     )
 }
 
 parser! {
     (RtExpr->Expr)
-    (LtExpr, maybe((OP, RtExpr))).map(|(l, opt)| match opt {
-        //Note this cares nothing for operation priority except for brackets
-        Some((oper, r)) => Expr::Oper(oper, Box::new(l), Box::new(r)),
-        None => l,
-    })
+    //Multiplication and division bind tighter than addition and subtraction
+    prec_climb(LtExpr, OP)
 }
 
 /// This loops asking the user for input of simple sum type "4 + 6 * (3-1)"
@@ -0,0 +1,152 @@
+//! Parsers for binary data, which work on byte slice input (```[u8]```).
+//!
+//! Numbers come in big and little endian forms, named like ```U32BE``` and ```U32LE```.
+//! Single bytes have no order, so are just ```U8``` and ```I8```.
+//!
+//! Fields that start with a length can be parsed with ```counted``` for a number of items,
+//! or with ```then_with``` to build the next parser from the length.
+//!
+//! ```rust
+//! use gobble::*;
+//! let field = (binary::U8, binary::U16LE.then_with(|n| take(*n as usize)));
+//! let (tag, (len, body)) = field.parse_ref_s(&[7, 2, 0, b'h', b'i'][..]).unwrap();
+//! assert_eq!((tag, len), (7, 2));
+//! assert_eq!(body, b"hi");
+//!
+//! let e = binary::U32BE.parse_s(&[0, 1][..]).unwrap_err();
+//! assert_eq!(e.exp.sentence(), "big endian u32");
+//! ```
+use crate::err::{Expected, PErr};
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+
+/// Reads the next N bytes, or fails at the start if there are not enough
+fn read_n<'a, const N: usize>(
+    it: &LCChars<'a, [u8]>,
+    exp: Expected,
+) -> Result<(LCChars<'a, [u8]>, [u8; N]), PErr<'a>> {
+    let mut res = [0; N];
+    let mut nit = it.clone();
+    for b in res.iter_mut() {
+        *b = match nit.next() {
+            Some(v) => v,
+            None => return it.err_r(exp),
+        };
+    }
+    Ok((nit, res))
+}
+
+macro_rules! bin_num {
+    ($id:ident, $t:ty, $from:ident, $name:literal) => {
+        #[doc = concat!("Parses a ", $name)]
+        #[derive(Copy, Clone)]
+        pub struct $id;
+
+        impl $crate::borrow::Owned for $id {}
+
+        impl Parser<[u8]> for $id {
+            type Out = $t;
+            fn parse<'a>(&self, it: &LCChars<'a, [u8]>) -> ParseRes<'a, $t, [u8]> {
+                let (nit, b) = read_n(it, self.expected())?;
+                Ok((nit, <$t>::$from(b), None))
+            }
+            fn expected(&self) -> Expected {
+                Expected::Named($name)
+            }
+        }
+    };
+}
+
+bin_num!(U8, u8, from_be_bytes, "u8");
+bin_num!(I8, i8, from_be_bytes, "i8");
+bin_num!(U16BE, u16, from_be_bytes, "big endian u16");
+bin_num!(U16LE, u16, from_le_bytes, "little endian u16");
+bin_num!(I16BE, i16, from_be_bytes, "big endian i16");
+bin_num!(I16LE, i16, from_le_bytes, "little endian i16");
+bin_num!(U32BE, u32, from_be_bytes, "big endian u32");
+bin_num!(U32LE, u32, from_le_bytes, "little endian u32");
+bin_num!(I32BE, i32, from_be_bytes, "big endian i32");
+bin_num!(I32LE, i32, from_le_bytes, "little endian i32");
+bin_num!(U64BE, u64, from_be_bytes, "big endian u64");
+bin_num!(U64LE, u64, from_le_bytes, "little endian u64");
+bin_num!(I64BE, i64, from_be_bytes, "big endian i64");
+bin_num!(I64LE, i64, from_le_bytes, "little endian i64");
+bin_num!(F32BE, f32, from_be_bytes, "big endian f32");
+bin_num!(F32LE, f32, from_le_bytes, "little endian f32");
+bin_num!(F64BE, f64, from_be_bytes, "big endian f64");
+bin_num!(F64LE, f64, from_le_bytes, "little endian f64");
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Tlv {
+        Int(i32),
+        Text(String),
+        Float(f64),
+        List(Vec<Tlv>),
+    }
+
+    fn encode(t: &Tlv, res: &mut Vec<u8>) {
+        match t {
+            Tlv::Int(n) => {
+                res.push(1);
+                res.extend(&n.to_be_bytes());
+            }
+            Tlv::Text(s) => {
+                res.push(2);
+                res.extend(&(s.len() as u16).to_le_bytes());
+                res.extend(s.as_bytes());
+            }
+            Tlv::Float(f) => {
+                res.push(3);
+                res.extend(&f.to_le_bytes());
+            }
+            Tlv::List(v) => {
+                res.push(4);
+                res.push(v.len() as u8);
+                for t in v {
+                    encode(t, res);
+                }
+            }
+        }
+    }
+
+    fn tlv<'a>(it: &LCChars<'a, [u8]>) -> ParseRes<'a, Tlv, [u8]> {
+        or!(
+            tok(1).ig_then(binary::I32BE).map(Tlv::Int),
+            tok(2)
+                .ig_then(binary::U16LE.then_with(|n| {
+                    take(*n as usize).map_ref(|b| std::str::from_utf8(b).map(str::to_string))
+                }))
+                .try_map(|(_, r)| r.map(Tlv::Text).map_err(|_| Expected::Named("utf8"))),
+            tok(3).ig_then(binary::F64LE).map(Tlv::Float),
+            tok(4).ig_then(counted(binary::U8, tlv)).map(Tlv::List),
+        )
+        .parse(it)
+    }
+
+    #[test]
+    fn test_tlv_round_trip() {
+        let v = Tlv::List(vec![
+            Tlv::Int(-40000),
+            Tlv::Text("hello".to_string()),
+            Tlv::List(vec![Tlv::Float(2.5), Tlv::List(Vec::new())]),
+            Tlv::Text(String::new()),
+        ]);
+        let mut b = Vec::new();
+        encode(&v, &mut b);
+        assert_eq!(first(tlv, eoi).parse_s(&b), Ok(v));
+    }
+
+    #[test]
+    fn test_tlv_short_input() {
+        let mut b = Vec::new();
+        encode(&Tlv::Text("hello".to_string()), &mut b);
+        b.pop();
+        let e = tlv.parse_s(&b).unwrap_err();
+        assert_eq!(e.index, Some(3));
+        assert_eq!(e.exp, Expected::Named("more items"));
+    }
+}
@@ -0,0 +1,246 @@
+//! Parsers whose results borrow from the input rather than allocating.
+//!
+//! ```Parser::Out``` cannot depend on the lifetime of the input, so char parsers like ```star```
+//! and ```string``` have to build a new ```String```. ```RefParser``` has an output type
+//! for each input lifetime, so ```Alpha.star_str()``` and ```string_ref(p)``` can return
+//! ```&'a str``` slices of the input instead.
+//!
+//! The combinators that arrange other parsers (tuples, ```or```, ```maybe```, ```first```,
+//! ```middle```, ```star```, ```sep_until``` and the like) are ```RefParser```s when their parts
+//! are, and the other parsers in this crate are ```RefParser```s through ```Owned```, so they mix
+//! freely. ```parser!``` makes borrowing rules when given a lifetime, as in ```(Key<'a>->&'a str)```.
+//! ```map_ref``` turns the borrowed result into a value, giving back a normal ```Parser```.
+//!
+//! ```rust
+//! use gobble::*;
+//! let src = "count = 42;";
+//! let p = Alpha.plus_str().then_ref(ws__("=")).then_ref(NumDigit.plus_str());
+//! let ((k, _), v) = p.parse_ref_s(src).unwrap();
+//! assert_eq!((k, v), ("count", "42"));
+//! //The results point into the source
+//! assert_eq!(k.as_ptr(), src.as_ptr());
+//!
+//! let n = NumDigit.plus_str().map_ref(|s| s.len());
+//! assert_eq!(n.parse_s("12345a"), Ok(5));
+//!
+//! parser! { (Key<'a>->&'a str) or(Alpha.plus_str(), middle("\"", (Alpha, ' ').star_str(), "\"")) }
+//! let list = sep_until(first(Key, ws__("=")).then_ref(maybe(common::Int)), ",", ";");
+//! let (v, _) = list.parse_ref_s("a=1,\"b c\"=,d=3;").unwrap();
+//! assert_eq!(v, vec![("a", Some(1)), ("b c", None), ("d", Some(3))]);
+//! ```
+use crate::err::{Expected, PErr};
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+use std::marker::PhantomData;
+
+/// A parser whose output may borrow from the input for the lifetime 'a
+pub trait RefParser<I: ?Sized + Input = str>: Sized {
+    type Out<'a>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I>;
+
+    /// Named to avoid clashing with ```Parser::expected``` as every str parser is both
+    fn expected_ref(&self) -> Expected;
+
+    fn parse_ref_s<'a>(&self, s: &'a I) -> Result<Self::Out<'a>, PErr<'a>> {
+        self.parse_ref(&LCChars::new(s)).map(|(_, v, _)| v)
+    }
+
+    fn then_ref<B: RefParser<I>>(self, b: B) -> RefThen<Self, B> {
+        RefThen { a: self, b }
+    }
+
+    /// Turns the borrowed output into an owned one, the result is a normal ```Parser```
+    fn map_ref<V, F: for<'a> Fn(Self::Out<'a>) -> V>(self, f: F) -> MapRef<Self, V, F> {
+        MapRef {
+            a: self,
+            f,
+            v: PhantomData,
+        }
+    }
+}
+
+/// A ```Parser``` whose output never borrows from the input, which makes it a ```RefParser```
+/// too. The parsers in this crate, and rules made by ```parser!```, already implement it.
+/// Implement it for your own ```Parser```s to use them alongside borrowing parsers.
+/// Functions used as parsers cannot be marked, so wrap them in a ```parser!``` rule first
+pub trait Owned {}
+
+impl<I: ?Sized + Input, P: Parser<I> + Owned> RefParser<I> for P {
+    type Out<'a>
+        = P::Out
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        self.parse(it)
+    }
+    fn expected_ref(&self) -> Expected {
+        self.expected()
+    }
+}
+
+/// Lets combinators run any ```Parser``` through the code they share with their ```RefParser```
+/// form, whether or not it is ```Owned```
+pub(crate) struct ByRef<'p, P>(pub(crate) &'p P);
+
+impl<'p, I: ?Sized + Input, P: Parser<I>> RefParser<I> for ByRef<'p, P> {
+    type Out<'a>
+        = P::Out
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        self.0.parse(it)
+    }
+    fn expected_ref(&self) -> Expected {
+        self.0.expected()
+    }
+}
+
+#[derive(Clone)]
+pub struct RefThen<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<I, A, B> RefParser<I> for RefThen<A, B>
+where
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+{
+    type Out<'a>
+        = (A::Out<'a>, B::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (i, v1, c1) = self.a.parse_ref(i)?;
+        let (i, v2, e) = self.b.parse_ref(&i).map_err(|e| e.join_op(c1))?;
+        Ok((i, (v1, v2), e))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.a.expected_ref(), self.b.expected_ref())
+    }
+}
+
+#[derive(Clone)]
+pub struct MapRef<A, V, F> {
+    a: A,
+    f: F,
+    v: PhantomData<fn() -> V>,
+}
+
+impl<A, V, F> Owned for MapRef<A, V, F> {}
+
+impl<I, A, V, F> Parser<I> for MapRef<A, V, F>
+where
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    F: for<'a> Fn(A::Out<'a>) -> V,
+{
+    type Out = V;
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, V, I> {
+        let (ri, v, ex) = self.a.parse_ref(i)?;
+        Ok((ri, (self.f)(v), ex))
+    }
+    fn expected(&self) -> Expected {
+        self.a.expected_ref()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[derive(Debug, PartialEq)]
+    pub struct Entry<'a> {
+        key: &'a str,
+        val: &'a str,
+    }
+
+    fn entry<'a>(it: &LCChars<'a>) -> ParseRes<'a, Entry<'a>> {
+        let p = (Alpha, NumDigit, '_')
+            .plus_str()
+            .then_ref(ws__(":"))
+            .then_ref(string_ref((Alpha, NumDigit, '.').iplus()))
+            .then_ref(";");
+        let (nit, (((key, _), val), _), c) = p.parse_ref(it)?;
+        Ok((nit, Entry { key, val }, c))
+    }
+
+    #[test]
+    fn test_borrowed_outputs_point_into_input() {
+        let src = "host_1:web.example;port: 8080;";
+        let (it, e1, _) = entry(&LCChars::str(src)).unwrap();
+        let (_, e2, _) = entry(&it).unwrap();
+        assert_eq!(
+            e1,
+            Entry {
+                key: "host_1",
+                val: "web.example"
+            }
+        );
+        assert_eq!(e1.key.as_ptr(), src.as_ptr());
+        assert_eq!(e2.val, "8080");
+        assert_eq!(e2.val.as_ptr(), src[25..].as_ptr());
+    }
+
+    parser! { (Atom<'a>->&'a str) or!("nil", Alpha.plus_str(), middle('"', Any.except("\"").star_str(), '"')) }
+
+    #[test]
+    fn test_combinators_take_ref_parsers() {
+        let src = r#"(a "b c" nil)"#;
+        let list = middle("(", star(ws_(Atom)), ws_(")"));
+        let v = list.parse_ref_s(src).unwrap();
+        assert_eq!(v, vec!["a", "b c", "nil"]);
+        assert_eq!(v[1].as_ptr(), src[4..].as_ptr());
+
+        let pair = (
+            Atom,
+            maybe(last(":", NumDigit.plus_str())),
+            plus_until_ig(";", EOI),
+        );
+        assert_eq!(
+            pair.parse_ref_s("x:12;;"),
+            Ok(("x", Some("12"), vec![";", ";"]))
+        );
+        assert_eq!(
+            sep_until_ig(first(Atom, WS.istar()), ",", ".").parse_ref_s("p ,q ."),
+            Ok(vec!["p", "q"])
+        );
+
+        let e = Atom.parse_ref_s(r#""b"#).unwrap_err();
+        assert_eq!(e.index, None);
+        assert_eq!(*e.context, ["Atom"]);
+    }
+
+    #[test]
+    fn test_borrowed_matches_allocating() {
+        for s in ["", "abc", "ab12", "héllo", "12"] {
+            assert_eq!(
+                Alpha.star_str().parse_ref_s(s).map(str::to_string),
+                Alpha.star().parse_s(s)
+            );
+            assert_eq!(
+                Alpha.plus_str().parse_ref_s(s).map(str::to_string),
+                Alpha.plus().parse_s(s)
+            );
+            assert_eq!(
+                string_ref(Alpha.exact(2))
+                    .parse_ref_s(s)
+                    .map(str::to_string),
+                string(Alpha.exact(2)).parse_s(s)
+            );
+        }
+        assert_eq!(Any.exact_str(2).parse_ref_s("héllo"), Ok("hé"));
+        assert_eq!(
+            Alpha.min_n_str(3).parse_ref_s("ab1").unwrap_err().index,
+            Some(2)
+        );
+        assert_eq!(
+            string_ref(tok(1).then(tok(2))).parse_ref_s(&[1, 2, 3][..]),
+            Ok(&[1, 2][..])
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Parsers behind a pointer, for grammars that are only known at runtime.
+//!
+//! Every combinator is its own generic type, which is fast, but means parsers of different
+//! shapes cannot be stored together, or picked between at runtime. ```boxed``` and ```rc```
+//! hide the type behind ```DynParser```, leaving only the output type, so they can be kept in a
+//! ```Vec``` and tried in turn with ```choice```.
+//!
+//! ```rust
+//! use gobble::*;
+//! #[derive(Debug, PartialEq)]
+//! enum Cmd {
+//!     Go(isize),
+//!     Say(String),
+//! }
+//!
+//! let mut commands: Vec<BoxedParser<Cmd>> = Vec::new();
+//! commands.push("go ".ig_then(common::Int).map(Cmd::Go).boxed());
+//! commands.push("say ".ig_then(Any.plus()).map(Cmd::Say).boxed());
+//!
+//! let p = choice(commands);
+//! assert_eq!(p.parse_s("go 3"), Ok(Cmd::Go(3)));
+//! assert_eq!(p.parse_s("say hi"), Ok(Cmd::Say("hi".to_string())));
+//! assert!(p.parse_s("jump").is_err());
+//! ```
+//!
+//! ```recursive``` builds a grammar that refers to itself, without a ```parser!``` rule.
+//! It is given a name, for when the grammar is described.
+//!
+//! ```rust
+//! use gobble::*;
+//! let depth = recursive("Depth", |inner| {
+//!     or(middle("(", inner, ")").map(|n: usize| n + 1), "x".asv(0))
+//! });
+//! assert_eq!(depth.parse_s("((x))"), Ok(2));
+//! assert_eq!(Grammar::of(&depth).to_ebnf(), "Depth ::= \"(\" Depth \")\" | \"x\"\n");
+//! ```
+use crate::borrow::Owned;
+use crate::describe::{Gram, RuleRef};
+use crate::err::Expected;
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/// The object safe part of ```Parser```, implemented for every parser.
+/// Needed as ```Parser``` requires Sized so its combinators can take self by value
+pub trait DynParser<I: ?Sized + Input = str> {
+    type Out;
+    fn dyn_parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I>;
+    fn dyn_expected(&self) -> Expected;
+    fn dyn_describe(&self) -> Gram;
+}
+
+impl<I: ?Sized + Input, P: Parser<I>> DynParser<I> for P {
+    type Out = P::Out;
+    fn dyn_parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        self.parse(it)
+    }
+    fn dyn_expected(&self) -> Expected {
+        self.expected()
+    }
+    fn dyn_describe(&self) -> Gram {
+        self.describe()
+    }
+}
+
+/// A parser of any type that outputs 'O', made with ```Parser::boxed```
+pub struct BoxedParser<'p, O, I: ?Sized + Input = str> {
+    p: Box<dyn DynParser<I, Out = O> + 'p>,
+}
+
+impl<'p, O, I: ?Sized + Input> BoxedParser<'p, O, I> {
+    pub fn new<P: Parser<I, Out = O> + 'p>(p: P) -> Self {
+        BoxedParser { p: Box::new(p) }
+    }
+}
+
+impl<'p, O, I: ?Sized + Input> Owned for BoxedParser<'p, O, I> {}
+
+impl<'p, O, I: ?Sized + Input> Parser<I> for BoxedParser<'p, O, I> {
+    type Out = O;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, O, I> {
+        self.p.dyn_parse(it)
+    }
+    fn expected(&self) -> Expected {
+        self.p.dyn_expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.dyn_describe()
+    }
+}
+
+/// Like ```BoxedParser```, but shared, so it can be cloned into several places in a grammar
+pub struct RcParser<'p, O, I: ?Sized + Input = str> {
+    p: Rc<dyn DynParser<I, Out = O> + 'p>,
+}
+
+impl<'p, O, I: ?Sized + Input> RcParser<'p, O, I> {
+    pub fn new<P: Parser<I, Out = O> + 'p>(p: P) -> Self {
+        RcParser { p: Rc::new(p) }
+    }
+}
+
+impl<'p, O, I: ?Sized + Input> Clone for RcParser<'p, O, I> {
+    fn clone(&self) -> Self {
+        RcParser { p: self.p.clone() }
+    }
+}
+
+impl<'p, O, I: ?Sized + Input> Owned for RcParser<'p, O, I> {}
+
+impl<'p, O, I: ?Sized + Input> Parser<I> for RcParser<'p, O, I> {
+    type Out = O;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, O, I> {
+        self.p.dyn_parse(it)
+    }
+    fn expected(&self) -> Expected {
+        self.p.dyn_expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.dyn_describe()
+    }
+}
+
+/// The grammar's reference to itself inside ```recursive```.
+/// It does not keep the grammar alive, so there is no reference cycle to leak
+pub struct WeakParser<'p, O, I: ?Sized + Input = str> {
+    p: Weak<dyn DynParser<I, Out = O> + 'p>,
+}
+
+impl<'p, O, I: ?Sized + Input> Clone for WeakParser<'p, O, I> {
+    fn clone(&self) -> Self {
+        WeakParser { p: self.p.clone() }
+    }
+}
+
+impl<'p, O, I: ?Sized + Input> Owned for WeakParser<'p, O, I> {}
+
+impl<'p, O, I: ?Sized + Input> Parser<I> for WeakParser<'p, O, I> {
+    type Out = O;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, O, I> {
+        match self.p.upgrade() {
+            Some(p) => p.dyn_parse(it),
+            None => it.err_r(Expected::Named("a recursive parser that still exists")),
+        }
+    }
+    fn expected(&self) -> Expected {
+        match self.p.upgrade() {
+            Some(p) => p.dyn_expected(),
+            None => Expected::Named("a recursive parser that still exists"),
+        }
+    }
+    /// A reference to the rule by its name
+    fn describe(&self) -> Gram {
+        match self.p.upgrade() {
+            Some(p) => p.dyn_describe(),
+            None => Gram::Other("a recursive parser that still exists".to_string()),
+        }
+    }
+}
+
+thread_local! {
+    /// The ```recursive``` grammars being described, or asked what they expect, by address
+    static ENTERED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks the grammar at 'addr' as entered until dropped, even if the caller panics.
+/// None if it is already entered, as the grammar has reached itself
+struct EnterGuard;
+
+impl EnterGuard {
+    fn enter(addr: usize) -> Option<Self> {
+        ENTERED.with(|e| {
+            let mut e = e.borrow_mut();
+            if e.contains(&addr) {
+                return None;
+            }
+            e.push(addr);
+            Some(EnterGuard)
+        })
+    }
+}
+
+impl Drop for EnterGuard {
+    fn drop(&mut self) {
+        ENTERED.with(|e| e.borrow_mut().pop());
+    }
+}
+
+/// The grammar built by ```recursive```, which describes itself as a rule
+struct Recursive<P> {
+    name: &'static str,
+    p: P,
+}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for Recursive<P> {
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        self.p.parse(it)
+    }
+    /// Inside itself, the grammar is only expected by name
+    fn expected(&self) -> Expected {
+        match EnterGuard::enter(self as *const Self as usize) {
+            Some(_guard) => self.p.expected(),
+            None => Expected::Named(self.name),
+        }
+    }
+    /// The definition goes on the outermost reference, those inside it only refer back
+    fn describe(&self) -> Gram {
+        let addr = self as *const Self as usize;
+        match EnterGuard::enter(addr) {
+            Some(_guard) => Gram::Rule(RuleRef::built(self.name, addr, Some(self.p.describe()))),
+            None => Gram::Rule(RuleRef::built(self.name, addr, None)),
+        }
+    }
+}
+
+/// Builds a parser that can refer to itself. 'f' is given the parser being built,
+/// to use wherever the grammar recurses. When described, the grammar is a rule called 'name'
+pub fn recursive<'p, I, P, F>(name: &'static str, f: F) -> RcParser<'p, P::Out, I>
+where
+    I: ?Sized + Input + 'p,
+    P: Parser<I> + 'p,
+    F: FnOnce(WeakParser<'p, P::Out, I>) -> P,
+{
+    let rc = Rc::new_cyclic(|w: &Weak<Recursive<P>>| {
+        let p: Weak<dyn DynParser<I, Out = P::Out> + 'p> = w.clone();
+        Recursive {
+            name,
+            p: f(WeakParser { p }),
+        }
+    });
+    RcParser { p: rc }
+}
+
+/// Tries each parser in turn, like ```or``` over a list only known at runtime.
+/// Usually used with ```BoxedParser``` so the parsers can be of different types
+pub struct Choice<P> {
+    ps: Vec<P>,
+}
+
+impl<P> Owned for Choice<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for Choice<P> {
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        let mut err = None;
+        for p in &self.ps {
+            match p.parse(it) {
+                Ok(r) => return Ok(r),
+                Err(e) if e.is_brk => return Err(e),
+                Err(e) => {
+                    err = Some(match err {
+                        Some(pe) => e.longer(pe),
+                        None => e,
+                    })
+                }
+            }
+        }
+        match err {
+            Some(e) => Err(e),
+            None => it.err_rp(self),
+        }
+    }
+    fn expected(&self) -> Expected {
+        Expected::OneOf(self.ps.iter().map(|p| p.expected()).collect())
+    }
+    fn describe(&self) -> Gram {
+        self.ps
+            .iter()
+            .map(|p| p.describe())
+            .reduce(Gram::alt)
+            .unwrap_or_else(|| Gram::Other("nothing".to_string()))
+    }
+}
+
+pub fn choice<P>(ps: Vec<P>) -> Choice<P> {
+    Choice { ps }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    type Op = fn(isize, isize) -> isize;
+
+    fn op_table<'p>(ops: &'p [(&'static str, Op)]) -> RcParser<'p, isize> {
+        let alts: Vec<BoxedParser<Op>> =
+            ops.iter().map(|(s, f)| ws__(*s).asv(*f).boxed()).collect();
+        let op = choice(alts);
+        recursive("Expr", move |expr| {
+            (
+                or(middle(ws__("("), expr, ws__(")")), ws__(common::Int)),
+                maybe((op, ws__(common::Int))),
+            )
+                .map(|(a, r)| match r {
+                    Some((f, b)) => f(a, b),
+                    None => a,
+                })
+        })
+    }
+
+    #[test]
+    fn test_runtime_operator_table() {
+        let ops: Vec<(&'static str, Op)> = vec![
+            ("+", |a, b| a + b),
+            ("*", |a, b| a * b),
+            ("max", isize::max),
+        ];
+        let expr = first(op_table(&ops), eoi);
+        assert_eq!(expr.parse_s("3 + 4"), Ok(7));
+        assert_eq!(expr.parse_s("((3 max 9)) * 2"), Ok(18));
+        assert_eq!(expr.parse_s("3 - 4").unwrap_err().index, Some(2));
+
+        //the grammar is only known at runtime, so can change between parses
+        let expr = first(op_table(&ops[..1]), eoi);
+        assert_eq!(expr.parse_s("3 + 4"), Ok(7));
+        assert_eq!(expr.parse_s("3 * 4").unwrap_err().index, Some(2));
+        assert!(choice(Vec::<BoxedParser<()>>::new()).parse_s("").is_err());
+    }
+
+    #[test]
+    fn test_recursive_expects_itself_by_name() {
+        //failing inside itself asks the grammar what it expects
+        let list = recursive("List", |list| middle("[", sep_star(list, ","), "]").ig());
+        assert_eq!(list.parse_s("[[],[[]]]"), Ok(()));
+        let e = list.parse_s("[x]").unwrap_err();
+        assert_eq!(e.index, Some(1));
+        assert_eq!(
+            e.exp,
+            Expected::OneOf(vec![Expected::Str("]"), Expected::Str("[")])
+        );
+
+        let w = recursive("W", |w| or(w.then_ig("x"), "y".ig()));
+        assert_eq!(
+            w.expected(),
+            Expected::OneOf(vec![Expected::Named("W"), Expected::Str("y")])
+        );
+    }
+}
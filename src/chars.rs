@@ -11,10 +11,15 @@
 //! ```
 //!
 
+use crate::borrow::Owned;
+use crate::borrow::RefParser;
+use crate::charset;
+use crate::describe::Gram;
 use crate::err::*;
 use crate::iter::*;
 use crate::ptrait::*;
 use crate::skip;
+use std::ops::RangeInclusive;
 
 //use crate::reader::*;
 
@@ -23,6 +28,21 @@ pub trait CharBool: Sized {
     fn expected(&self) -> Expected {
         Expected::Str(std::any::type_name::<Self>())
     }
+    /// The set as sorted ranges, if it can be known without testing every char.
+    /// Used by ```CharSet``` and to describe combined sets
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        None
+    }
+    /// Chars in both sets
+    ///```rust
+    /// use gobble::*;
+    /// let lower_hex = HexDigit.and('a'..='z');
+    /// assert_eq!(lower_hex.expected().to_string(), "[a-f]");
+    /// assert_eq!(lower_hex.plus().parse_s("fe0A"), Ok("fe".to_string()));
+    ///```
+    fn and<B: CharBool>(self, b: B) -> CharAnd<Self, B> {
+        CharAnd { a: self, b }
+    }
     fn one(self) -> OneChar<Self> {
         OneChar { cb: self }
     }
@@ -66,6 +86,39 @@ pub trait CharBool: Sized {
     fn exact(self, n: usize) -> CharExact<Self> {
         CharExact { a: self, n }
     }
+
+    /// Like ```star``` but returns a slice of the input, see ```RefParser```
+    fn star_str(self) -> CharStr<Self> {
+        CharStr {
+            cb: self,
+            min: 0,
+            exact: false,
+        }
+    }
+
+    fn plus_str(self) -> CharStr<Self> {
+        CharStr {
+            cb: self,
+            min: 1,
+            exact: false,
+        }
+    }
+
+    fn min_n_str(self, min: usize) -> CharStr<Self> {
+        CharStr {
+            cb: self,
+            min,
+            exact: false,
+        }
+    }
+
+    fn exact_str(self, n: usize) -> CharStr<Self> {
+        CharStr {
+            cb: self,
+            min: n,
+            exact: true,
+        }
+    }
 }
 
 pub struct CharNot<C: CharBool> {
@@ -83,24 +136,48 @@ impl<C: CharBool> CharBool for CharNot<C> {
     fn expected(&self) -> Expected {
         Expected::Except(Box::new(self.c.expected()))
     }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        Some(charset::complement(&self.c.ranges()?))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct CharAnd<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: CharBool, B: CharBool> CharBool for CharAnd<A, B> {
+    fn char_bool(&self, c: char) -> bool {
+        self.a.char_bool(c) && self.b.char_bool(c)
+    }
+    fn expected(&self) -> Expected {
+        match self.ranges() {
+            Some(r) => Expected::Ranges(r),
+            None => Expected::Both(Box::new(self.a.expected()), Box::new(self.b.expected())),
+        }
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        Some(charset::intersect(&self.a.ranges()?, &self.b.ranges()?))
+    }
 }
 
 pub fn is_alpha(c: char) -> bool {
-    (c >= 'A' && c <= 'Z') || (c >= 'a' && c <= 'z')
+    c.is_ascii_uppercase() || c.is_ascii_lowercase()
 }
-char_bool!(Alpha, is_alpha);
+char_bool!(Alpha, ('a'..='z', 'A'..='Z'));
 
 pub fn is_num(c: char) -> bool {
-    c >= '0' && c <= '9'
+    c.is_ascii_digit()
 }
-char_bool!(NumDigit, is_num);
+char_bool!(NumDigit, '0'..='9');
 
 char_bool!(Any, |_| true);
 
 pub fn is_hex(c: char) -> bool {
-    is_num(c) || (c >= 'a' && c <= 'f') || (c >= 'A' && c <= 'F')
+    is_num(c) || ('a'..='f').contains(&c) || ('A'..='F').contains(&c)
 }
-char_bool!(HexDigit, is_hex);
+char_bool!(HexDigit, ('0'..='9', 'a'..='f', 'A'..='F'));
 
 char_bool!(WS, "\t ");
 char_bool!(WSL, " \t\n\r");
@@ -112,6 +189,9 @@ impl CharBool for char {
     fn expected(&self) -> Expected {
         Expected::Char(*self)
     }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        Some(vec![(*self, *self)])
+    }
 }
 
 impl CharBool for &'static str {
@@ -121,6 +201,85 @@ impl CharBool for &'static str {
     fn expected(&self) -> Expected {
         Expected::CharIn(self)
     }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        Some(charset::normalize(self.chars().map(|c| (c, c)).collect()))
+    }
+}
+
+impl CharBool for RangeInclusive<char> {
+    fn char_bool(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+    fn expected(&self) -> Expected {
+        Expected::Ranges(self.ranges().unwrap_or_default())
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        Some(charset::normalize(vec![(*self.start(), *self.end())]))
+    }
+}
+
+/// Does the description use a named class, such as ```Alpha```
+fn has_named_class(e: &Expected) -> bool {
+    match e {
+        Expected::CharIn(s) => Gram::is_named_class(s),
+        Expected::OneOf(v) => v.iter().any(has_named_class),
+        Expected::Except(e) => has_named_class(e),
+        _ => false,
+    }
+}
+
+/// A union as ranges, like "[a-z_]", when they are known. Unions with a named class keep
+/// every part as it was, as "Alpha" reads better than "[A-Za-z]"
+fn union_of(exps: Vec<Expected>, ranges: Option<Vec<(char, char)>>) -> Expected {
+    match ranges {
+        Some(r) if !exps.iter().any(has_named_class) => Expected::Ranges(r),
+        _ => Expected::OneOf(exps),
+    }
+}
+
+fn union_expected<C: CharBool>(v: &[C]) -> Expected {
+    union_of(v.iter().map(|cb| cb.expected()).collect(), union_ranges(v))
+}
+
+fn union_ranges<C: CharBool>(v: &[C]) -> Option<Vec<(char, char)>> {
+    charset::union_all(v.iter().map(|cb| cb.ranges()))
+}
+
+/// Any of the sets, for unions longer than the tuples allow
+impl<C: CharBool, const N: usize> CharBool for [C; N] {
+    fn char_bool(&self, c: char) -> bool {
+        self.iter().any(|cb| cb.char_bool(c))
+    }
+    fn expected(&self) -> Expected {
+        union_expected(self)
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        union_ranges(self)
+    }
+}
+
+impl<C: CharBool> CharBool for &[C] {
+    fn char_bool(&self, c: char) -> bool {
+        self.iter().any(|cb| cb.char_bool(c))
+    }
+    fn expected(&self) -> Expected {
+        union_expected(self)
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        union_ranges(self)
+    }
+}
+
+impl<C: CharBool> CharBool for Vec<C> {
+    fn char_bool(&self, c: char) -> bool {
+        self.iter().any(|cb| cb.char_bool(c))
+    }
+    fn expected(&self) -> Expected {
+        union_expected(self)
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        union_ranges(self)
+    }
 }
 
 impl<F: Fn(char) -> bool> CharBool for F {
@@ -134,7 +293,10 @@ impl<A: CharBool, B: CharBool> CharBool for (A, B) {
         self.0.char_bool(c) || self.1.char_bool(c)
     }
     fn expected(&self) -> Expected {
-        Expected::OneOf(vec![self.0.expected(), self.1.expected()])
+        union_of(vec![self.0.expected(), self.1.expected()], self.ranges())
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        charset::union_all(vec![self.0.ranges(), self.1.ranges()])
     }
 }
 
@@ -143,11 +305,13 @@ impl<A: CharBool, B: CharBool, C: CharBool> CharBool for (A, B, C) {
         self.0.char_bool(c) || self.1.char_bool(c) || self.2.char_bool(c)
     }
     fn expected(&self) -> Expected {
-        Expected::OneOf(vec![
-            self.0.expected(),
-            self.1.expected(),
-            self.2.expected(),
-        ])
+        union_of(
+            vec![self.0.expected(), self.1.expected(), self.2.expected()],
+            self.ranges(),
+        )
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        charset::union_all(vec![self.0.ranges(), self.1.ranges(), self.2.ranges()])
     }
 }
 
@@ -162,11 +326,22 @@ where
         self.0.char_bool(c) || self.1.char_bool(c) || self.2.char_bool(c) || self.3.char_bool(c)
     }
     fn expected(&self) -> Expected {
-        Expected::OneOf(vec![
-            self.0.expected(),
-            self.1.expected(),
-            self.2.expected(),
-            self.3.expected(),
+        union_of(
+            vec![
+                self.0.expected(),
+                self.1.expected(),
+                self.2.expected(),
+                self.3.expected(),
+            ],
+            self.ranges(),
+        )
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        charset::union_all(vec![
+            self.0.ranges(),
+            self.1.ranges(),
+            self.2.ranges(),
+            self.3.ranges(),
         ])
     }
 }
@@ -187,12 +362,24 @@ where
             || self.4.char_bool(c)
     }
     fn expected(&self) -> Expected {
-        Expected::OneOf(vec![
-            self.0.expected(),
-            self.1.expected(),
-            self.2.expected(),
-            self.3.expected(),
-            self.4.expected(),
+        union_of(
+            vec![
+                self.0.expected(),
+                self.1.expected(),
+                self.2.expected(),
+                self.3.expected(),
+                self.4.expected(),
+            ],
+            self.ranges(),
+        )
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        charset::union_all(vec![
+            self.0.ranges(),
+            self.1.ranges(),
+            self.2.ranges(),
+            self.3.ranges(),
+            self.4.ranges(),
         ])
     }
 }
@@ -215,13 +402,26 @@ where
             || self.5.char_bool(c)
     }
     fn expected(&self) -> Expected {
-        Expected::OneOf(vec![
-            self.0.expected(),
-            self.1.expected(),
-            self.2.expected(),
-            self.3.expected(),
-            self.4.expected(),
-            self.5.expected(),
+        union_of(
+            vec![
+                self.0.expected(),
+                self.1.expected(),
+                self.2.expected(),
+                self.3.expected(),
+                self.4.expected(),
+                self.5.expected(),
+            ],
+            self.ranges(),
+        )
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        charset::union_all(vec![
+            self.0.ranges(),
+            self.1.ranges(),
+            self.2.ranges(),
+            self.3.ranges(),
+            self.4.ranges(),
+            self.5.ranges(),
         ])
     }
 }
@@ -240,11 +440,19 @@ pub struct OneChar<CB: CharBool> {
     cb: CB,
 }
 
+impl<CB: CharBool> Owned for OneChar<CB> {}
+
 impl<CB: CharBool> Parser for OneChar<CB> {
     type Out = char;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, char> {
         do_one_char(it, &self.cb)
     }
+    fn expected(&self) -> Expected {
+        self.cb.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.cb.expected())
+    }
 }
 
 pub fn one_char<C: CharBool>(cb: C) -> OneChar<C> {
@@ -279,11 +487,58 @@ pub fn do_chars<'a, CB: CharBool>(
         }
     }
 }
+/// The same as do_chars, but returns the slice of input matched rather than a new String
+pub fn do_chars_str<'a, CB: CharBool>(
+    it: &LCChars<'a>,
+    cb: &CB,
+    min: usize,
+    exact: bool,
+) -> ParseRes<'a, &'a str> {
+    let mut n = 0;
+    let mut it2 = it.clone();
+    loop {
+        if n == min && exact {
+            return Ok((it2.clone(), it.slice_to(&it2), None));
+        }
+        let prev = it2.clone();
+        match it2.next() {
+            Some(c) if cb.char_bool(c) => n += 1,
+            Some(_) | None => {
+                if n >= min {
+                    let eo = prev.err_oc(cb);
+                    return Ok((prev.clone(), it.slice_to(&prev), eo));
+                } else {
+                    return prev.err_r(cb.expected());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CharStr<C: CharBool> {
+    cb: C,
+    min: usize,
+    exact: bool,
+}
+
+impl<CB: CharBool> RefParser for CharStr<CB> {
+    type Out<'a> = &'a str;
+    fn parse_ref<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, &'a str> {
+        do_chars_str(it, &self.cb, self.min, self.exact)
+    }
+    fn expected_ref(&self) -> Expected {
+        self.cb.expected()
+    }
+}
+
 #[derive(Clone)]
 pub struct CharStar<C: CharBool> {
     cb: C,
 }
 
+impl<CB: CharBool> Owned for CharStar<CB> {}
+
 impl<CB: CharBool> Parser for CharStar<CB> {
     type Out = String;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, String> {
@@ -292,6 +547,9 @@ impl<CB: CharBool> Parser for CharStar<CB> {
     fn expected(&self) -> Expected {
         self.cb.expected()
     }
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.cb.expected()).star()
+    }
 }
 
 #[derive(Clone)]
@@ -299,11 +557,19 @@ pub struct CharPlus<C: CharBool> {
     cb: C,
 }
 
+impl<CB: CharBool> Owned for CharPlus<CB> {}
+
 impl<CB: CharBool> Parser for CharPlus<CB> {
     type Out = String;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, String> {
         do_chars(it, &self.cb, 1, false)
     }
+    fn expected(&self) -> Expected {
+        self.cb.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.cb.expected()).plus()
+    }
 }
 
 pub struct CharsExcept<A: CharBool, E: CharBool> {
@@ -318,6 +584,10 @@ impl<A: CharBool, E: CharBool> CharBool for CharsExcept<A, E> {
     fn expected(&self) -> Expected {
         self.a.expected().or(Expected::except(self.e.expected()))
     }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        let e = charset::complement(&self.e.ranges()?);
+        Some(charset::intersect(&self.a.ranges()?, &e))
+    }
 }
 
 #[derive(Clone)]
@@ -326,11 +596,19 @@ pub struct CharExact<A: CharBool> {
     n: usize,
 }
 
+impl<A: CharBool> Owned for CharExact<A> {}
+
 impl<A: CharBool> Parser for CharExact<A> {
     type Out = String;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, String> {
         do_chars(it, &self.a, self.n, true)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.a.expected()).times(self.n)
+    }
 }
 
 #[derive(Clone)]
@@ -339,18 +617,27 @@ pub struct CharMin<A: CharBool> {
     min: usize,
 }
 
+impl<A: CharBool> Owned for CharMin<A> {}
+
 impl<A: CharBool> Parser for CharMin<A> {
     type Out = String;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, String> {
         do_chars(it, &self.cb, self.min, false)
     }
+    fn expected(&self) -> Expected {
+        self.cb.expected()
+    }
+    fn describe(&self) -> Gram {
+        let g = Gram::from_expected(self.cb.expected());
+        Gram::seq(g.clone().times(self.min), g.star())
+    }
 }
 
 #[cfg(test)]
-pub mod test {
+mod test {
     use super::*;
     #[test]
     pub fn test_alpha_works_as_struct() {
-        assert_eq!(Alpha.char_bool('a'), true)
+        assert!(Alpha.char_bool('a'))
     }
 }
@@ -0,0 +1,293 @@
+//! Sets of chars as sorted ranges.
+//!
+//! Any ```CharBool``` can be compiled into a ```CharSet```, a sorted table of ranges with a
+//! bitmap for ASCII, so testing a char is a lookup however the set was put together.
+//! Sets that know their ranges (see ```CharBool::ranges```), such as chars, strs, ranges and
+//! their unions, intersections and complements, are compiled directly, anything else is
+//! tested against every char once, so build a ```CharSet``` once and reuse it.
+//!
+//! ```rust
+//! use gobble::*;
+//! let hex = CharSet::new(('0'..='9', 'a'..='f', 'A'..='F'));
+//! assert_eq!(hex.expected().to_string(), "[0-9A-Fa-f]");
+//! //borrowed, so the same set can be used in several places
+//! assert_eq!((&hex).plus().parse_s("fF09x"), Ok("fF09".to_string()));
+//! assert_eq!((&hex, '_').plus().parse_s("f_0-"), Ok("f_0".to_string()));
+//!
+//! let vowel = ['a', 'e', 'i', 'o', 'u'];
+//! let consonant = CharSet::new(('a'..='z').and(not(vowel)));
+//! assert!(consonant.char_bool('b') && !consonant.char_bool('e'));
+//! assert_eq!(consonant.expected().to_string(), "[b-df-hj-np-tv-z]");
+//! ```
+use crate::chars::CharBool;
+use crate::err::Expected;
+
+/// The char after 'c', skipping the surrogates which are not chars
+fn next(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        c => std::char::from_u32(c as u32 + 1),
+    }
+}
+
+fn prev(c: char) -> Option<char> {
+    match c {
+        '\u{E000}' => Some('\u{D7FF}'),
+        '\0' => None,
+        c => std::char::from_u32(c as u32 - 1),
+    }
+}
+
+/// Sorts the ranges and joins any that touch or overlap
+pub(crate) fn normalize(mut v: Vec<(char, char)>) -> Vec<(char, char)> {
+    v.retain(|(a, b)| a <= b);
+    v.sort_unstable();
+    let mut res: Vec<(char, char)> = Vec::with_capacity(v.len());
+    for (a, b) in v {
+        match res.last_mut() {
+            Some((_, pb)) if next(*pb).map(|n| a <= n).unwrap_or(true) => *pb = b.max(*pb),
+            _ => res.push((a, b)),
+        }
+    }
+    res
+}
+
+/// Both must already be normalized
+pub(crate) fn intersect(a: &[(char, char)], b: &[(char, char)]) -> Vec<(char, char)> {
+    let (mut i, mut j) = (0, 0);
+    let mut res = Vec::new();
+    while i < a.len() && j < b.len() {
+        let lo = a[i].0.max(b[j].0);
+        let hi = a[i].1.min(b[j].1);
+        if lo <= hi {
+            res.push((lo, hi));
+        }
+        match a[i].1 < b[j].1 {
+            true => i += 1,
+            false => j += 1,
+        }
+    }
+    res
+}
+
+/// Must already be normalized
+pub(crate) fn complement(v: &[(char, char)]) -> Vec<(char, char)> {
+    let mut res = Vec::new();
+    let mut start = Some('\0');
+    for (a, b) in v {
+        if let (Some(s), Some(p)) = (start, prev(*a)) {
+            if s <= p {
+                res.push((s, p));
+            }
+        }
+        start = next(*b);
+    }
+    if let Some(s) = start {
+        res.push((s, char::MAX));
+    }
+    res
+}
+
+/// The union of all, or None if any is unknown
+pub(crate) fn union_all<It: IntoIterator<Item = Option<Vec<(char, char)>>>>(
+    it: It,
+) -> Option<Vec<(char, char)>> {
+    let mut res = Vec::new();
+    for r in it {
+        res.extend(r?);
+    }
+    Some(normalize(res))
+}
+
+fn push_class_char(res: &mut String, c: char) {
+    match c {
+        ']' | '[' | '-' | '^' | '\\' => {
+            res.push('\\');
+            res.push(c);
+        }
+        c if c.is_control() || (c.is_whitespace() && c != ' ') => res.extend(c.escape_default()),
+        c => res.push(c),
+    }
+}
+
+/// The ranges as they would appear inside "[]", as in "a-fA-F0-9"
+pub fn ranges_text(v: &[(char, char)]) -> String {
+    let mut res = String::new();
+    for (a, b) in v {
+        push_class_char(&mut res, *a);
+        if a != b {
+            if next(*a) != Some(*b) {
+                res.push('-');
+            }
+            push_class_char(&mut res, *b);
+        }
+    }
+    res
+}
+
+/// Only this many ranges are listed when describing a set
+const MAX_SHOWN: usize = 16;
+
+/// A ```CharBool``` compiled to sorted ranges, see the module docs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharSet {
+    ascii: u128,
+    ranges: Vec<(char, char)>,
+    exp: Expected,
+}
+
+impl CharSet {
+    pub fn new<C: CharBool>(cb: C) -> Self {
+        let ranges = match cb.ranges() {
+            Some(r) => r,
+            None => {
+                let mut v = Vec::new();
+                let (mut start, mut last) = (None, '\0');
+                for c in '\0'..=char::MAX {
+                    match (start, cb.char_bool(c)) {
+                        (None, true) => start = Some(c),
+                        (Some(s), false) => {
+                            v.push((s, last));
+                            start = None;
+                        }
+                        _ => {}
+                    }
+                    last = c;
+                }
+                if let Some(s) = start {
+                    v.push((s, char::MAX));
+                }
+                v
+            }
+        };
+        Self::from_ranges(ranges, cb.expected())
+    }
+
+    /// 'exp' is used to describe the set when it has too many ranges to list
+    fn from_ranges(ranges: Vec<(char, char)>, exp: Expected) -> Self {
+        let mut ascii = 0u128;
+        for (a, b) in &ranges {
+            for c in (*a as u32)..=(*b as u32).min(127) {
+                ascii |= 1 << c;
+            }
+        }
+        let comp = complement(&ranges);
+        let exp = match (ranges.len(), comp.len()) {
+            (n, c) if c < n && c <= MAX_SHOWN => Expected::except(Expected::Ranges(comp)),
+            (n, _) if n <= MAX_SHOWN => Expected::Ranges(ranges.clone()),
+            _ => exp,
+        };
+        CharSet { ascii, ranges, exp }
+    }
+
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+}
+
+impl CharBool for CharSet {
+    fn char_bool(&self, c: char) -> bool {
+        if c.is_ascii() {
+            return self.ascii & (1 << c as u32) != 0;
+        }
+        let c = c as u32;
+        let i = self.ranges.partition_point(|(a, _)| *a as u32 <= c);
+        i > 0 && c <= self.ranges[i - 1].1 as u32
+    }
+    fn expected(&self) -> Expected {
+        self.exp.clone()
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        Some(self.ranges.clone())
+    }
+}
+
+impl CharBool for &CharSet {
+    fn char_bool(&self, c: char) -> bool {
+        (*self).char_bool(c)
+    }
+    fn expected(&self) -> Expected {
+        self.exp.clone()
+    }
+    fn ranges(&self) -> Option<Vec<(char, char)>> {
+        Some(self.ranges.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_compiled_sets_match_their_source() {
+        let src = (HexDigit, "_$", |c: char| c == 'λ' || c == '\u{10FFFF}');
+        let set = CharSet::new(src);
+        assert_eq!(
+            set.ranges(),
+            &[
+                ('$', '$'),
+                ('0', '9'),
+                ('A', 'F'),
+                ('_', '_'),
+                ('a', 'f'),
+                ('λ', 'λ'),
+                ('\u{10FFFF}', '\u{10FFFF}')
+            ]
+        );
+        for c in "az09_$-λμ\u{10FFFF}\u{D7FF}\u{E000}".chars() {
+            assert_eq!(set.char_bool(c), src.char_bool(c), "{}", c);
+        }
+
+        //sets are described by what they leave out if that is shorter, or as they were if both are long
+        let not_quote = CharSet::new(not("\"\\"));
+        assert!(not_quote.char_bool('\u{E000}') && !not_quote.char_bool('"'));
+        assert_eq!(
+            not_quote.expected().sentence(),
+            "anything except a char in [\"\\\\]"
+        );
+        assert_eq!(CharSet::new(UAlpha).expected(), Expected::CharIn("UAlpha"));
+        assert!(CharSet::new(UAlpha).char_bool('变'));
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let digits: &[char] = &['1', '2', '3', '5', '7'];
+        assert_eq!(digits.expected().to_string(), "[1-357]");
+        let v = vec!['x'..='z', 'a'..='c', 'b'..='d'];
+        assert_eq!(v.expected(), Expected::Ranges(vec![('a', 'd'), ('x', 'z')]));
+        assert_eq!(
+            Alpha.except("aeiou").and('a'..='j').ranges(),
+            Some(vec![('b', 'd'), ('f', 'h'), ('j', 'j')])
+        );
+        assert_eq!(not(not("ab")).ranges(), Some(vec![('a', 'b')]));
+        assert_eq!(Alpha.and(|c: char| c > 'x').ranges(), None);
+        assert!(Alpha.and(|c: char| c > 'x').char_bool('y'));
+        //without ranges, an intersection is described by its parts
+        let e = UAlpha.and('a'..='f').expected();
+        assert_eq!(
+            e.sentence(),
+            "both a char in \"UAlpha\" and a char in [a-f]"
+        );
+
+        //tuples are ranges too, but unions with a named class keep their names
+        let t = ('x'..='z', ('a', "bcd"));
+        assert_eq!(t.expected(), Expected::Ranges(vec![('a', 'd'), ('x', 'z')]));
+        assert_eq!(t.expected(), vec!['x'..='z', 'a'..='d'].expected());
+        let named = || Expected::OneOf(vec![Expected::CharIn("HexDigit"), Expected::Char('_')]);
+        assert_eq!((HexDigit, '_').expected(), named());
+        assert_eq!([(HexDigit, '_')].expected(), Expected::OneOf(vec![named()]));
+        assert_eq!(
+            (not(Alpha), '_').expected(),
+            Expected::OneOf(vec![
+                Expected::Except(Box::new(Expected::CharIn("Alpha"))),
+                Expected::Char('_'),
+            ])
+        );
+
+        //ranges describe and generate as classes
+        let p = CharSet::new(('a'..='f', '-')).plus();
+        assert_eq!(p.describe().to_ebnf(), "[\\-a-f]+");
+        let s = Generator::new(5).sample(&p).unwrap();
+        assert_eq!(p.parse_s(&s), Ok(s.clone()));
+    }
+}
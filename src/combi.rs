@@ -1,24 +1,54 @@
+use crate::borrow::{Owned, RefParser};
+use crate::describe::Gram;
 use crate::err::*;
+use crate::input::Input;
 use crate::iter::*;
 use crate::ptrait::*;
 use std::fmt::Debug;
 
 #[derive(Clone)]
-pub struct Maybe<A: Parser> {
+pub struct Maybe<A> {
     p: A,
 }
 
-impl<A> Parser for Maybe<A>
+impl<I, A> RefParser<I> for Maybe<A>
 where
-    A: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+{
+    type Out<'a>
+        = Option<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        match self.p.parse_ref(i) {
+            Ok((ir, v, ex)) => Ok((ir, Some(v), ex)),
+            Err(e) => Ok((i.clone(), None, Some(e))),
+        }
+    }
+    fn expected_ref(&self) -> Expected {
+        self.p.expected_ref()
+    }
+}
+
+impl<I, A> Parser<I> for Maybe<A>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
 {
     type Out = Option<A::Out>;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         match self.p.parse(i) {
             Ok((ir, v, ex)) => Ok((ir, Some(v), ex)),
             Err(e) => Ok((i.clone(), None, Some(e))),
         }
     }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe().opt()
+    }
 }
 
 /// returns an option on whether this item was found A common use would be
@@ -39,25 +69,33 @@ where
 /// let s = p.parse_s("34").unwrap();
 /// assert_eq!(s,34);
 /// ```
-pub fn maybe<P: Parser>(p: P) -> Maybe<P> {
+pub fn maybe<P>(p: P) -> Maybe<P> {
     Maybe { p }
 }
 
-pub struct Exists<P: Parser> {
+pub struct Exists<P> {
     p: P,
 }
 
-impl<P: Parser> Parser for Exists<P> {
+impl<P> Owned for Exists<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for Exists<P> {
     type Out = bool;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, bool> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, bool, I> {
         match self.p.parse(it) {
             Ok((nit, _, e)) => Ok((nit, true, e)),
             Err(e) => Ok((it.clone(), false, Some(e))),
         }
     }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe().opt()
+    }
 }
 
-pub fn exists<P: Parser>(p: P) -> Exists<P> {
+pub fn exists<P>(p: P) -> Exists<P> {
     Exists { p }
 }
 
@@ -67,31 +105,61 @@ pub struct Wrap<A, B> {
     b: B,
 }
 
-impl<A, B> Parser for Wrap<A, B>
+impl<I, A, B> RefParser<I> for Wrap<A, B>
 where
-    A: Parser,
-    B: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+{
+    type Out<'a>
+        = B::Out<'a>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, B::Out<'a>, I> {
+        let (i, _, c1) = self.a.parse_ref(i)?;
+        let (i, res, c2) = self.b.parse_ref(&i).map_err(|e| e.join_op(c1))?;
+        let (n, _, c3) = self.a.parse_ref(&i).map_err(|e| e.join_op(c2))?;
+        Ok((n, res, c3))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.a.expected_ref(), self.b.expected_ref())
+    }
+}
+
+impl<I, A, B> Parser<I> for Wrap<A, B>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
 {
     type Out = B::Out;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (i, _, c1) = self.a.parse(i)?;
         let (i, res, c2) = self.b.parse(&i).map_err(|e| e.join_op(c1))?;
         let (n, _, c3) = self.a.parse(&i).map_err(|e| e.join_op(c2))?;
         Ok((n, res, c3))
     }
+    fn expected(&self) -> Expected {
+        Expected::first(self.a.expected(), self.b.expected())
+    }
+    fn describe(&self) -> Gram {
+        Gram::Seq(vec![
+            self.a.describe(),
+            self.b.describe(),
+            self.a.describe(),
+        ])
+    }
 }
 
-pub fn wrap<A, B>(a: A, b: B) -> Wrap<A, B>
-where
-    A: Parser,
-    B: Parser,
-{
+pub fn wrap<A, B>(a: A, b: B) -> Wrap<A, B> {
     Wrap { a, b }
 }
 
-impl<P: Parser<Out = V>, V: Debug> Parser for FailOn<P> {
+impl<P> Owned for FailOn<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I, Out = V>, V: Debug> Parser<I> for FailOn<P> {
     type Out = ();
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, (), I> {
         match self.p.parse(it) {
             Ok((_, _, _)) => it.err_rp(self),
             Err(_) => Ok((it.clone(), (), None)),
@@ -102,41 +170,132 @@ impl<P: Parser<Out = V>, V: Debug> Parser for FailOn<P> {
     }
 }
 
-pub struct FailOn<P: Parser> {
+pub struct FailOn<P> {
     p: P,
 }
 
-pub fn fail_on<P: Parser>(p: P) -> FailOn<P> {
+pub fn fail_on<P>(p: P) -> FailOn<P> {
     FailOn { p }
 }
 
+pub struct Recover<P, S> {
+    p: P,
+    sync: S,
+}
+
+impl<P, S> Owned for Recover<P, S> {}
+
+impl<I: ?Sized + Input, P: Parser<I>, S: Parser<I>> Parser<I> for Recover<P, S> {
+    type Out = Option<P::Out>;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        let e = match self.p.parse(it) {
+            Ok((nit, v, ct)) => return Ok((nit, Some(v), ct)),
+            Err(e) => e,
+        };
+        let mut nit = it.clone();
+        loop {
+            if self.sync.parse(&nit).is_ok() {
+                break;
+            }
+            if nit.next().is_none() {
+                break;
+            }
+        }
+        nit.add_recovered(e.strung());
+        Ok((nit, None, None))
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+/// If the parser fails, the error is recorded, and the input is skipped up to (but not including)
+/// the point where sync would succeed. This returns None in place of the failed value.
+/// Use ```parse_s_all``` to get the recorded errors.
+///
+/// ```rust
+/// use gobble::*;
+/// let stmt = recover(first(common::Ident, ws__("=")).then(common::Int), ";");
+/// let p = sep_until_ig(stmt, ";", eoi);
+/// let (v, errs) = p.parse_s_all("a=1;b=;c=3;4=5");
+/// assert_eq!(
+///     v,
+///     Some(vec![Some(("a".to_string(), 1)), None, Some(("c".to_string(), 3)), None])
+/// );
+/// assert_eq!(errs.len(), 2);
+/// assert_eq!(errs[0].index, Some(6));
+/// assert_eq!(errs[1].index, Some(11));
+/// ```
+pub fn recover<P, S>(p: P, sync: S) -> Recover<P, S> {
+    Recover { p, sync }
+}
+
 #[derive(PartialEq, Debug, Clone)]
-pub struct PDebugger<P: Parser> {
+pub struct PDebugger<P> {
     p: P,
     s: &'static str,
 }
 
-pub fn debug<P: Parser>(p: P, s: &'static str) -> PDebugger<P> {
+/// Traces 'p' under the name 's', as if it were a ```parser!``` rule.
+/// Without the "trace" feature this does nothing, see ```TraceSession```
+pub fn debug<P>(p: P, s: &'static str) -> PDebugger<P> {
     PDebugger { p, s }
 }
 
-impl<P: Parser> Parser for PDebugger<P> {
+impl<P> Owned for PDebugger<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for PDebugger<P> {
     type Out = P::Out;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
-        let pstr = it.clone().as_str();
-        let plen = pstr.len().min(10);
-        println!("DebuggerPre - {} >>{:?}", self.s, &pstr[..plen]);
-        let r = self.p.parse(it);
-        match &r {
-            Ok((nit, _, _)) => {
-                let s = match (it.index(), nit.index()) {
-                    (Some(st), Some(f)) => &it.as_str()[..f - st],
-                    _ => it.as_str(),
-                };
-                println!("Success - {}, \"{}\"", self.s, s);
-            }
-            Err(_) => println!("Fail - {}", self.s),
-        };
-        r
+    #[cfg(feature = "trace")]
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        crate::trace::traced(self.s, it, || self.p.parse(it))
+    }
+    #[cfg(not(feature = "trace"))]
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        self.p.parse(it)
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_recovered_errors_dropped_on_backtrack() {
+        let item = || recover(common::Int, ",");
+        let p = or(
+            (sep_until_ig(item(), ",", "]"), "!").map(|(v, _)| v),
+            sep_until_ig(item(), ",", "]"),
+        );
+        let (v, errs) = p.parse_s_all("1,x,3]");
+        assert_eq!(v, Some(vec![Some(1), None, Some(3)]));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].index, Some(2));
+    }
+
+    #[test]
+    fn test_recovered_errors_kept_on_failure() {
+        let stmt = recover(first(common::Ident, ws__("=")).then(common::Int), ";");
+        let p = star(first(stmt, ";")).then_ig(eoi);
+        let (v, errs) = p.parse_s_all("a=1;b=;c=3;4=5");
+        assert_eq!(v, None);
+        let idx: Vec<_> = errs.iter().map(|e| e.index).collect();
+        //without a ';' the last statement is dropped by star, and eoi fails there
+        assert_eq!(idx, vec![Some(6), Some(11), Some(11)]);
+
+        //the error is kept when nothing could be skipped before sync
+        let (v, errs) = star(recover(common::Int, ";")).parse_s_all(";");
+        assert_eq!(v, Some(vec![]));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].index, Some(0));
     }
 }
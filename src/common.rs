@@ -22,12 +22,14 @@
 //! ```
 use crate::chars::*;
 use crate::combi::*;
+use crate::describe::*;
 use crate::err::*;
 use crate::iter::*;
 use crate::ptrait::*;
 use crate::reader::*;
 use crate::strings::*;
 use crate::tuple::*;
+use crate::unicode::*;
 use std::convert::TryFrom;
 
 parser! { "Escapes a '\' and converts '\\n' '\\t' '\\r'"
@@ -51,6 +53,12 @@ parser! { "A letter followed by numbers and letters or '_'"
     string((Alpha.iplus(), (Alpha, NumDigit, '_').istar()))
 }
 
+parser! { "An identifier following UAX #31, XID_Start or '_' followed by XID_Continue.
+Not normalized, so the same name can be written with different chars"
+    (UIdent->String)
+    string(((XidStart, '_').iplus(), XidContinue.istar()))
+}
+
 #[deprecated(since = "0.5.0", note = "use Ident instead")]
 pub fn common_ident<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
     Ident.parse(it)
@@ -58,7 +66,10 @@ pub fn common_ident<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
 
 parser! { "a usize"
     (UInt->usize)
-    common_uint
+    described(common_uint, || {
+        let d = Gram::Class("NumDigit".to_string());
+        Gram::seq(d.clone(), Gram::alt(d, Gram::Terminal("_".to_string())).star())
+    })
 }
 
 #[deprecated(since = "0.5.1", note = " will go private as UInt does the job")]
@@ -131,7 +142,10 @@ parser! {"'e' followed by a uint, allowed on floats"
 
 parser! { "floating point numbers eg '134.4e6'"
     (Float->f64)
-    (Int,dot_part,maybe(Exponent)).map(|(n,d,e)|{
+    (Int,described(dot_part, || {
+        let d = Gram::Class("NumDigit".to_string());
+        Gram::seq(Gram::Terminal(".".to_string()), Gram::alt(d, Gram::Terminal("_".to_string())).star())
+    }),maybe(Exponent)).map(|(n,d,e)|{
         let mut res =n as f64;
         res += res.signum() * d;
         if let Some(exp) = e{
@@ -154,7 +168,7 @@ parser! { "floating point numbers eg '134.4e6'"
 }
 
 #[cfg(test)]
-pub mod test {
+mod test {
     use super::*;
     #[test]
     pub fn test_parse_numbers() {
@@ -0,0 +1,455 @@
+//! Describing the shape of a grammar, to document it or draw it.
+//!
+//! ```Parser::describe``` returns a ```Gram``` tree, made of sequences, choices and repeats of
+//! terminals. Rules made with ```parser!``` or ```recursive``` appear as references by name, so
+//! recursive grammars stay finite, and ```Grammar::of``` collects the definition of every rule
+//! reached. Rules are told apart by their type rather than their name, so two rules called
+//! "Item" in different modules are both kept, though they print with the same name.
+//! The result can be written as EBNF, or as JSON in the node types used by railroad diagram
+//! generators.
+//!
+//! Parsers built from closures can not be looked into, so they appear as ```Gram::Other```,
+//! described by what they expect.
+//!
+//! ```rust
+//! use gobble::*;
+//! parser! {
+//!     (Item->String)
+//!     or(middle("[", Item, "]"), common::Ident)
+//! }
+//! parser! {
+//!     (List->Vec<String>)
+//!     sep_plus(Item, ws__(","))
+//! }
+//! assert_eq!(
+//!     Grammar::of(&List).to_ebnf(),
+//!     r#"List ::= Item (WS* "," WS* Item)*
+//! Item ::= "[" Item "]" | Ident
+//! Ident ::= Alpha+ (Alpha | NumDigit | "_")*
+//! "#
+//! );
+//! ```
+use crate::borrow::Owned;
+use crate::charset::ranges_text;
+use crate::err::{short_type_name, Expected};
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+use std::any::TypeId;
+use std::rc::Rc;
+
+/// What tells one rule from another, as names can be shared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum RuleKey {
+    /// The type of a ```parser!``` rule
+    Type(TypeId),
+    /// Where a ```recursive``` grammar lives
+    Addr(usize),
+}
+
+#[derive(Debug, Clone)]
+enum RuleDef {
+    /// From ```parser!```, built when asked for
+    Lazy(fn() -> Gram),
+    /// From ```recursive```, built while describing it, None on references back into it
+    Built(Option<Rc<Gram>>),
+}
+
+/// A reference to a rule by name, with a way to get its definition, see ```Gram::Rule```
+#[derive(Debug, Clone)]
+pub struct RuleRef {
+    pub name: &'static str,
+    pub(crate) key: RuleKey,
+    def: RuleDef,
+}
+
+impl RuleRef {
+    /// A reference to the rule of type 'R', used by ```parser!```
+    pub fn of<R: 'static>(name: &'static str, def: fn() -> Gram) -> Self {
+        RuleRef {
+            name,
+            key: RuleKey::Type(TypeId::of::<R>()),
+            def: RuleDef::Lazy(def),
+        }
+    }
+
+    pub(crate) fn built(name: &'static str, addr: usize, def: Option<Gram>) -> Self {
+        RuleRef {
+            name,
+            key: RuleKey::Addr(addr),
+            def: RuleDef::Built(def.map(Rc::new)),
+        }
+    }
+
+    /// The definition of the rule. None for the references inside a ```recursive``` grammar
+    /// back to itself, as the definition is on the outer reference
+    pub fn definition(&self) -> Option<Gram> {
+        match &self.def {
+            RuleDef::Lazy(f) => Some(f()),
+            RuleDef::Built(g) => g.as_deref().cloned(),
+        }
+    }
+
+    /// Is the definition carried on this reference, rather than built when asked for
+    pub(crate) fn is_built(&self) -> bool {
+        matches!(self.def, RuleDef::Built(Some(_)))
+    }
+}
+
+/// A node in the description of a grammar
+#[derive(Debug, Clone)]
+pub enum Gram {
+    /// Literal text, or a single item
+    Terminal(String),
+    /// One char from a set, either listed, or named like "Alpha"
+    Class(String),
+    /// One char in the sorted ranges
+    Ranges(Vec<(char, char)>),
+    /// One char that does not match the inner node
+    Not(Box<Gram>),
+    /// A rule made with ```parser!``` or ```recursive```
+    Rule(RuleRef),
+    Seq(Vec<Gram>),
+    Alt(Vec<Gram>),
+    Opt(Box<Gram>),
+    Star(Box<Gram>),
+    Plus(Box<Gram>),
+    /// A parser that can not be looked into, described by what it expects
+    Other(String),
+}
+
+impl Gram {
+    /// A sequence of both, flattening any sequences within
+    pub fn seq(a: Gram, b: Gram) -> Gram {
+        let mut v = match a {
+            Gram::Seq(v) => v,
+            a => vec![a],
+        };
+        match b {
+            Gram::Seq(bv) => v.extend(bv),
+            b => v.push(b),
+        }
+        Gram::Seq(v)
+    }
+
+    /// A choice of either, flattening any choices within
+    pub fn alt(a: Gram, b: Gram) -> Gram {
+        let mut v = match a {
+            Gram::Alt(v) => v,
+            a => vec![a],
+        };
+        match b {
+            Gram::Alt(bv) => v.extend(bv),
+            b => v.push(b),
+        }
+        Gram::Alt(v)
+    }
+
+    pub fn opt(self) -> Gram {
+        Gram::Opt(Box::new(self))
+    }
+
+    pub fn star(self) -> Gram {
+        Gram::Star(Box::new(self))
+    }
+
+    pub fn plus(self) -> Gram {
+        Gram::Plus(Box::new(self))
+    }
+
+    /// This exactly 'n' times
+    pub fn times(self, n: usize) -> Gram {
+        Gram::Seq(vec![self; n])
+    }
+
+    /// 'a' with 'b' between each, at least once
+    pub fn sep_plus(a: Gram, b: Gram) -> Gram {
+        Gram::seq(a.clone(), Gram::seq(b, a).star())
+    }
+
+    /// The best description available from an ```Expected```, used by parsers that do not
+    /// implement ```describe```
+    pub fn from_expected(e: Expected) -> Gram {
+        match e {
+            Expected::Str(s) => Gram::Terminal(s.to_string()),
+            Expected::Char(c) => Gram::Terminal(c.to_string()),
+            Expected::Item(s) | Expected::Text(s) => Gram::Terminal(s),
+            Expected::CharIn(s) => Gram::Class(s.to_string()),
+            Expected::Ranges(v) => Gram::Ranges(v),
+            Expected::Except(e) => Gram::Not(Box::new(Gram::from_expected(*e))),
+            Expected::OneOf(v) => v
+                .into_iter()
+                .map(Gram::from_expected)
+                .reduce(Gram::alt)
+                .unwrap_or_else(|| Gram::Other("nothing".to_string())),
+            Expected::Named(s) => Gram::Other(s.to_string()),
+            Expected::ObOn(p, _) => Gram::Other(short_type_name(p)),
+            e => Gram::Other(e.sentence()),
+        }
+    }
+
+    pub(crate) fn is_named_class(s: &str) -> bool {
+        s.starts_with(|c: char| c.is_ascii_uppercase()) && s.chars().all(char::is_alphanumeric)
+    }
+
+    /// How tightly the EBNF binds, so parentheses are only added where needed
+    fn prec(&self) -> usize {
+        match self {
+            Gram::Seq(v) | Gram::Alt(v) if v.len() == 1 => v[0].prec(),
+            Gram::Alt(_) => 0,
+            Gram::Seq(v) if !v.is_empty() => 1,
+            Gram::Opt(_) | Gram::Star(_) | Gram::Plus(_) => 2,
+            _ => 3,
+        }
+    }
+
+    fn ebnf_in(&self, prec: usize) -> String {
+        let s = self.to_ebnf();
+        match self.prec() < prec {
+            true => format!("({})", s),
+            false => s,
+        }
+    }
+
+    /// The node as an EBNF expression, in the W3C style
+    pub fn to_ebnf(&self) -> String {
+        match self {
+            Gram::Terminal(s) if s.contains('"') => format!("'{}'", s.escape_default()),
+            Gram::Terminal(s) => format!("\"{}\"", s.escape_default()),
+            Gram::Class(s) if Gram::is_named_class(s) => s.clone(),
+            Gram::Class(s) => format!("[{}]", s.escape_default()),
+            Gram::Ranges(v) => format!("[{}]", ranges_text(v)),
+            Gram::Not(g) => match &**g {
+                Gram::Class(s) if !Gram::is_named_class(s) => {
+                    format!("[^{}]", s.escape_default())
+                }
+                Gram::Ranges(v) => format!("[^{}]", ranges_text(v)),
+                g => format!("(Char - {})", g.ebnf_in(3)),
+            },
+            Gram::Rule(r) => r.name.to_string(),
+            Gram::Seq(v) if v.is_empty() => "\"\"".to_string(),
+            Gram::Seq(v) => {
+                let parts: Vec<String> = v.iter().map(|g| g.ebnf_in(1)).collect();
+                parts.join(" ")
+            }
+            Gram::Alt(v) => {
+                let parts: Vec<String> = v.iter().map(|g| g.ebnf_in(1)).collect();
+                parts.join(" | ")
+            }
+            Gram::Opt(g) => format!("{}?", g.ebnf_in(3)),
+            Gram::Star(g) => format!("{}*", g.ebnf_in(3)),
+            Gram::Plus(g) => format!("{}+", g.ebnf_in(3)),
+            Gram::Other(s) => format!("<{}>", s),
+        }
+    }
+
+    /// The node as JSON, using the node types of railroad diagram generators:
+    /// Terminal, NonTerminal, Comment, Sequence, Choice, Optional, ZeroOrMore and OneOrMore
+    pub fn to_json(&self) -> String {
+        let text = |t: &str, s: &str| format!("{{\"type\":\"{}\",\"text\":{}}}", t, json_str(s));
+        let items = |t: &str, v: &[Gram]| {
+            let parts: Vec<String> = v.iter().map(|g| g.to_json()).collect();
+            format!("{{\"type\":\"{}\",\"items\":[{}]}}", t, parts.join(","))
+        };
+        let item = |t: &str, g: &Gram| format!("{{\"type\":\"{}\",\"item\":{}}}", t, g.to_json());
+        match self {
+            Gram::Terminal(s) => text("Terminal", s),
+            Gram::Class(_) | Gram::Ranges(_) | Gram::Not(_) => text("Terminal", &self.to_ebnf()),
+            Gram::Rule(r) => text("NonTerminal", r.name),
+            Gram::Other(s) => text("Comment", s),
+            Gram::Seq(v) => items("Sequence", v),
+            Gram::Alt(v) => items("Choice", v),
+            Gram::Opt(g) => item("Optional", g),
+            Gram::Star(g) => item("ZeroOrMore", g),
+            Gram::Plus(g) => item("OneOrMore", g),
+        }
+    }
+
+    fn rules_used(&self, res: &mut Vec<RuleRef>) {
+        match self {
+            Gram::Rule(r) => res.push(r.clone()),
+            Gram::Not(g) | Gram::Opt(g) | Gram::Star(g) | Gram::Plus(g) => g.rules_used(res),
+            Gram::Seq(v) | Gram::Alt(v) => v.iter().for_each(|g| g.rules_used(res)),
+            _ => {}
+        }
+    }
+}
+
+fn json_str(s: &str) -> String {
+    let mut res = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\t' => res.push_str("\\t"),
+            '\r' => res.push_str("\\r"),
+            c if (c as u32) < 0x20 => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// A parser that parses as 'p', but describes itself with the function 'g', made with
+/// ```described```
+#[derive(Clone)]
+pub struct Described<P> {
+    p: P,
+    g: fn() -> Gram,
+}
+
+impl<P> Owned for Described<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for Described<P> {
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        self.p.parse(it)
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        (self.g)()
+    }
+}
+
+/// Gives 'p' the description 'g', for parsers written as functions, which can not be looked
+/// into, and would otherwise be ```Gram::Other```. Parsing is unchanged, and nothing checks
+/// that 'g' matches what 'p' parses, but ```Generator::sample``` only returns inputs that 'p'
+/// parses.
+///
+/// ```rust
+/// use gobble::*;
+/// fn hex<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
+///     "0x".ig_then(HexDigit.plus()).parse(it)
+/// }
+/// let p = described(hex, || {
+///     Gram::seq(Gram::Terminal("0x".into()), Gram::Class("HexDigit".into()).plus())
+/// });
+/// assert_eq!(p.describe().to_ebnf(), r#""0x" HexDigit+"#);
+/// assert_eq!(p.parse_s("0xff"), Ok("ff".to_string()));
+/// ```
+pub fn described<P>(p: P, g: fn() -> Gram) -> Described<P> {
+    Described { p, g }
+}
+
+/// Every rule reachable from a parser, with its definition, in the order first reached
+#[derive(Debug, Clone)]
+pub struct Grammar {
+    pub rules: Vec<(&'static str, Gram)>,
+}
+
+impl Grammar {
+    /// If 'p' is not a ```parser!``` rule itself, it becomes the first rule, called "start"
+    pub fn of<I: ?Sized + Input, P: Parser<I>>(p: &P) -> Grammar {
+        let mut rules = Vec::new();
+        let mut todo = Vec::new();
+        let mut seen = Vec::new();
+        match p.describe() {
+            Gram::Rule(r) => todo.push(r),
+            g => {
+                g.rules_used(&mut todo);
+                todo.reverse();
+                rules.push(("start", g));
+            }
+        }
+        while let Some(r) = todo.pop() {
+            if seen.contains(&r.key) {
+                continue;
+            }
+            //references back into a recursive grammar come after its definition
+            let g = match r.definition() {
+                Some(g) => g,
+                None => continue,
+            };
+            let mut used = Vec::new();
+            g.rules_used(&mut used);
+            todo.extend(used.into_iter().rev());
+            seen.push(r.key);
+            rules.push((r.name, g));
+        }
+        Grammar { rules }
+    }
+
+    /// One line per rule, as "Name ::= definition"
+    pub fn to_ebnf(&self) -> String {
+        let mut res = String::new();
+        for (n, g) in &self.rules {
+            res.push_str(&format!("{} ::= {}\n", n, g.to_ebnf()));
+        }
+        res
+    }
+
+    /// A list of objects with the "name" of the rule, and the "diagram" from ```Gram::to_json```
+    pub fn to_json(&self) -> String {
+        let parts: Vec<String> = self
+            .rules
+            .iter()
+            .map(|(n, g)| format!("{{\"name\":{},\"diagram\":{}}}", json_str(n), g.to_json()))
+            .collect();
+        format!("[{}]", parts.join(","))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    parser! {
+        (Value->())
+        or3(
+            common::Int.ig(),
+            middle("[", sep_star(Value, ws__(",")), "]").ig(),
+            middle("{", sep_star(Pair, ws__(",")), "}").ig(),
+        )
+    }
+
+    parser! {
+        (Pair->())
+        (Alpha.plus(), ws__(":"), Value).ig()
+    }
+
+    #[test]
+    fn test_recursive_grammar_to_ebnf() {
+        let g = Grammar::of(&first(Value, EOI));
+        let names: Vec<&str> = g.rules.iter().map(|(n, _)| *n).collect();
+        assert_eq!(names, vec!["start", "Value", "Int", "UInt", "Pair"]);
+        assert_eq!(
+            g.to_ebnf(),
+            r#"start ::= Value <end of input>
+Value ::= Int | "[" (Value (WS* "," WS* Value)*)? "]" | "{" (Pair (WS* "," WS* Pair)*)? "}"
+Int ::= "-"? UInt
+UInt ::= NumDigit (NumDigit | "_")*
+Pair ::= Alpha+ WS* ":" WS* Value
+"#
+        );
+    }
+
+    mod other {
+        use crate::*;
+        parser! { (Pair->()) ("<", Alpha.plus(), ">").ig() }
+    }
+
+    #[test]
+    fn test_rules_are_kept_apart_by_type() {
+        let g = Grammar::of(&(Pair, other::Pair));
+        let names: Vec<&str> = g.rules.iter().map(|(n, _)| *n).collect();
+        assert_eq!(names, vec!["start", "Pair", "Value", "Int", "UInt", "Pair"]);
+        assert_eq!(g.rules[5].1.to_ebnf(), r#""<" Alpha+ ">""#);
+    }
+
+    #[test]
+    fn test_describe_to_json() {
+        let p = (maybe("-"), NumDigit.plus(), star(not("\"\n").one()));
+        assert_eq!(
+            p.describe().to_json(),
+            concat!(
+                r#"{"type":"Sequence","items":[{"type":"Optional","item":{"type":"Terminal","text":"-"}},"#,
+                r#"{"type":"OneOrMore","item":{"type":"Terminal","text":"NumDigit"}},"#,
+                r#"{"type":"ZeroOrMore","item":{"type":"Terminal","text":"[^\\\"\\n]"}}]}"#
+            )
+        );
+    }
+}
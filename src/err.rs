@@ -3,7 +3,10 @@ use std::fmt;
 use std::fmt::Debug;
 use thiserror::*;
 
+/// What a parser was looking for when it failed.
+/// New kinds may be added, so matches on it need a wildcard arm
 #[derive(Debug, PartialEq, Eq, Clone, Error, Hash)]
+#[non_exhaustive]
 pub enum Expected {
     EOI,
     Char(char),
@@ -13,6 +16,17 @@ pub enum Expected {
     Str(&'static str),
     OneOf(Vec<Expected>),
     Except(Box<Expected>),
+    /// A char that matches both, from ```CharBool::and```
+    Both(Box<Expected>, Box<Expected>),
+    LeftRec(&'static str),
+    /// The name of a rule, such as those made by ```parser!```
+    Named(&'static str),
+    /// An item of a non text input, such as a token, as shown by Debug
+    Item(String),
+    /// Text only known while parsing, such as a ```tag```
+    Text(String),
+    /// One char in the sorted ranges, shown like "[a-fA-F0-9]"
+    Ranges(Vec<(char, char)>),
 }
 
 impl fmt::Display for Expected {
@@ -33,6 +47,16 @@ impl fmt::Display for Expected {
                 write!(f, ")")
             }
             Except(e) => write!(f, " Except : ({})", e),
+            Both(a, b) => write!(f, "({} And {})", a, b),
+            Named(s) => write!(f, "{:?}", s),
+            Item(s) => write!(f, "{}", s),
+            Text(s) => write!(f, "{:?}", s),
+            Ranges(v) => write!(f, "[{}]", crate::charset::ranges_text(v)),
+            LeftRec(s) => write!(
+                f,
+                "{} not to call itself at the same position (try left_rec)",
+                s
+            ),
         }
     }
 }
@@ -75,9 +99,115 @@ impl Expected {
             false => a,
         }
     }
+
+    /// Literals come first when sorting
+    fn rank(&self) -> u8 {
+        match self {
+            Expected::Char(_) | Expected::Str(_) | Expected::Text(_) | Expected::Item(_) => 0,
+            Expected::Named(_) => 1,
+            Expected::EOI => 3,
+            _ => 2,
+        }
+    }
+
+    fn flatten_into(self, v: &mut Vec<Expected>) {
+        match self {
+            Expected::Nil => {}
+            Expected::OneOf(ov) => ov.into_iter().for_each(|e| e.flatten_into(v)),
+            Expected::Except(e) => v.push(Expected::Except(Box::new(e.simplify()))),
+            e => v.push(e),
+        }
+    }
+
+    /// Flattens nested OneOfs, removes Nil, and duplicates, and sorts the options so that
+    /// equivalent expectations come out the same. Options that read the same
+    /// (see ```sentence```), such as ```Char('a')``` and ```Str("a")```, count as duplicates.
+    ///
+    /// ```rust
+    /// use gobble::*;
+    /// use Expected::*;
+    /// let e = OneOf(vec![Char('('), OneOf(vec![Nil, Named("Ident"), Char('(')]), Named("Int")]);
+    /// assert_eq!(e.simplify(), OneOf(vec![Char('('), Named("Ident"), Named("Int")]));
+    /// assert_eq!(OneOf(vec![Nil, Str("a")]).simplify(), Str("a"));
+    /// assert_eq!(OneOf(vec![Str("a"), Named("A"), Char('a')]).simplify().sentence(), "one of: `a`, A");
+    /// ```
+    pub fn simplify(self) -> Self {
+        let mut v = Vec::new();
+        self.flatten_into(&mut v);
+        v.sort_by_cached_key(|e| (e.rank(), e.sentence()));
+        v.dedup_by_key(|e| e.sentence());
+        match v.len() {
+            0 => Expected::Nil,
+            1 => v.pop().unwrap(),
+            _ => Expected::OneOf(v),
+        }
+    }
+
+    /// A human friendly description, to follow the word "expected"
+    ///
+    /// ```rust
+    /// use gobble::*;
+    /// let p = or_ig!('(', common::Ident, common::Int, '(');
+    /// let e = p.parse_s("+").unwrap_err();
+    /// assert_eq!(e.exp.sentence(), "one of: `(`, Ident, Int");
+    /// ```
+    pub fn sentence(&self) -> String {
+        use Expected::*;
+        match self {
+            EOI => "end of input".to_string(),
+            Char(c) => format!("`{}`", c.escape_default()),
+            Nil => "nothing".to_string(),
+            CharIn(s) => format!("a char in {:?}", s),
+            ObOn(p, _) => short_type_name(p),
+            Str(s) => format!("`{}`", s.escape_default()),
+            Named(s) => s.to_string(),
+            Item(s) => format!("`{}`", s),
+            Text(s) => format!("`{}`", s.escape_default()),
+            Ranges(_) => format!("a char in {}", self),
+            OneOf(_) => match self.clone().simplify() {
+                OneOf(v) => {
+                    let parts: Vec<String> = v.iter().map(|e| e.sentence()).collect();
+                    format!("one of: {}", parts.join(", "))
+                }
+                e => e.sentence(),
+            },
+            Except(e) => format!("anything except {}", e.sentence()),
+            Both(a, b) => format!("both {} and {}", a.sentence(), b.sentence()),
+            LeftRec(_) => self.to_string(),
+        }
+    }
 }
 
+/// Removes the module paths from a type name
+///
+/// ```rust
+/// use gobble::*;
+/// assert_eq!(
+///     short_type_name("gobble::chars::CharStar<gobble::chars::Alpha>"),
+///     "CharStar<Alpha>"
+/// );
+/// ```
+pub fn short_type_name(s: &str) -> String {
+    let mut res = String::new();
+    let mut word = String::new();
+    for c in s.chars() {
+        match c {
+            ':' => word.clear(),
+            c if c.is_alphanumeric() || c == '_' => word.push(c),
+            c => {
+                res.push_str(&word);
+                word.clear();
+                res.push(c);
+            }
+        }
+    }
+    res.push_str(&word);
+    res
+}
+
+/// Fields may be added, so build one with ```PErr::new``` or ```LCChars::err```
 #[derive(Clone, PartialEq, Eq, Error, Hash)]
+#[non_exhaustive]
 pub struct PErr<'a> {
     pub exp: Expected,
     pub found: &'a str,
@@ -86,6 +216,8 @@ pub struct PErr<'a> {
     pub col: usize,
     pub is_brk: bool,
     pub child: Option<Box<PErr<'a>>>,
+    /// The names of the rules this error happened within, innermost first
+    pub context: Box<[&'static str]>,
 }
 
 fn compare_index(a: &Option<usize>, b: &Option<usize>) -> Ordering {
@@ -110,6 +242,25 @@ pub fn n_chars(s: &str, n: usize) -> String {
 }
 
 impl<'a> PErr<'a> {
+    pub fn new(
+        exp: Expected,
+        found: &'a str,
+        index: Option<usize>,
+        line: usize,
+        col: usize,
+    ) -> Self {
+        PErr {
+            exp,
+            found,
+            index,
+            line,
+            col,
+            is_brk: false,
+            child: None,
+            context: Box::new([]),
+        }
+    }
+
     pub fn longer(mut self, b: Self) -> Self {
         match compare_index(&self.index, &b.index) {
             Ordering::Greater => self,
@@ -117,6 +268,9 @@ impl<'a> PErr<'a> {
             _ => {
                 self.child = join_children(self.child, b.child);
                 self.exp = self.exp.join(b.exp);
+                if self.context.is_empty() {
+                    self.context = b.context;
+                }
                 self
             }
         }
@@ -135,6 +289,9 @@ impl<'a> PErr<'a> {
             _ => {
                 self.child = join_children(self.child, b.child);
                 self.exp = self.exp.join(b.exp);
+                if self.context.is_empty() {
+                    self.context = b.context;
+                }
                 self
             }
         }
@@ -156,9 +313,18 @@ impl<'a> PErr<'a> {
             index: self.index,
             is_brk: self.is_brk,
             child: self.child.map(|v| Box::new((*v).strung())),
+            context: self.context,
         }
     }
 
+    /// Adds the name of a rule this error happened within
+    pub fn in_context(mut self, name: &'static str) -> Self {
+        let mut v = self.context.into_vec();
+        v.push(name);
+        self.context = v.into_boxed_slice();
+        self
+    }
+
     /*    pub fn wrap(mut self, ne: Self) -> Self {
         match self.child {
             Some(c) => self.child = Some(Box::new(c.wrap(ne))),
@@ -188,11 +354,11 @@ impl<'a> fmt::Debug for PErr<'a> {
             Some(n) => n.to_string(),
             None => "EOI".to_string(),
         };
-        write!(
+        writeln!(
             f,
-            "Expected '{}', Found '{}', at (i={},l={},c={})\n",
+            "Expected '{}', Found '{}', at (i={},l={},c={})",
             self.exp,
-            n_chars(&self.found, 10),
+            n_chars(self.found, 10),
             i_str,
             self.line,
             self.col
@@ -209,11 +375,11 @@ impl<'a> fmt::Display for PErr<'a> {
             Some(n) => n.to_string(),
             None => "EOI".to_string(),
         };
-        write!(
+        writeln!(
             f,
-            "Expected '{}', Found '{}', at (i={},l={},c={})\n",
+            "Expected '{}', Found '{}', at (i={},l={},c={})",
             self.exp,
-            n_chars(&self.found, 10),
+            n_chars(self.found, 10),
             i_str,
             self.line,
             self.col
@@ -226,7 +392,9 @@ impl<'a> fmt::Display for PErr<'a> {
 }
 
 //The StrungError has the String it was parsed from attached to it.
+/// Fields may be added, so build one with ```StrungError::new``` or ```PErr::strung```
 #[derive(Clone, Error, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct StrungError {
     pub exp: Expected,
     pub found: String,
@@ -235,6 +403,35 @@ pub struct StrungError {
     pub col: usize,
     pub is_brk: bool,
     pub child: Option<Box<StrungError>>,
+    /// The names of the rules this error happened within, innermost first
+    pub context: Box<[&'static str]>,
+}
+
+impl StrungError {
+    pub fn new(
+        exp: Expected,
+        found: String,
+        index: Option<usize>,
+        line: usize,
+        col: usize,
+    ) -> Self {
+        StrungError {
+            exp,
+            found,
+            index,
+            line,
+            col,
+            is_brk: false,
+            child: None,
+            context: Box::new([]),
+        }
+    }
+
+    /// The context as a trail from the outermost rule in, eg "FSig > argument list"
+    pub fn breadcrumbs(&self) -> String {
+        let v: Vec<&str> = self.context.iter().rev().cloned().collect();
+        v.join(" > ")
+    }
 }
 
 impl fmt::Debug for StrungError {
@@ -243,9 +440,9 @@ impl fmt::Debug for StrungError {
             Some(n) => n.to_string(),
             None => "EOI".to_string(),
         };
-        write!(
+        writeln!(
             f,
-            "Expected '{}', Found '{}', at (i={},l={},c={})\n",
+            "Expected '{}', Found '{}', at (i={},l={},c={})",
             self.exp, self.found, i_str, self.line, self.col
         )
     }
@@ -256,9 +453,9 @@ impl fmt::Display for StrungError {
             Some(n) => n.to_string(),
             None => "EOI".to_string(),
         };
-        write!(
+        writeln!(
             f,
-            "Expected '{}', Found '{}', at (i={},l={},c={})\n",
+            "Expected '{}', Found '{}', at (i={},l={},c={})",
             self.exp, self.found, i_str, self.line, self.col
         )?;
         if let Some(ref c) = self.child {
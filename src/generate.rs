@@ -0,0 +1,381 @@
+//! Random inputs built from the description of a grammar, for property tests and fuzzing.
+//!
+//! A ```Generator``` walks the ```Gram``` from ```Parser::describe```, choosing between
+//! alternatives and how many times to repeat with its own small random number generator, so it
+//! needs nothing outside of this crate, and the same seed always gives the same inputs.
+//! Past the depth limit, it takes the shortest way out of any recursive rules.
+//!
+//! Parsers that can not be looked into (```Gram::Other```) can not be generated, give them a
+//! description with ```described```.
+//!
+//! The description does not say how the parser chooses, and parsers do not backtrack into a
+//! repeat or ```maybe``` that has already matched, so an input made from the description may
+//! still not parse, as with ```(maybe(Alpha.one()), "x")``` given "x".
+//! ```sample``` checks each input with the parser, and tries again until one parses,
+//! ```gram``` works from the description alone, so is only a best effort.
+//!
+//! ```rust
+//! use gobble::*;
+//! let sig = || ("(", sep_until(common::Ident, ",", ")"));
+//! let mut gen = Generator::new(7);
+//! for _ in 0..20 {
+//!     let s = gen.sample(&sig()).unwrap();
+//!     assert!(first(sig(), EOI).parse_s(&s).is_ok(), "{}", s);
+//! }
+//! assert_eq!(Generator::new(7).sample(&sig()), Generator::new(7).sample(&sig()));
+//! ```
+use crate::describe::{Gram, RuleKey, RuleRef};
+use crate::iter::LCChars;
+use crate::ptrait::Parser;
+use std::collections::HashMap;
+
+/// A xorshift64* random number generator, good enough for choosing, and never zero
+#[derive(Debug, Clone)]
+pub struct Rng {
+    s: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng {
+            s: seed ^ 0x2545_F491_4F6C_DD1D,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        if self.s == 0 {
+            self.s = 0x9E37_79B9_7F4A_7C15;
+        }
+        self.s ^= self.s >> 12;
+        self.s ^= self.s << 25;
+        self.s ^= self.s >> 27;
+        self.s.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// A number from 0 up to but not including 'n', which must not be 0
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+const PLAIN: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ";
+const OTHERS: &str =
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 .,;:-+*/=()[]{}";
+
+/// The chars a named class is generated from. Any uses plain chars to avoid
+/// making terminators and escapes by accident
+fn named_class(s: &str) -> Option<&'static str> {
+    match s {
+        "Alpha" => Some("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+        "NumDigit" => Some("0123456789"),
+        "HexDigit" => Some("0123456789abcdefABCDEF"),
+        "WS" => Some(" \t"),
+        "WSL" => Some(" \t\n"),
+        "Any" => Some(PLAIN),
+        _ => None,
+    }
+}
+
+const FAR: usize = usize::MAX / 2;
+
+/// Makes random inputs for a grammar, see the module docs
+pub struct Generator {
+    rng: Rng,
+    depth: usize,
+    max_depth: usize,
+    max_repeat: usize,
+    max_tries: usize,
+    rules: HashMap<RuleKey, Gram>,
+    costs: HashMap<RuleKey, usize>,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            rng: Rng::new(seed),
+            depth: 0,
+            max_depth: 8,
+            max_repeat: 3,
+            max_tries: 100,
+            rules: HashMap::new(),
+            costs: HashMap::new(),
+        }
+    }
+
+    /// How many rules deep to go before taking the shortest way out
+    pub fn max_depth(mut self, n: usize) -> Self {
+        self.max_depth = n;
+        self
+    }
+
+    /// The most times to repeat anything, while under the depth limit
+    pub fn max_repeat(mut self, n: usize) -> Self {
+        self.max_repeat = n;
+        self
+    }
+
+    /// How many inputs ```sample``` makes, looking for one that parses
+    pub fn max_tries(mut self, n: usize) -> Self {
+        self.max_tries = n;
+        self
+    }
+
+    /// A random input that 'p' parses all of. None if part of it can not be generated,
+    /// or if none of ```max_tries``` inputs parsed
+    pub fn sample<P: Parser>(&mut self, p: &P) -> Option<String> {
+        let g = p.describe();
+        for _ in 0..self.max_tries {
+            let s = self.gram(&g)?;
+            if let Ok((it, _, _)) = p.parse(&LCChars::str(&s)) {
+                if it.rest().is_empty() {
+                    return Some(s);
+                }
+            }
+        }
+        None
+    }
+
+    /// A random input made from 'g' alone, which may not parse, see the module docs
+    pub fn gram(&mut self, g: &Gram) -> Option<String> {
+        self.depth = 0;
+        let mut res = String::new();
+        self.push(g, &mut res)?;
+        Some(res)
+    }
+
+    /// The definition of the rule, kept so it is only built once.
+    /// One carried on the reference replaces any kept, as the address of a ```recursive```
+    /// grammar may have been used by another before
+    fn rule(&mut self, r: &RuleRef) -> Option<Gram> {
+        if r.is_built() {
+            let def = r.definition()?;
+            if self.rules.insert(r.key, def.clone()).is_some() {
+                self.costs.remove(&r.key);
+            }
+            return Some(def);
+        }
+        if let Some(def) = self.rules.get(&r.key) {
+            return Some(def.clone());
+        }
+        let def = r.definition()?;
+        self.rules.insert(r.key, def.clone());
+        Some(def)
+    }
+
+    /// The fewest rules deep 'g' can be generated in, FAR if it can not finish
+    fn cost(&mut self, g: &Gram, stack: &mut Vec<RuleKey>) -> usize {
+        match g {
+            Gram::Rule(r) => {
+                if let Some(c) = self.costs.get(&r.key) {
+                    return *c;
+                }
+                if stack.contains(&r.key) {
+                    return FAR;
+                }
+                let def = match self.rule(r) {
+                    Some(def) => def,
+                    None => return FAR,
+                };
+                stack.push(r.key);
+                let c = self.cost(&def, stack).saturating_add(1).min(FAR);
+                stack.pop();
+                self.costs.insert(r.key, c);
+                c
+            }
+            Gram::Seq(v) => v.iter().map(|g| self.cost(g, stack)).max().unwrap_or(0),
+            Gram::Alt(v) => v.iter().map(|g| self.cost(g, stack)).min().unwrap_or(FAR),
+            Gram::Plus(g) => self.cost(g, stack),
+            _ => 0,
+        }
+    }
+
+    /// A random char from 's', None if there are none to pick from
+    fn pick(&mut self, s: &str) -> Option<char> {
+        let cs: Vec<char> = s.chars().collect();
+        match cs.len() {
+            0 => None,
+            n => Some(cs[self.rng.below(n)]),
+        }
+    }
+
+    fn push(&mut self, g: &Gram, res: &mut String) -> Option<()> {
+        let deep = self.depth >= self.max_depth;
+        match g {
+            Gram::Terminal(s) => res.push_str(s),
+            Gram::Class(s) => match named_class(s) {
+                Some(cs) => res.push(self.pick(cs)?),
+                None if Gram::is_named_class(s) => return None,
+                None => res.push(self.pick(s)?),
+            },
+            Gram::Ranges(v) if v.is_empty() => return None,
+            Gram::Ranges(v) => {
+                let (a, b) = v[self.rng.below(v.len())];
+                let n = self.rng.below((b as u32 - a as u32) as usize + 1) as u32;
+                res.push(std::char::from_u32(a as u32 + n).unwrap_or(a));
+            }
+            Gram::Not(g) => match &**g {
+                //a named class only lists part of its chars, so others may still be in it
+                Gram::Class(s) if named_class(s).is_none() && !Gram::is_named_class(s) => {
+                    let cs: String = OTHERS.chars().filter(|c| !s.contains(*c)).collect();
+                    res.push(self.pick(&cs)?);
+                }
+                Gram::Ranges(v) => {
+                    let cs: String = OTHERS
+                        .chars()
+                        .filter(|c| !v.iter().any(|(a, b)| a <= c && c <= b))
+                        .collect();
+                    res.push(self.pick(&cs)?);
+                }
+                Gram::Terminal(s) if s.chars().count() == 1 => {
+                    let cs: String = OTHERS.chars().filter(|c| !s.contains(*c)).collect();
+                    res.push(self.pick(&cs)?);
+                }
+                _ => return None,
+            },
+            Gram::Rule(r) => {
+                let def = self.rule(r)?;
+                self.depth += 1;
+                let r = self.push(&def, res);
+                self.depth -= 1;
+                r?;
+            }
+            Gram::Seq(v) => {
+                for g in v {
+                    self.push(g, res)?;
+                }
+            }
+            Gram::Alt(v) if v.is_empty() => return None,
+            Gram::Alt(v) => {
+                let i = match deep {
+                    true => {
+                        let costs: Vec<usize> =
+                            v.iter().map(|g| self.cost(g, &mut Vec::new())).collect();
+                        let low = costs.iter().min().copied().unwrap_or(0);
+                        costs.iter().position(|c| *c == low).unwrap_or(0)
+                    }
+                    false => self.rng.below(v.len()),
+                };
+                self.push(&v[i], res)?;
+            }
+            Gram::Opt(g) => {
+                if !deep && self.rng.below(2) == 1 {
+                    self.push(g, res)?;
+                }
+            }
+            Gram::Star(r) | Gram::Plus(r) => {
+                let min = match g {
+                    Gram::Plus(_) => 1,
+                    _ => 0,
+                };
+                let n = match deep {
+                    true => min,
+                    false => min + self.rng.below(self.max_repeat.max(min) + 1 - min),
+                };
+                for _ in 0..n {
+                    self.push(r, res)?;
+                }
+            }
+            Gram::Other(_) => return None,
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    parser! {
+        (Value->())
+        or3(
+            common::Float.ig(),
+            middle("[", sep_until(Value, ws__(","), "]"), WS.istar()).ig(),
+            common::Quoted.ig(),
+        )
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        let mut gen = Generator::new(42);
+        for _ in 0..100 {
+            let s = gen.sample(&common::Float).unwrap();
+            let f = first(common::Float, EOI).parse_s(&s);
+            assert!(f.is_ok(), "{}", s);
+
+            let s = gen.sample(&common::Quoted).unwrap();
+            assert!(first(common::Quoted, EOI).parse_s(&s).is_ok(), "{}", s);
+        }
+
+        //recursive rules stop at the depth limit
+        let mut gen = Generator::new(3).max_depth(4).max_repeat(4);
+        let mut longest = 0;
+        for _ in 0..50 {
+            let s = gen.sample(&Value).unwrap();
+            assert!(first(Value, EOI).parse_s(&s).is_ok(), "{}", s);
+            longest = longest.max(s.len());
+        }
+        assert!(longest > 10);
+
+        //as do recursive grammars built at runtime
+        let list = recursive("List", |list| middle("[", sep_star(list, ","), "]").ig());
+        for _ in 0..20 {
+            let s = gen.sample(&list).unwrap();
+            assert!(first(list.clone(), EOI).parse_s(&s).is_ok(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_samples_are_checked() {
+        //maybe takes the "x" when it generates nothing
+        let p = || (maybe(Alpha.one()), "x");
+        let g = p().describe();
+        let mut gen = Generator::new(9);
+        let made: Vec<String> = (0..20).map(|_| gen.gram(&g).unwrap()).collect();
+        assert!(made.iter().any(|s| p().parse_s(s).is_err()));
+        for _ in 0..20 {
+            let s = gen.sample(&p()).unwrap();
+            assert!(first(p(), EOI).parse_s(&s).is_ok(), "{}", s);
+        }
+
+        //a repeat always takes what follows it, so nothing can parse
+        assert_eq!(gen.max_tries(10).sample(&(Alpha.star(), "x")), None);
+    }
+
+    #[test]
+    fn test_sets_with_nothing_to_pick_fail() {
+        let not = |g| Gram::Not(Box::new(g));
+        let mut gen = Generator::new(5);
+        //only some of a named class is known, so nothing is safe to pick outside it
+        assert_eq!(gen.gram(&not(Gram::Class("UAlpha".into()))), None);
+        assert_eq!(gen.gram(&not(Gram::Class("Alpha".into()))), None);
+        //everything the generator knows is excluded
+        let all = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 .,;:-+*/=()[]{}";
+        assert_eq!(gen.gram(&not(Gram::Class(all.into()))), None);
+        assert_eq!(
+            gen.gram(&not(Gram::Class(",;".into()))).map(|s| s.len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_generator_is_deterministic() {
+        let run = |seed| {
+            let mut gen = Generator::new(seed);
+            (0..10)
+                .map(|_| gen.sample(&common::Float).unwrap())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1), run(2));
+
+        //functions can not be looked into
+        fn int<'a>(it: &LCChars<'a>) -> ParseRes<'a, isize> {
+            common::Int.parse(it)
+        }
+        assert_eq!(Generator::new(1).sample(&int), None);
+        let int = described(int, || Gram::Plus(Box::new(Gram::Class("NumDigit".into()))));
+        assert!(common::Int
+            .parse_s(&Generator::new(1).sample(&int).unwrap())
+            .is_ok());
+    }
+}
@@ -0,0 +1,300 @@
+//! Parsers for layout sensitive syntax, where indentation marks out blocks, as in Python or YAML.
+//!
+//! ```LCChars``` carries a stack of indentation levels. ```indented_block``` is used at the end
+//! of a line, and takes the indent of the next line as the level of the block. Each item of
+//! the block must start on a new line at that level, and the block ends when a line dedents
+//! to any enclosing level. Dedenting to a level that was never entered is an error.
+//!
+//! Blank lines (only spaces and tabs) are skipped. Indentation is measured as the column,
+//! so tabs follow ```Cols``` like everything else.
+//!
+//! ```rust
+//! use gobble::*;
+//! #[derive(Debug, PartialEq)]
+//! enum Stmt {
+//!     Call(String),
+//!     If(String, Vec<Stmt>),
+//! }
+//!
+//! fn stmt<'a>(it: &LCChars<'a>) -> ParseRes<'a, Stmt> {
+//!     or(
+//!         ("if ", common::Ident, ":", indented_block(stmt)).map(|(_, c, _, b)| Stmt::If(c, b)),
+//!         common::Ident.map(Stmt::Call),
+//!     )
+//!     .parse(it)
+//! }
+//!
+//! let src = "if a:\n  b\n  if c:\n    d\n\n  e\nf";
+//! let prog = sep_until_ig(stmt, SameIndent, eoi).parse_s(src).unwrap();
+//! assert_eq!(
+//!     prog,
+//!     vec![
+//!         Stmt::If(
+//!             "a".to_string(),
+//!             vec![
+//!                 Stmt::Call("b".to_string()),
+//!                 Stmt::If("c".to_string(), vec![Stmt::Call("d".to_string())]),
+//!                 Stmt::Call("e".to_string()),
+//!             ]
+//!         ),
+//!         Stmt::Call("f".to_string()),
+//!     ]
+//! );
+//! ```
+use crate::borrow::Owned;
+use crate::chars::{CharBool, WS};
+use crate::describe::Gram;
+use crate::err::Expected;
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+use crate::skip::do_skip_c;
+
+enum NextLine<'a> {
+    /// The start of the next line with content, after its indentation, and that indentation
+    Line(LCChars<'a>, usize),
+    /// The input ends before another line with content
+    End,
+    /// There is more on this line
+    NotAtEol,
+}
+
+fn next_line<'a>(it: &LCChars<'a>) -> NextLine<'a> {
+    let mut it = it.clone();
+    let mut first = true;
+    loop {
+        it = match do_skip_c(&it, &WS, 0, false) {
+            Ok((nit, _, _)) => nit,
+            Err(_) => return NextLine::NotAtEol,
+        };
+        let mut it2 = it.clone();
+        match it2.next() {
+            None => return NextLine::End,
+            Some('\n') => {
+                first = false;
+                it = it2;
+            }
+            Some('\r') if it2.as_str().starts_with('\n') => {
+                it2.next();
+                first = false;
+                it = it2;
+            }
+            Some(_) if first => return NextLine::NotAtEol,
+            Some(_) => {
+                let n = it.lc().1;
+                return NextLine::Line(it, n);
+            }
+        }
+    }
+}
+
+/// Parses one or more 'p' on the following lines, indented further than the current block.
+/// Use at the end of a line, such as after "if a:".
+pub fn indented_block<P: Parser>(p: P) -> IndentedBlock<P> {
+    IndentedBlock { p }
+}
+
+pub struct IndentedBlock<P> {
+    p: P,
+}
+
+impl<P> Owned for IndentedBlock<P> {}
+
+impl<P: Parser> Parser for IndentedBlock<P> {
+    type Out = Vec<P::Out>;
+    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Vec<P::Out>> {
+        let (mut it2, n) = match next_line(it) {
+            NextLine::Line(li, n) if n > it.indent() => (li, n),
+            NextLine::Line(li, _) => return li.err_rp(self),
+            _ => return it.err_rp(self),
+        };
+        it2.push_indent(n);
+        let mut res = Vec::new();
+        loop {
+            let (mut nit, v, c) = self.p.parse(&it2)?;
+            res.push(v);
+            match next_line(&nit) {
+                NextLine::Line(li, m) if m == n => it2 = li,
+                NextLine::Line(li, m) if m > n => {
+                    return Err(li.err(Expected::Named("no further indent")).brk())
+                }
+                NextLine::Line(li, m) if !nit.is_indent_level(m) => {
+                    let e = li.err(Expected::Named("a dedent to an enclosing block"));
+                    return Err(e.brk());
+                }
+                NextLine::NotAtEol => {
+                    return Err(nit.err(Expected::Named("end of line")).join_op(c));
+                }
+                NextLine::Line(_, _) | NextLine::End => {
+                    nit.pop_indent();
+                    return Ok((nit, res, c));
+                }
+            }
+        }
+    }
+    fn expected(&self) -> Expected {
+        Expected::Named("indented block")
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe().plus()
+    }
+}
+
+/// Moves from the end of a line to the start of the next line with content,
+/// which must be at the indent of the current block. Use between the items of a block.
+#[derive(Copy, Clone)]
+pub struct SameIndent;
+
+impl Owned for SameIndent {}
+
+impl Parser for SameIndent {
+    type Out = ();
+    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+        match next_line(it) {
+            NextLine::Line(li, n) if n == it.indent() => Ok((li, (), None)),
+            NextLine::Line(li, _) => li.err_rp(self),
+            _ => it.err_rp(self),
+        }
+    }
+    fn expected(&self) -> Expected {
+        Expected::Named("a new line at the same indent")
+    }
+}
+
+/// Whitespace that may continue onto following lines, as long as they are indented
+/// further than the current block, so one item can be folded over several lines.
+#[derive(Copy, Clone)]
+pub struct LineFold;
+
+impl Owned for LineFold {}
+
+impl Parser for LineFold {
+    type Out = ();
+    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+        let (mut it, _, _) = do_skip_c(it, &WS, 0, false)?;
+        while let NextLine::Line(li, n) = next_line(&it) {
+            if n <= it.indent() {
+                break;
+            }
+            it = li;
+        }
+        Ok((it, (), None))
+    }
+    fn expected(&self) -> Expected {
+        WS.expected()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[derive(Debug, PartialEq)]
+    pub enum Node {
+        Text(String),
+        Map(Vec<(String, Node)>),
+        List(Vec<Node>),
+    }
+
+    fn text<'a>(it: &LCChars<'a>) -> ParseRes<'a, Node> {
+        let word = || not(" \t\r\n").plus();
+        (word(), star(LineFold.ig_then(word())))
+            .map(|(a, v)| Node::Text(v.into_iter().fold(a, |a, w| a + " " + &w)))
+            .parse(it)
+    }
+
+    fn entry<'a>(it: &LCChars<'a>) -> ParseRes<'a, (String, Node)> {
+        (
+            common::Ident,
+            ":",
+            or3(
+                indented_block(entry).map(Node::Map),
+                indented_block("- ".ig_then(text)).map(Node::List),
+                WS.istar().ig_then(text),
+            ),
+        )
+            .map(|(k, _, v)| (k, v))
+            .parse(it)
+    }
+
+    fn doc() -> impl Parser<Out = Vec<(String, Node)>> {
+        sep_until_ig(entry, SameIndent, (WSL.istar(), eoi))
+    }
+
+    fn txt(s: &str) -> Node {
+        Node::Text(s.to_string())
+    }
+
+    #[test]
+    fn test_yaml_like_nesting() {
+        let src = "server:
+  host: example.com
+  ports:
+    - 80
+    - 443
+
+  about: a server that
+    folds onto
+    more lines
+debug: yes
+";
+        let v = doc().parse_s(src).unwrap();
+        assert_eq!(
+            v,
+            vec![
+                (
+                    "server".to_string(),
+                    Node::Map(vec![
+                        ("host".to_string(), txt("example.com")),
+                        ("ports".to_string(), Node::List(vec![txt("80"), txt("443")])),
+                        (
+                            "about".to_string(),
+                            txt("a server that folds onto more lines")
+                        ),
+                    ])
+                ),
+                ("debug".to_string(), txt("yes")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_crlf_line_ends() {
+        let src = "a:\r\n  b: one\r\n    two\r\n  c:\r\n    - 3\r\nd: 4\r\n";
+        let v = doc().parse_s(src).unwrap();
+        assert_eq!(
+            v,
+            vec![
+                (
+                    "a".to_string(),
+                    Node::Map(vec![
+                        ("b".to_string(), txt("one two")),
+                        ("c".to_string(), Node::List(vec![txt("3")])),
+                    ])
+                ),
+                ("d".to_string(), txt("4")),
+            ]
+        );
+        assert_eq!(v, doc().parse_s(&src.replace("\r\n", "\n")).unwrap());
+    }
+
+    #[test]
+    fn test_bad_indentation() {
+        let e = doc().parse_s("a:\n    b: 1\n  c: 2").unwrap_err();
+        assert!(e.is_brk);
+        assert_eq!((e.line, e.col), (2, 2));
+        assert_eq!(e.exp, Expected::Named("a dedent to an enclosing block"));
+
+        //text folds deeper lines, so use a block of plain words
+        let e = ("a:", indented_block(common::Ident))
+            .parse_s("a:\n  b\n    c")
+            .unwrap_err();
+        assert_eq!((e.line, e.col), (2, 4));
+        assert_eq!(e.exp, Expected::Named("no further indent"));
+
+        //a block must be deeper than its parent
+        assert!(doc().parse_s("a:\nb: 1").is_err());
+        //the indent stack does not leak out of the block
+        let (it, _, _) = entry(&LCChars::str("a:\n  b: 1\nc: 2")).unwrap();
+        assert_eq!(it.indent(), 0);
+    }
+}
@@ -0,0 +1,336 @@
+//! Parsers are not limited to strings. ```LCChars``` can walk any type implementing ```Input```,
+//! and the combinators (```then```, ```or```, ```star```, ```sep_until``` and so on) work the
+//! same over all of them. This crate implements ```Input``` for ```str``` and for slices of any
+//! ```Clone + PartialEq``` item, so bytes (```[u8]```) and pre-lexed tokens can both be parsed.
+//!
+//! ```Parser``` takes the input type as a parameter, defaulting to ```str```, so
+//! ```impl Parser<Out = V>``` is still a string parser, and ```impl Parser<[u8], Out = V>```
+//! a byte parser.
+//!
+//! ```rust
+//! use gobble::*;
+//! #[derive(Clone, Debug, PartialEq)]
+//! enum Tk {
+//!     Num(i64),
+//!     Comma,
+//!     Open,
+//!     Close,
+//! }
+//!
+//! let num = tok_if(|t: &Tk| matches!(t, Tk::Num(_))).map(|t| match t {
+//!     Tk::Num(n) => n,
+//!     _ => 0,
+//! });
+//! let list = tok(Tk::Open).ig_then(sep_until_ig(num, tok(Tk::Comma), tok(Tk::Close)));
+//! let toks = vec![Tk::Open, Tk::Num(3), Tk::Comma, Tk::Num(4), Tk::Close];
+//! assert_eq!(list.parse_s(&toks), Ok(vec![3, 4]));
+//! ```
+use crate::borrow::{Owned, RefParser};
+use crate::err::Expected;
+use crate::iter::{ColUnit, Cols, LCChars};
+use crate::ptrait::{ParseRes, Parser};
+use crate::unicode::grapheme_joins;
+use std::fmt::Debug;
+use std::str::CharIndices;
+
+/// Something ```LCChars``` can walk over.
+/// Indexes are in whatever unit the input uses, bytes for str, and items for slices.
+pub trait Input {
+    type Item: Clone + PartialEq;
+    /// Iterates the items with their indexes
+    type Iter<'a>: Iterator<Item = (usize, Self::Item)> + Clone
+    where
+        Self: 'a;
+
+    fn items(&self) -> Self::Iter<'_>;
+
+    /// The remainder of the input not yet consumed by the iterator
+    fn rest<'a>(it: &Self::Iter<'a>) -> &'a Self;
+
+    /// The index the iterator is up to, which unlike its next item, exists at the end too
+    fn offset<'a>(it: &Self::Iter<'a>) -> usize;
+
+    /// The first 'n' units of the input
+    fn head(&self, n: usize) -> &Self;
+
+    /// The input after the first 'n' units
+    fn tail(&self, n: usize) -> &Self;
+
+    /// The text shown as "found" in errors, inputs that are not text show nothing
+    fn found(&self) -> &str {
+        ""
+    }
+
+    /// Does this item begin a new line, for counting lines and columns
+    fn is_newline(_i: &Self::Item) -> bool {
+        false
+    }
+
+    /// The column after item 'i', which started at 'col'. 'rest' is the input after it
+    fn next_col<'a>(_i: &Self::Item, _rest: &Self::Iter<'a>, col: usize, _cols: &Cols) -> usize {
+        col + 1
+    }
+}
+
+impl Input for str {
+    type Item = char;
+    type Iter<'a> = CharIndices<'a>;
+
+    fn items(&self) -> CharIndices<'_> {
+        self.char_indices()
+    }
+
+    fn rest<'a>(it: &Self::Iter<'a>) -> &'a str {
+        it.as_str()
+    }
+
+    fn offset<'a>(it: &Self::Iter<'a>) -> usize {
+        it.offset()
+    }
+
+    fn head(&self, n: usize) -> &str {
+        &self[..n]
+    }
+
+    fn tail(&self, n: usize) -> &str {
+        &self[n..]
+    }
+
+    fn found(&self) -> &str {
+        self
+    }
+
+    fn is_newline(c: &char) -> bool {
+        *c == '\n'
+    }
+
+    fn next_col<'a>(c: &char, rest: &Self::Iter<'a>, col: usize, cols: &Cols) -> usize {
+        match c {
+            '\t' if cols.tab > 1 => (col / cols.tab + 1) * cols.tab,
+            '\r' if cols.crlf && rest.as_str().starts_with('\n') => col,
+            _ => {
+                col + match cols.unit {
+                    ColUnit::Chars => 1,
+                    ColUnit::Bytes => c.len_utf8(),
+                    ColUnit::Utf16 => c.len_utf16(),
+                    ColUnit::Graphemes => match grapheme_joins(*c, rest.as_str()) {
+                        true => 0,
+                        false => 1,
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Iterates a slice, cloning out each item with its index
+#[derive(Clone, Debug)]
+pub struct SliceIter<'a, T> {
+    s: &'a [T],
+    i: usize,
+}
+
+impl<'a, T: Clone> Iterator for SliceIter<'a, T> {
+    type Item = (usize, T);
+    fn next(&mut self) -> Option<(usize, T)> {
+        let v = self.s.get(self.i)?.clone();
+        self.i += 1;
+        Some((self.i - 1, v))
+    }
+}
+
+impl<T: Clone + PartialEq> Input for [T] {
+    type Item = T;
+    type Iter<'a>
+        = SliceIter<'a, T>
+    where
+        T: 'a;
+
+    fn items(&self) -> SliceIter<'_, T> {
+        SliceIter { s: self, i: 0 }
+    }
+
+    fn rest<'a>(it: &Self::Iter<'a>) -> &'a [T] {
+        &it.s[it.i..]
+    }
+
+    fn offset<'a>(it: &Self::Iter<'a>) -> usize {
+        it.i
+    }
+
+    fn head(&self, n: usize) -> &[T] {
+        &self[..n]
+    }
+
+    fn tail(&self, n: usize) -> &[T] {
+        &self[n..]
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Tok<T> {
+    t: T,
+}
+
+impl<T> Owned for Tok<T> {}
+
+impl<T: Clone + PartialEq + Debug> Parser<[T]> for Tok<T> {
+    type Out = T;
+    fn parse<'a>(&self, it: &LCChars<'a, [T]>) -> ParseRes<'a, T, [T]> {
+        let mut it2 = it.clone();
+        match it2.next() {
+            Some(t) if t == self.t => Ok((it2, t, None)),
+            _ => it.err_rp(self),
+        }
+    }
+    fn expected(&self) -> Expected {
+        Expected::Item(format!("{:?}", self.t))
+    }
+}
+
+/// Matches one item of a slice input equal to 't', such as a byte or a token
+///
+/// ```rust
+/// use gobble::*;
+/// let p = (tok(b'<'), star(tok_if(|b: &u8| *b != b'>')), tok(b'>'));
+/// let (_, v, _) = p.parse_s(&b"<abc>"[..]).unwrap();
+/// assert_eq!(v, b"abc");
+/// ```
+pub fn tok<T>(t: T) -> Tok<T> {
+    Tok { t }
+}
+
+#[derive(Clone, Copy)]
+pub struct TokIf<F> {
+    f: F,
+}
+
+impl<F> Owned for TokIf<F> {}
+
+impl<T: Clone + PartialEq, F: Fn(&T) -> bool> Parser<[T]> for TokIf<F> {
+    type Out = T;
+    fn parse<'a>(&self, it: &LCChars<'a, [T]>) -> ParseRes<'a, T, [T]> {
+        let mut it2 = it.clone();
+        match it2.next() {
+            Some(t) if (self.f)(&t) => Ok((it2, t, None)),
+            _ => it.err_rp(self),
+        }
+    }
+}
+
+/// Matches one item of a slice input for which 'f' returns true.
+/// Use ```named``` to give it a better error.
+pub fn tok_if<T, F: Fn(&T) -> bool>(f: F) -> TokIf<F> {
+    TokIf { f }
+}
+
+#[derive(Clone, Copy)]
+pub struct Take {
+    n: usize,
+}
+
+impl<T: Clone + PartialEq> RefParser<[T]> for Take {
+    type Out<'a>
+        = &'a [T]
+    where
+        T: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, [T]>) -> ParseRes<'a, &'a [T], [T]> {
+        if it.rest().len() < self.n {
+            return it.err_r(Expected::Named("more items"));
+        }
+        let mut it2 = it.clone();
+        for _ in 0..self.n {
+            it2.next();
+        }
+        Ok((it2.clone(), it.slice_to(&it2), None))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::Named("more items")
+    }
+}
+
+/// Takes the next 'n' items of a slice input, failing if there are not that many left.
+/// This is a ```RefParser```, returning the items as a slice of the input
+pub fn take(n: usize) -> Take {
+    Take { n }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Tk<'src> {
+        Id(&'src str),
+        Num(i64),
+        Eq,
+        Semi,
+    }
+
+    //tokens borrow from the source they were lexed from
+    fn lex(src: &str) -> Vec<Tk<'_>> {
+        src.split_whitespace()
+            .map(|w| match w {
+                "=" => Tk::Eq,
+                ";" => Tk::Semi,
+                w => w.parse().map(Tk::Num).unwrap_or(Tk::Id(w)),
+            })
+            .collect()
+    }
+
+    fn id<'src>() -> impl Parser<[Tk<'src>], Out = &'src str> {
+        tok_if(|t: &Tk<'src>| matches!(t, Tk::Id(_)))
+            .map(|t| match t {
+                Tk::Id(s) => s,
+                _ => "",
+            })
+            .named("identifier")
+    }
+
+    fn num<'src>() -> impl Parser<[Tk<'src>], Out = i64> {
+        tok_if(|t: &Tk<'src>| matches!(t, Tk::Num(_))).map(|t| match t {
+            Tk::Num(n) => n,
+            _ => 0,
+        })
+    }
+
+    #[test]
+    fn test_token_input_combinators() {
+        use Tk::*;
+        let stmt = || (id(), tok(Eq), or(num(), id().map(|_| -1))).map(|(a, _, b)| (a, b));
+        let p = || sep_until_ig(stmt(), tok(Semi), eoi);
+        let src = String::from("a = 3 ; b = a");
+        let toks = lex(&src);
+        assert_eq!(p().parse_s(&toks), Ok(vec![("a", 3), ("b", -1)]));
+
+        let toks = lex("a = 3 ; =");
+        let e = p().parse_s(&toks).unwrap_err();
+        assert_eq!(e.index, Some(4));
+        assert_eq!(e.exp, Expected::Named("identifier"));
+        assert_eq!(e.found, "");
+    }
+
+    #[test]
+    fn test_byte_input_combinators() {
+        //each frame is a non zero tag byte followed by letters, frames repeat until a 0
+        let frame = tok_if(|b: &u8| *b > 0).then(star(tok_if(|b: &u8| *b >= b'a')));
+        let p = first(star(frame), tok(0));
+        let v = p.parse_s(&[2, b'h', b'i', 1, b'x', 0][..]).unwrap();
+        assert_eq!(v, vec![(2, b"hi".to_vec()), (1, b"x".to_vec())]);
+
+        assert!(p.parse_s(&[2, b'h', 7][..]).is_err());
+    }
+
+    #[test]
+    fn test_take_borrows_the_items() {
+        let src = [3, 1, 4, 1, 5];
+        let p = tok(3).ig_then(take(2)).then_ref(take(2));
+        let (a, b) = p.parse_ref_s(&src[..]).unwrap();
+        assert_eq!((a, b), (&[1, 4][..], &[1, 5][..]));
+        assert_eq!(a.as_ptr(), src[1..].as_ptr());
+
+        let e = take(6).parse_ref_s(&src[..]).unwrap_err();
+        assert_eq!(e.index, Some(0));
+        assert_eq!(e.exp, Expected::Named("more items"));
+    }
+}
@@ -1,23 +1,187 @@
 use crate::chars::CharBool;
-use crate::err::{Expected, PErr};
+use crate::err::{Expected, PErr, StrungError};
+use crate::input::Input;
 use crate::ptrait::{ParseRes, Parser};
+use crate::span::Loc;
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
 use std::str::{CharIndices, Chars};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-#[derive(Clone, Debug)]
-pub struct LCChars<'a> {
-    iter: CharIndices<'a>,
+/// A list of errors that have been recovered from.
+/// Each iterator only holds the errors found on the path it took,
+/// so errors from failed branches are dropped with them.
+#[derive(Debug)]
+struct Recovered {
+    e: StrungError,
+    prev: Option<Rc<Recovered>>,
+}
+
+fn list_recovered(mut r: &Option<Rc<Recovered>>) -> Vec<StrungError> {
+    let mut res = Vec::new();
+    while let Some(rc) = r {
+        res.push(rc.e.clone());
+        r = &rc.prev;
+    }
+    res.reverse();
+    res
+}
+
+/// The indentation levels entered by ```indented_block```, innermost first
+#[derive(Debug)]
+struct Indent {
+    n: usize,
+    prev: Option<Rc<Indent>>,
+}
+
+/// The position in the input, counting lines and columns as it goes.
+/// For str input (the default) this iterates chars, for other inputs see ```Input```
+pub struct LCChars<'a, I: ?Sized + Input + 'a = str> {
+    iter: I::Iter<'a>,
     l: usize,
     c: usize,
+    cols: Cols,
+    /// Added to indexes, when the input is part of a larger document
+    base: usize,
+    indent: Option<Rc<Indent>>,
+    /// User state, see ```get_state```. Never changed in place, so backtracking restores it
+    state: Option<Rc<dyn Any>>,
+    recovered: Option<Rc<Recovered>>,
+    /// Shared by every iterator from the same parse, holds the recovered errors of the path
+    /// that reached furthest, so they can still be reported if the parse fails
+    furthest: Option<Rc<RefCell<Option<Rc<Recovered>>>>>,
+    /// Which input this is, so results stored while parsing one input are never used for another
+    input: usize,
 }
 
-impl<'a> LCChars<'a> {
-    pub fn str(s: &'a str) -> Self {
+/// Each input given to an ```LCChars``` gets its own id, see ```LCChars::input_id```
+fn next_input_id() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+/// What a column counts. Indexes are always bytes for str, whichever is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColUnit {
+    Chars,
+    Bytes,
+    /// UTF-16 code units, as used by the Language Server Protocol
+    Utf16,
+    /// User perceived characters, (extended grapheme clusters), so "e\u{301}" is one column.
+    /// A char that carries its cluster on takes no column, so a point inside a cluster has
+    /// the column of its start
+    Graphemes,
+}
+
+/// How ```LCChars``` counts columns. The default counts one per char, with no special cases.
+///
+/// ```rust
+/// use gobble::*;
+/// let src = "\tö😀x";
+/// let at_x = |cols: Cols| {
+///     let it = LCChars::str(src).with_cols(cols);
+///     let (it, _, _) = (Any.one(), Any.one(), Any.one()).parse(&it).unwrap();
+///     it.lc()
+/// };
+/// assert_eq!(at_x(Cols::default()), (0, 3));
+/// assert_eq!(at_x(Cols::bytes()), (0, 7));
+/// assert_eq!(at_x(Cols::utf16()), (0, 4));
+/// assert_eq!(at_x(Cols::graphemes()), (0, 3));
+/// assert_eq!(at_x(Cols::default().tabs(4)), (0, 6));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cols {
+    pub unit: ColUnit,
+    /// Tabs move to the next multiple of this, 1 counts a tab like any other char
+    pub tab: usize,
+    /// Count "\r\n" as a single line break, giving the '\r' no column
+    pub crlf: bool,
+}
+
+impl Default for Cols {
+    fn default() -> Self {
+        Cols {
+            unit: ColUnit::Chars,
+            tab: 1,
+            crlf: false,
+        }
+    }
+}
+
+impl Cols {
+    pub fn bytes() -> Self {
+        Cols {
+            unit: ColUnit::Bytes,
+            ..Cols::default()
+        }
+    }
+
+    pub fn utf16() -> Self {
+        Cols {
+            unit: ColUnit::Utf16,
+            ..Cols::default()
+        }
+    }
+
+    pub fn graphemes() -> Self {
+        Cols {
+            unit: ColUnit::Graphemes,
+            ..Cols::default()
+        }
+    }
+
+    pub fn tabs(mut self, width: usize) -> Self {
+        self.tab = width.max(1);
+        self
+    }
+
+    pub fn crlf(mut self) -> Self {
+        self.crlf = true;
+        self
+    }
+}
+
+impl<'a, I: ?Sized + Input> Clone for LCChars<'a, I> {
+    fn clone(&self) -> Self {
         LCChars {
-            iter: s.char_indices(),
-            l: 0,
-            c: 0,
+            iter: self.iter.clone(),
+            l: self.l,
+            c: self.c,
+            cols: self.cols,
+            base: self.base,
+            indent: self.indent.clone(),
+            state: self.state.clone(),
+            recovered: self.recovered.clone(),
+            furthest: self.furthest.clone(),
+            input: self.input,
         }
     }
+}
+
+impl<'a, I: ?Sized + Input> fmt::Debug for LCChars<'a, I>
+where
+    I::Iter<'a>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LCChars")
+            .field("iter", &self.iter)
+            .field("l", &self.l)
+            .field("c", &self.c)
+            .field("cols", &self.cols)
+            .field("base", &self.base)
+            .field("indent", &self.indent)
+            .field("state", &self.state.is_some())
+            .field("recovered", &self.recovered)
+            .finish()
+    }
+}
+
+impl<'a> LCChars<'a> {
+    pub fn str(s: &'a str) -> Self {
+        LCChars::new(s)
+    }
 
     #[deprecated(since = "0.2.1", note = "use from_char_indices instead")]
     pub fn from_chars(iter: Chars<'a>) -> LCChars<'a> {
@@ -25,25 +189,111 @@ impl<'a> LCChars<'a> {
     }
 
     pub fn from_char_indices(iter: CharIndices<'a>) -> LCChars<'a> {
-        LCChars { iter, l: 0, c: 0 }
+        LCChars {
+            iter,
+            l: 0,
+            c: 0,
+            cols: Cols::default(),
+            base: 0,
+            indent: None,
+            state: None,
+            recovered: None,
+            furthest: None,
+            input: next_input_id(),
+        }
     }
 
     pub fn as_str(&self) -> &'a str {
         self.iter.as_str()
     }
 
-    pub fn err(&self, exp: Expected) -> PErr<'a> {
-        PErr {
-            exp,
-            found: &self.iter.as_str(),
-            index: self.index(),
-            line: self.l,
-            col: self.c,
-            is_brk: false,
-            child: None,
+    pub fn err_oc<C: CharBool>(&self, c: &C) -> Option<PErr<'a>> {
+        Some(self.err(c.expected()))
+    }
+}
+
+impl<'a, I: ?Sized + Input> LCChars<'a, I> {
+    pub fn new(s: &'a I) -> Self {
+        LCChars {
+            iter: s.items(),
+            l: 0,
+            c: 0,
+            cols: Cols::default(),
+            base: 0,
+            indent: None,
+            state: None,
+            recovered: None,
+            furthest: None,
+            input: next_input_id(),
+        }
+    }
+
+    /// Starts at 'start' in a larger document, so that indexes, lines and columns
+    /// in errors and positions refer to the whole document rather than 's'.
+    ///
+    /// ```rust
+    /// use gobble::*;
+    /// let doc = "# Title\n\n```\nlet x = 3;\nlet y = ;\n```";
+    /// let code = &doc[13..34];
+    /// let it = LCChars::at(code, Loc { index: 13, line: 3, col: 0 });
+    /// let stmt = ("let ", common::Ident, " = ", common::Int, ";\n");
+    /// let e = star_until_ig(stmt, eoi).parse(&it).unwrap_err();
+    /// assert_eq!((e.index, e.line, e.col), (Some(32), 4, 8));
+    /// assert_eq!(&doc[32..33], ";");
+    /// ```
+    pub fn at(s: &'a I, start: Loc) -> Self {
+        LCChars {
+            iter: s.items(),
+            l: start.line,
+            c: start.col,
+            cols: Cols::default(),
+            base: start.index,
+            indent: None,
+            state: None,
+            recovered: None,
+            furthest: None,
+            input: next_input_id(),
+        }
+    }
+
+    /// Count columns from here on with 'cols', see ```Cols```
+    pub fn with_cols(mut self, cols: Cols) -> Self {
+        self.cols = cols;
+        self
+    }
+
+    pub fn cols(&self) -> Cols {
+        self.cols
+    }
+
+    /// The input not yet consumed
+    pub fn rest(&self) -> &'a I {
+        I::rest(&self.iter)
+    }
+
+    /// The input between this point and 'end'
+    pub fn slice_to(&self, end: &Self) -> &'a I {
+        match (self.index(), end.index()) {
+            (Some(st), Some(f)) => self.rest().head(f - st),
+            _ => self.rest(),
         }
     }
 
+    /// A copy moved straight to 'end', which must be ahead of this point in the same input.
+    /// The rest is sliced rather than walked, so lines and columns are taken from 'end'
+    pub(crate) fn jump_to(&self, end: Loc) -> Self {
+        let mut res = self.clone();
+        res.iter = self.rest().tail(end.index - self.loc().index).items();
+        res.base = end.index;
+        res.l = end.line;
+        res.c = end.col;
+        res
+    }
+
+    pub fn err(&self, exp: Expected) -> PErr<'a> {
+        PErr::new(exp, self.rest().found(), self.index(), self.l, self.c)
+    }
+
     pub fn err_s(&self, s: &'static str) -> PErr<'a> {
         self.err(Expected::Str(s))
     }
@@ -52,20 +302,16 @@ impl<'a> LCChars<'a> {
         Err(self.err_s(s))
     }
 
-    pub fn err_p<P: Parser>(&self, p: &P) -> PErr<'a> {
+    pub fn err_p<P: Parser<I>>(&self, p: &P) -> PErr<'a> {
         self.err(p.expected())
     }
-    pub fn err_rp<P: Parser, V>(&self, p: &P) -> Result<V, PErr<'a>> {
+    pub fn err_rp<P: Parser<I>, V>(&self, p: &P) -> Result<V, PErr<'a>> {
         Err(self.err_p(p))
     }
-    pub fn err_op<P: Parser>(&self, p: &P) -> Option<PErr<'a>> {
+    pub fn err_op<P: Parser<I>>(&self, p: &P) -> Option<PErr<'a>> {
         Some(self.err_p(p))
     }
 
-    pub fn err_oc<C: CharBool>(&self, c: &C) -> Option<PErr<'a>> {
-        Some(self.err(c.expected()))
-    }
-
     pub fn err_r<V>(&self, e: Expected) -> Result<V, PErr<'a>> {
         Err(self.err(e))
     }
@@ -74,33 +320,223 @@ impl<'a> LCChars<'a> {
         (self.l, self.c)
     }
     pub fn index(&self) -> Option<usize> {
-        self.iter.clone().next().map(|(i, _)| i)
+        self.iter.clone().next().map(|(i, _)| i + self.base)
     }
-}
 
-impl<'a> Iterator for LCChars<'a> {
-    type Item = char;
-    fn next(&mut self) -> Option<char> {
-        //println!("lc {} {} ", self.l, self.c);
-        match self.iter.next() {
-            Some((_, '\n')) => {
-                self.l += 1;
-                self.c = 0;
-                Some('\n')
+    /// The current position, which unlike ```index``` is known at the end of input too
+    pub fn loc(&self) -> Loc {
+        Loc {
+            index: I::offset(&self.iter) + self.base,
+            line: self.l,
+            col: self.c,
+        }
+    }
+
+    /// The indentation of the innermost ```indented_block```, 0 outside of any
+    pub fn indent(&self) -> usize {
+        self.indent.as_ref().map(|i| i.n).unwrap_or(0)
+    }
+
+    /// Is 'n' the indentation of any block entered, or the base level of 0
+    pub fn is_indent_level(&self, n: usize) -> bool {
+        let mut r = &self.indent;
+        while let Some(rc) = r {
+            if rc.n == n {
+                return true;
+            }
+            r = &rc.prev;
+        }
+        n == 0
+    }
+
+    /// The indentation of every block entered, innermost first
+    pub(crate) fn indents(&self) -> Vec<usize> {
+        let mut res = Vec::new();
+        let mut r = &self.indent;
+        while let Some(i) = r {
+            res.push(i.n);
+            r = &i.prev;
+        }
+        res
+    }
+
+    /// Identifies the input being parsed. Clones share it, but each ```new``` or ```at```
+    /// gets a new one, even for the same text
+    pub(crate) fn input_id(&self) -> usize {
+        self.input
+    }
+
+    pub(crate) fn push_indent(&mut self, n: usize) {
+        self.indent = Some(Rc::new(Indent {
+            n,
+            prev: self.indent.take(),
+        }));
+    }
+
+    pub(crate) fn pop_indent(&mut self) {
+        self.indent = self.indent.take().and_then(|i| i.prev.clone());
+    }
+
+    /// Carry 's' through the parse, for parsers that need context, see ```get_state```
+    pub fn with_state<S: 'static>(mut self, s: S) -> Self {
+        self.set_state(s);
+        self
+    }
+
+    /// The user state, if there is one of type 'S'
+    pub fn state<S: 'static>(&self) -> Option<&S> {
+        self.state.as_ref().and_then(|s| s.downcast_ref())
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// Replace the user state from here on. Iterators cloned before this keep the old state
+    pub fn set_state<S: 'static>(&mut self, s: S) {
+        self.state = Some(Rc::new(s));
+    }
+
+    pub(crate) fn state_rc(&self) -> Option<Rc<dyn Any>> {
+        self.state.clone()
+    }
+
+    pub(crate) fn set_state_rc(&mut self, s: Option<Rc<dyn Any>>) {
+        self.state = s;
+    }
+
+    /// Record an error that the parser has recovered from
+    pub fn add_recovered(&mut self, e: StrungError) {
+        let rc = Rc::new(Recovered {
+            e,
+            prev: self.recovered.take(),
+        });
+        if let Some(f) = &self.furthest {
+            let mut f = f.borrow_mut();
+            let pos = |r: &Rc<Recovered>| r.e.index.unwrap_or(usize::MAX);
+            if f.as_ref().map(|old| pos(old) <= pos(&rc)).unwrap_or(true) {
+                *f = Some(rc.clone());
             }
-            Some((_, v)) => {
-                self.c += 1;
-                Some(v)
+        }
+        self.recovered = Some(rc);
+    }
+
+    /// Keep track of the recovered errors on the path that reaches furthest,
+    /// see ```furthest_recovered```
+    pub fn with_furthest_recovered(mut self) -> Self {
+        self.furthest = Some(Rc::new(RefCell::new(None)));
+        self
+    }
+
+    /// The errors recovered from on the way to this point, in the order they were found
+    pub fn recovered(&self) -> Vec<StrungError> {
+        list_recovered(&self.recovered)
+    }
+
+    /// The errors recovered from, on the path that recovered furthest into the input,
+    /// by any iterator cloned from this one since ```with_furthest_recovered```.
+    /// Used when the parse fails, so there is no finished path to take them from
+    pub fn furthest_recovered(&self) -> Vec<StrungError> {
+        match &self.furthest {
+            Some(f) => list_recovered(&f.borrow()),
+            None => Vec::new(),
+        }
+    }
+
+    /// The errors recovered from between 'start' and this point
+    pub(crate) fn recovered_since(&self, start: &LCChars<I>) -> Vec<StrungError> {
+        let mut res = Vec::new();
+        let mut r = &self.recovered;
+        while let Some(rc) = r {
+            if let Some(st) = &start.recovered {
+                if Rc::ptr_eq(rc, st) {
+                    break;
+                }
             }
-            None => None,
+            res.push(rc.e.clone());
+            r = &rc.prev;
         }
+        res.reverse();
+        res
     }
 }
 
-pub fn index<'a>(it: &LCChars<'a>) -> ParseRes<'a, Option<usize>> {
-    return Ok((it.clone(), it.index(), None));
+impl<'a, I: ?Sized + Input> Iterator for LCChars<'a, I> {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        //println!("lc {} {} ", self.l, self.c);
+        let (_, v) = self.iter.next()?;
+        if I::is_newline(&v) {
+            self.l += 1;
+            self.c = 0;
+        } else {
+            self.c = I::next_col(&v, &self.iter, self.c, &self.cols);
+        }
+        Some(v)
+    }
 }
 
-pub fn line_col<'a>(it: &LCChars<'a>) -> ParseRes<'a, (usize, usize)> {
-    return Ok((it.clone(), (it.l, it.c), None));
+pub fn index<'a, I: ?Sized + Input>(it: &LCChars<'a, I>) -> ParseRes<'a, Option<usize>, I> {
+    Ok((it.clone(), it.index(), None))
+}
+
+pub fn line_col<'a, I: ?Sized + Input>(it: &LCChars<'a, I>) -> ParseRes<'a, (usize, usize), I> {
+    Ok((it.clone(), (it.l, it.c), None))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_grapheme_columns() {
+        let src = "e\u{301}👍🏽👨\u{200d}👩\u{200d}👧🇫🇷🇩🇪\u{1100}\u{1161}\u{11a8}x";
+        let p = (Any.except("x").star(), "y");
+        let e = p.parse_s_cols(src, Cols::graphemes()).unwrap_err();
+        assert_eq!(e.col, 6);
+        //chars count every code point
+        assert_eq!(p.parse_s(src).unwrap_err().col, 16);
+    }
+
+    #[test]
+    fn test_crlf_and_tab_columns() {
+        let src = "a\r\n\tb";
+        let p = ("a\r\n", WS.istar(), "c");
+        let e = p.parse(&LCChars::str(src)).unwrap_err();
+        assert_eq!((e.line, e.col), (1, 1));
+
+        let it = LCChars::str(src).with_cols(Cols::default().tabs(8).crlf());
+        let e = p.parse(&it).unwrap_err();
+        assert_eq!((e.line, e.col), (1, 8));
+        //the '\r' takes no column
+        let (it2, _, _) = Any.one().parse(&it).unwrap();
+        let (it2, _, _) = Any.one().parse(&it2).unwrap();
+        assert_eq!(it2.lc(), (0, 1));
+
+        //the default is unchanged
+        let (it, _, _) = Any.exact(2).parse(&LCChars::str(src)).unwrap();
+        assert_eq!(it.lc(), (0, 2));
+    }
+
+    #[test]
+    fn test_parse_at_moves_positions() {
+        let start = Loc {
+            index: 100,
+            line: 7,
+            col: 4,
+        };
+        let p = (
+            Alpha.plus().spanned(),
+            WSL.istar(),
+            pos_ig(Alpha.plus()),
+            ";",
+        );
+        let (a, _, b, _) = p.parse_at("ab\n  cd;", start).unwrap();
+        assert_eq!(a.span.start, start);
+        assert_eq!(a.span.end.col, 6);
+        assert_eq!((b.line, b.col, b.start, b.fin), (8, 2, 105, Some(107)));
+
+        let e = p.parse_at("ab\n  cd", start).unwrap_err();
+        assert_eq!((e.index, e.line, e.col), (None, 8, 4));
+    }
 }
@@ -0,0 +1,335 @@
+//! A separate lexing stage, for grammars where whitespace and comments can go between any
+//! two tokens. Rather than wrapping every rule in ```ws__```, a ```Lexer``` turns the source
+//! into a ```Vec<Token<K>>```, which can then be parsed as a token slice, see ```Input```.
+//!
+//! At each point the skip rules are run until none of them move, then every token rule is
+//! tried, and the longest match wins. On a tie the rule added first wins, so add keywords
+//! before identifiers.
+//!
+//! ```rust
+//! use gobble::*;
+//! #[derive(Clone, Debug, PartialEq)]
+//! enum K {
+//!     Let,
+//!     Ident,
+//!     Num,
+//!     Eq,
+//! }
+//! let lexer = Lexer::new()
+//!     .rule(K::Let, "let")
+//!     .rule(K::Ident, Alpha.plus())
+//!     .rule(K::Num, NumDigit.plus())
+//!     .rule(K::Eq, '=')
+//!     .skip(WSL.iplus());
+//!
+//! let toks = lexer.parse_s("let letter = 45").unwrap();
+//! let kinds: Vec<K> = toks.iter().map(|t| t.kind.clone()).collect();
+//! assert_eq!(kinds, vec![K::Let, K::Ident, K::Eq, K::Num]);
+//! assert_eq!(toks[1].text, "letter");
+//! assert_eq!(toks[3].span.start.col, 13);
+//!
+//! let stmt = (kind(K::Let), kind(K::Ident), kind(K::Eq), kind(K::Num));
+//! let (_, name, _, val) = stmt.parse_s(&toks).unwrap();
+//! assert_eq!((name.text.as_str(), val.text.as_str()), ("letter", "45"));
+//! ```
+use crate::borrow::Owned;
+use crate::err::{Expected, PErr};
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+use crate::span::{Loc, Span};
+use std::fmt::Debug;
+
+/// A token found by a ```Lexer```, with the text and span it covers in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<K> {
+    pub kind: K,
+    pub text: String,
+    pub span: Span,
+}
+
+type Rule = Box<dyn for<'a> Fn(&LCChars<'a>) -> Result<LCChars<'a>, PErr<'a>>>;
+
+fn rule<P: Parser + 'static>(p: P) -> Rule {
+    Box::new(move |it| p.parse(it).map(|(nit, _, _)| nit))
+}
+
+pub struct Lexer<K> {
+    rules: Vec<(K, Rule)>,
+    skips: Vec<Rule>,
+}
+
+impl<K> Default for Lexer<K> {
+    fn default() -> Self {
+        Lexer {
+            rules: Vec::new(),
+            skips: Vec::new(),
+        }
+    }
+}
+
+impl<K: Clone> Lexer<K> {
+    pub fn new() -> Self {
+        Lexer::default()
+    }
+
+    /// Add a rule making tokens of 'kind' from whatever 'p' matches
+    pub fn rule<P: Parser + 'static>(mut self, kind: K, p: P) -> Self {
+        self.rules.push((kind, rule(p)));
+        self
+    }
+
+    /// Add a rule for things between tokens, such as whitespace and comments
+    pub fn skip<P: Parser + 'static>(mut self, p: P) -> Self {
+        self.skips.push(rule(p));
+        self
+    }
+
+    fn do_skip<'a>(&self, it: &LCChars<'a>) -> LCChars<'a> {
+        let mut it = it.clone();
+        loop {
+            let start = it.loc().index;
+            for s in &self.skips {
+                if let Ok(nit) = s(&it) {
+                    it = nit;
+                }
+            }
+            if it.loc().index == start {
+                return it;
+            }
+        }
+    }
+
+    /// The longest match of any rule, errors if none match anything
+    fn longest<'a>(&self, it: &LCChars<'a>) -> Result<(K, LCChars<'a>), PErr<'a>> {
+        let start = it.loc().index;
+        let mut best: Option<(&K, LCChars<'a>)> = None;
+        let mut err: Option<PErr<'a>> = None;
+        for (k, r) in &self.rules {
+            match r(it) {
+                Ok(nit) => {
+                    let len = nit.loc().index;
+                    match &best {
+                        Some((_, b)) if b.loc().index >= len => {}
+                        _ if len == start => {}
+                        _ => best = Some((k, nit)),
+                    }
+                }
+                Err(e) => {
+                    err = Some(match err {
+                        Some(pe) => pe.longer(e),
+                        None => e,
+                    })
+                }
+            }
+        }
+        match best {
+            Some((k, nit)) => Ok((k.clone(), nit)),
+            None => Err(it.err(Expected::Named("token")).join_op(err)),
+        }
+    }
+}
+
+impl<K> Owned for Lexer<K> {}
+
+impl<K: Clone> Parser for Lexer<K> {
+    type Out = Vec<Token<K>>;
+    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Vec<Token<K>>> {
+        let mut res = Vec::new();
+        let mut it = self.do_skip(it);
+        while it.index().is_some() {
+            let (kind, nit) = self.longest(&it)?;
+            res.push(Token {
+                kind,
+                text: it.slice_to(&nit).to_string(),
+                span: Span {
+                    start: it.loc(),
+                    end: nit.loc(),
+                },
+            });
+            it = self.do_skip(&nit);
+        }
+        Ok((it, res, None))
+    }
+    fn expected(&self) -> Expected {
+        Expected::Named("token")
+    }
+}
+
+#[derive(Clone)]
+pub struct Kind<K> {
+    k: K,
+}
+
+impl<K> Owned for Kind<K> {}
+
+impl<K: Clone + PartialEq + Debug> Parser<[Token<K>]> for Kind<K> {
+    type Out = Token<K>;
+    fn parse<'a>(&self, it: &LCChars<'a, [Token<K>]>) -> ParseRes<'a, Token<K>, [Token<K>]> {
+        let mut it2 = it.clone();
+        match it2.next() {
+            Some(t) if t.kind == self.k => Ok((it2, t, None)),
+            _ => it.err_rp(self),
+        }
+    }
+    fn expected(&self) -> Expected {
+        Expected::Item(format!("{:?}", self.k))
+    }
+}
+
+/// Matches one token of the given kind, when parsing the output of a ```Lexer```
+pub fn kind<K>(k: K) -> Kind<K> {
+    Kind { k }
+}
+
+/// Where in the source a token index points, for errors from parsing tokens.
+/// An index of None (the end of the tokens) gives the end of the last token
+pub fn token_loc<K>(toks: &[Token<K>], index: Option<usize>) -> Loc {
+    match index.and_then(|i| toks.get(i)) {
+        Some(t) => t.span.start,
+        None => toks.last().map(|t| t.span.end).unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::collections::HashMap;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum JK {
+        Open,
+        Close,
+        OpenSq,
+        CloseSq,
+        Colon,
+        Comma,
+        Str,
+        Num,
+        True,
+        False,
+        Null,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Value {
+        Null,
+        Bool(bool),
+        Num(f64),
+        Str(String),
+        Array(Vec<Value>),
+        Object(HashMap<String, Value>),
+    }
+
+    fn js_char() -> impl Parser<Out = char> {
+        or3(
+            "\\u".ig_then(HexDigit.exact(4).try_map(|v| {
+                let n = u32::from_str_radix(&v, 16).map_err(|_| Expected::Str("4 hex digits"))?;
+                std::char::from_u32(n).ok_or(Expected::Str("4 hex digits"))
+            })),
+            '\\'.ig_then(or6(
+                'b'.asv('\u{08}'),
+                'f'.asv('\u{0C}'),
+                'n'.asv('\n'),
+                'r'.asv('\r'),
+                't'.asv('\t'),
+                "\"\\/".one(),
+            )),
+            Any.one(),
+        )
+    }
+
+    fn json_lexer() -> Lexer<JK> {
+        Lexer::new()
+            .rule(JK::Open, '{')
+            .rule(JK::Close, '}')
+            .rule(JK::OpenSq, '[')
+            .rule(JK::CloseSq, ']')
+            .rule(JK::Colon, ':')
+            .rule(JK::Comma, ',')
+            .rule(JK::Str, '"'.ig_then(chars_until(js_char(), '"')))
+            .rule(JK::Num, or(common::Float.ig(), common::Int.ig()))
+            .rule(JK::True, "true")
+            .rule(JK::False, "false")
+            .rule(JK::Null, "null")
+            .skip(" \t\n\r".iplus())
+    }
+
+    type JRes<'a, V> = ParseRes<'a, V, [Token<JK>]>;
+
+    fn string<'a>(it: &LCChars<'a, [Token<JK>]>) -> JRes<'a, String> {
+        kind(JK::Str)
+            .try_map(|t| {
+                '"'.ig_then(chars_until(js_char(), '"'))
+                    .map(|(s, _)| s)
+                    .parse_s(&t.text)
+                    .map_err(|_| Expected::Named("string"))
+            })
+            .parse(it)
+    }
+
+    fn value<'a>(it: &LCChars<'a, [Token<JK>]>) -> JRes<'a, Value> {
+        or!(
+            kind(JK::Null).asv(Value::Null),
+            kind(JK::True).asv(Value::Bool(true)),
+            kind(JK::False).asv(Value::Bool(false)),
+            kind(JK::Num).try_map(|t| t
+                .text
+                .parse()
+                .map(Value::Num)
+                .map_err(|_| Expected::Named("number"))),
+            string.map(Value::Str),
+            kind(JK::OpenSq)
+                .ig_then(sep_until_ig(value, kind(JK::Comma), kind(JK::CloseSq)))
+                .map(Value::Array),
+            kind(JK::Open)
+                .ig_then(sep_until_ig(
+                    (string, kind(JK::Colon), value),
+                    kind(JK::Comma),
+                    kind(JK::Close)
+                ))
+                .map(|v| Value::Object(v.into_iter().map(|(k, _, v)| (k, v)).collect())),
+        )
+        .parse(it)
+    }
+
+    #[test]
+    fn test_lex_then_parse_json() {
+        //the example from examples/json.rs
+        let src = r#"{
+        "name":"sam\t\u0048",
+        "age"    :5,
+        "n":null
+    }"#;
+        let toks = json_lexer().parse_s(src).unwrap();
+        assert_eq!(toks.len(), 13);
+        assert_eq!(toks[3].kind, JK::Str);
+        assert_eq!(
+            toks[3].span.start,
+            Loc {
+                index: 17,
+                line: 1,
+                col: 15
+            }
+        );
+
+        let v = first(value, eoi).parse_s(&toks).unwrap();
+        let mut m = HashMap::new();
+        m.insert("name".to_string(), Value::Str("sam\tH".to_string()));
+        m.insert("age".to_string(), Value::Num(5.));
+        m.insert("n".to_string(), Value::Null);
+        assert_eq!(v, Value::Object(m));
+    }
+
+    #[test]
+    fn test_lex_errors() {
+        let e = json_lexer().parse_s("[1, @]").unwrap_err();
+        assert_eq!((e.index, e.col), (Some(4), 4));
+
+        let src = "{\"a\": [1.5, true,, 3]}";
+        let toks = json_lexer().parse_s(src).unwrap();
+        let e = first(value, eoi).parse_s(&toks).unwrap_err();
+        assert_eq!(e.index, Some(8));
+        assert_eq!(token_loc(&toks, e.index).col, 17);
+        assert_eq!(token_loc(&toks, None).index, src.len());
+    }
+}
@@ -82,8 +82,7 @@
 //!  * ```&'static str``` which will return itself if it matches
 //!  * ```char``` which will return itself if it matched the next char
 //!  * Tuples of up to 6 parsers. Returning a tuple of all the
-//!     parsers matched one after the
-//!  other.
+//!    parsers matched one after the other.
 //!
 //!  Most of the time a parser can be built simply by combining other parsers
 //!  ```rust
@@ -249,29 +248,63 @@
 #[macro_use]
 pub mod macros;
 
+pub mod binary;
+pub mod borrow;
+pub mod boxed;
 pub mod chars;
+pub mod charset;
 pub mod combi;
 pub mod common;
+pub mod describe;
 pub mod err;
+pub mod generate;
+pub mod indent;
+pub mod input;
 pub mod iter;
+pub mod lex;
+pub mod memo;
+pub mod prec;
 pub mod ptrait;
 pub mod pull;
 pub mod reader;
 pub mod repeater;
+pub mod report;
 pub mod skip;
+pub mod span;
+pub mod state;
+pub mod stream;
 pub mod strings;
+#[cfg(feature = "trace")]
+pub mod trace;
 pub mod traits;
 pub mod tuple;
+pub mod unicode;
 
+pub use borrow::*;
+pub use boxed::*;
 pub use chars::*;
+pub use charset::*;
 pub use combi::*;
+pub use describe::*;
 //pub use common::*;
 pub use err::*;
+pub use generate::*;
+pub use indent::*;
+pub use input::*;
 pub use iter::*;
-pub use macros::*;
+pub use lex::*;
+pub use memo::*;
+pub use prec::*;
 pub use ptrait::*;
 pub use reader::*;
 pub use repeater::*;
+pub use report::*;
 pub use skip::*;
+pub use span::*;
+pub use state::*;
+pub use stream::*;
 pub use strings::*;
+#[cfg(feature = "trace")]
+pub use trace::*;
 pub use tuple::*;
+pub use unicode::*;
@@ -6,7 +6,7 @@
 //!
 
 /// Makes zero sized parsers based on the expression given and potentially the return type given.
-
+///
 /// ```rust
 /// use gobble::*;
 /// parser!{
@@ -15,13 +15,50 @@
 /// }
 /// assert_eq!(Cat.parse_s("ctar"),Ok("cta".to_string()));
 /// ```
+///
+/// Given a lifetime the rule is a ```RefParser```, so its result can borrow from the input
+///
+/// ```rust
+/// use gobble::*;
+/// parser!{ (Word<'a>->&'a str) Alpha.plus_str() }
+/// assert_eq!(Word.parse_ref_s("cat dog"),Ok("cat"));
+/// ```
 #[macro_export]
 macro_rules! parser {
+    ($($doc:literal $(,)?)? ($id:ident<$l:lifetime> -> $ot:ty) $(,)? $x:expr $(,)?) => {
+        parser!($($doc)? ($id<$l>->$ot) $x, Expected::Named(stringify!($id)));
+    };
+    ($($doc:literal $(,)?)? ($id:ident<$l:lifetime> -> $ot:ty) $(,)? $x:expr,$exp:expr $(,)?) => {
+        $(#[doc=$doc])?
+        #[derive(Copy, Clone)]
+        pub struct $id;
+        impl $crate::memo::Rule for $id {}
+        impl $crate::borrow::RefParser for $id {
+            type Out<$l> = $ot;
+            ///Parse run the main parser
+            fn parse_ref<$l>(&self, it: &LCChars<$l>) -> ParseRes<$l, $ot> {
+                $crate::memo::rule_guard(self, stringify!($id), it, || {
+                    let name_e = it.err(self.expected_ref());
+                    match (&$x).parse_ref(it){
+                        Ok(v)=> Ok(v),
+                        Err(e)=> match (e.index,name_e.index) {
+                            (Some(ei),Some(ii)) if (ii == ei) && !e.is_brk => Err(name_e),
+                            _=>Err(e.in_context(stringify!($id)).join(name_e)),
+                        }
+                    }
+                })
+            }
+            ///The expected return type
+            fn expected_ref(&self) -> Expected {
+                $exp
+            }
+        }
+    };
     ($id:ident,$x:expr) => {
         parser!(($id->&'static str) $x);
     };
     ($($doc:literal $(,)?)? ($id:ident -> $ot:ty) $(,)? $x:expr $(,)?) => {
-        parser!($($doc)? ($id->$ot) $x, Expected::Str(stringify!($id)));
+        parser!($($doc)? ($id->$ot) $x, Expected::Named(stringify!($id)));
     };
     ($id:ident,$x:expr,$exp:expr) => {
         parser!(($id->&'static str) $x, $exp);
@@ -30,23 +67,34 @@ macro_rules! parser {
         $(#[doc=$doc])?
         #[derive(Copy, Clone)]
         pub struct $id;
+        impl $crate::memo::Rule for $id {}
+        impl $crate::borrow::Owned for $id {}
         impl Parser for $id {
             type Out = $ot;
             ///Parse run the main parser
             fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
-                let name_e = it.err_p(self);
-                match (&$x).parse(it){
-                    Ok(v)=> Ok(v),
-                    Err(e)=> match (e.index,name_e.index) {
-                        (Some(ei),Some(ii)) if (ii == ei) => it.err_rp(self),
-                        _=>Err(e.join(name_e)),
+                $crate::memo::rule_guard(self, stringify!($id), it, || {
+                    let name_e = it.err_p(self);
+                    match (&$x).parse(it){
+                        Ok(v)=> Ok(v),
+                        Err(e)=> match (e.index,name_e.index) {
+                            (Some(ei),Some(ii)) if (ii == ei) && !e.is_brk => it.err_rp(self),
+                            _=>Err(e.in_context(stringify!($id)).join(name_e)),
+                        }
                     }
-                }
+                })
             }
             ///The expected return type
             fn expected(&self) -> Expected {
                 $exp
             }
+            ///A reference to this rule, see ```Grammar```
+            fn describe(&self) -> $crate::describe::Gram {
+                $crate::describe::Gram::Rule($crate::describe::RuleRef::of::<$id>(
+                    stringify!($id),
+                    || (&$x).describe(),
+                ))
+            }
         }
     };
 }
@@ -140,6 +188,9 @@ macro_rules! char_bool {
             fn expected(&self) -> Expected {
                 $exp
             }
+            fn ranges(&self) -> Option<Vec<(char, char)>> {
+                (&$x).ranges()
+            }
         }
     };
 }
@@ -150,21 +201,25 @@ macro_rules! char_bools {
 }
 
 /// a macro replacement for numbered or statements.
+/// Like ```or``` this works with ```RefParser```s as well as ```Parser```s
 /// ```rust
 /// use gobble::*;
 /// assert_eq!(or!("cat","dog","car",).parse_s("catdogman "),Ok("cat"));
+/// assert_eq!(or!("0x", Alpha.plus_str()).parse_ref_s("abc"), Ok("abc"));
 /// ```
 #[macro_export]
 macro_rules! or{
-    ($s:expr,$($x:expr),* $(,)?) => { $s$(.or($x))*;};
+    ($s:expr $(,)?) => { $s };
+    ($s:expr,$x:expr $(,$y:expr)* $(,)?) => { $crate::or!($crate::or($s, $x) $(,$y)*) };
 }
 
 #[macro_export]
 macro_rules! or_ig{
-    ($s:expr,$($x:expr),* $(,)?) => { $s.ig()$(.or($x.ig()))*;};
+    ($s:expr,$($x:expr),* $(,)?) => { $s.ig()$(.or($x.ig()))*};
 }
 
 #[cfg(test)]
+#[allow(clippy::upper_case_acronyms, dead_code)]
 mod test {
 
     fn size_of<T: Sized>(_t: &T) -> usize {
@@ -189,7 +244,7 @@ mod test {
     }
 
     char_bool!(HOT, "hot");
-    char_bool!(MNUM, |c| c >= '0' && c <= '9');
+    char_bool!(MNUM, |c: char| c.is_ascii_digit());
 
     #[test]
     pub fn charbool_macro_makes_parser() {
@@ -214,7 +269,7 @@ mod test {
         ((SUB->Oper::Sub) '-'),
         ((DIV->Oper::Div) '/'),
         ((MUL->Oper::Mul) '*'),
-        (VAR , Alpha.plus().map(|s|Oper::Var(s))),
+        (VAR , Alpha.plus().map(Oper::Var)),
     }
 
     #[test]
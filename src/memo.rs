@@ -0,0 +1,739 @@
+//! Packrat style memoization.
+//!
+//! Because ```Or``` restarts each branch from the same place, grammars that share a prefix
+//! between branches can end up parsing the same text many times over.
+//! Wrapping a rule in ```memo``` stores its result against the byte index it started from,
+//! so the second time it is asked for, the stored result is returned instead.
+//!
+//! The table only exists while a ```MemoSession``` is alive, outside of a session ```memo```
+//! parses as normal. Results are stored by the type of the parser, so ```memo``` only takes
+//! ```Rule```s, the zero sized rules created by ```parser!```, where every value parses the
+//! same way. They are also stored by input, so results from parsing one string are never
+//! used for another, and by the blocks entered with ```indented_block```.
+//!
+//! While there is a user state (see ```get_state```), ```memo``` parses as normal, as the
+//! stored result could depend on it. For the same reason ```left_rec``` only uses the table
+//! while it grows, and forgets the result once it is done.
+//!
+//! ```left_rec``` uses the same table to allow rules that begin by calling themselves,
+//! growing the result one step at a time until it can grow no further. Like ```memo``` it
+//! only takes ```Rule```s.
+//! While a session is alive, rules made with ```parser!``` that recurse to themselves at the
+//! same position without ```left_rec``` return an error rather than overflowing the stack.
+//! Outside of a session rules do no extra work.
+//!
+//! ```rust
+//! use gobble::*;
+//! parser! {
+//!     (Item->String)
+//!     or(middle("[", MItem, "]"), common::Ident)
+//! }
+//! parser! { (MItem->String) memo(Item) }
+//! parser! {
+//!     (List->Vec<String>)
+//!     or(
+//!         (star(first(MItem, ",")), MItem).map(|(mut v, l)| {
+//!             v.push(l);
+//!             v
+//!         }),
+//!         MItem.map(|i| vec![i]),
+//!     )
+//! }
+//!
+//! let sess = MemoSession::new();
+//! let v = sess.parse_s(&List, "[[a]],b,[c]").unwrap();
+//! assert_eq!(v, vec!["a", "b", "c"]);
+//! ```
+use crate::borrow::Owned;
+use crate::describe::Gram;
+use crate::err::{Expected, PErr, StrungError};
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::*;
+use crate::span::Loc;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct MemoTable {
+    depth: usize,
+    map: HashMap<Key, Box<dyn Any>>,
+    frames: Vec<Frame>,
+}
+
+/// Where a result was found: by which parser, in which input, inside which indented blocks
+#[derive(PartialEq, Eq, Hash)]
+struct Key {
+    id: TypeId,
+    input: usize,
+    indents: Vec<usize>,
+    index: usize,
+}
+
+impl Key {
+    fn new<I: ?Sized + Input>(id: TypeId, it: &LCChars<I>) -> Self {
+        Key {
+            id,
+            input: it.input_id(),
+            indents: it.indents(),
+            index: pos_key(it),
+        }
+    }
+}
+
+/// A parser where every value of the type parses the same way, so its results can be stored
+/// by type. Implemented by every rule made with ```parser!```
+pub trait Rule: 'static {}
+
+/// Rules currently being parsed, and the left_recs growing, with their positions
+/// as (input, index)
+enum Frame {
+    Rule(TypeId, (usize, usize)),
+    Grow(TypeId, (usize, usize)),
+}
+
+impl Frame {
+    fn pos(&self) -> (usize, usize) {
+        match self {
+            Frame::Rule(_, p) | Frame::Grow(_, p) => *p,
+        }
+    }
+}
+
+thread_local! {
+    static MEMO: RefCell<MemoTable> = RefCell::new(MemoTable::default());
+}
+
+/// Pops the frame it was created with, even if the parser panics
+struct FrameGuard;
+
+impl FrameGuard {
+    fn push(f: Frame) -> Self {
+        MEMO.with(|m| m.borrow_mut().frames.push(f));
+        FrameGuard
+    }
+}
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        MEMO.with(|m| m.borrow_mut().frames.pop());
+    }
+}
+
+/// The position as a key, the end of input is after every other position
+fn pos_key<I: ?Sized + Input>(it: &LCChars<I>) -> usize {
+    it.index().unwrap_or(usize::MAX)
+}
+
+/// While a session is alive, ```memo``` parsers on this thread will store their results.
+/// The results are cleared when the last session is dropped.
+/// Results from one input are never used for another, but they are kept until cleared,
+/// ```parse_s``` on the session clears them first so the table does not keep growing.
+pub struct MemoSession {
+    //stops construction without 'new'
+    _p: (),
+}
+
+impl MemoSession {
+    pub fn new() -> Self {
+        MEMO.with(|m| m.borrow_mut().depth += 1);
+        MemoSession { _p: () }
+    }
+
+    /// Forget all stored results
+    pub fn clear(&self) {
+        MEMO.with(|m| m.borrow_mut().map.clear());
+    }
+
+    /// Clear the table and parse the string
+    pub fn parse_s<'a, I: ?Sized + Input, P: Parser<I>>(
+        &self,
+        p: &P,
+        s: &'a I,
+    ) -> Result<P::Out, PErr<'a>> {
+        self.clear();
+        p.parse_s(s)
+    }
+
+    /// The number of results currently stored
+    pub fn len(&self) -> usize {
+        MEMO.with(|m| m.borrow().map.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for MemoSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for MemoSession {
+    fn drop(&mut self) {
+        MEMO.with(|m| {
+            let mut m = m.borrow_mut();
+            m.depth -= 1;
+            if m.depth == 0 {
+                m.map.clear();
+            }
+        })
+    }
+}
+
+pub(crate) fn memo_active() -> bool {
+    MEMO.with(|m| m.borrow().depth > 0)
+}
+
+/// An error without the borrowed "found" str, which can be rebuilt from the start position
+#[derive(Clone)]
+struct StoredErr {
+    exp: Expected,
+    index: Option<usize>,
+    line: usize,
+    col: usize,
+    is_brk: bool,
+    child: Option<Box<StoredErr>>,
+    context: Box<[&'static str]>,
+}
+
+impl StoredErr {
+    fn store(e: &PErr) -> Self {
+        StoredErr {
+            exp: e.exp.clone(),
+            index: e.index,
+            line: e.line,
+            col: e.col,
+            is_brk: e.is_brk,
+            child: e.child.as_ref().map(|c| Box::new(StoredErr::store(c))),
+            context: e.context.clone(),
+        }
+    }
+
+    fn restore<'a, I: ?Sized + Input>(&self, it: &LCChars<'a, I>) -> PErr<'a> {
+        let st = it.loc().index;
+        let found = match self.index {
+            Some(i) if i >= st => it.rest().tail(i - st).found(),
+            _ => "",
+        };
+        PErr {
+            exp: self.exp.clone(),
+            found,
+            index: self.index,
+            line: self.line,
+            col: self.col,
+            is_brk: self.is_brk,
+            child: self.child.as_ref().map(|c| Box::new(c.restore(it))),
+            context: self.context.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Stored<V> {
+    Done(
+        Loc,
+        V,
+        Option<StoredErr>,
+        Vec<StrungError>,
+        Option<Rc<dyn Any>>,
+    ),
+    Fail(StoredErr),
+}
+
+impl<V: Clone> Stored<V> {
+    fn store<I: ?Sized + Input>(it: &LCChars<I>, r: &ParseRes<V, I>) -> Self {
+        match r {
+            Ok((nit, v, ct)) => Stored::Done(
+                nit.loc(),
+                v.clone(),
+                ct.as_ref().map(StoredErr::store),
+                nit.recovered_since(it),
+                nit.state_rc(),
+            ),
+            Err(e) => Stored::Fail(StoredErr::store(e)),
+        }
+    }
+
+    fn restore<'a, I: ?Sized + Input>(self, it: &LCChars<'a, I>) -> ParseRes<'a, V, I> {
+        match self {
+            Stored::Done(fin, v, ct, rec, state) => {
+                let mut nit = it.jump_to(fin);
+                nit.set_state_rc(state);
+                for e in rec {
+                    nit.add_recovered(e);
+                }
+                Ok((nit, v, ct.map(|e| e.restore(it))))
+            }
+            Stored::Fail(e) => Err(e.restore(it)),
+        }
+    }
+}
+
+fn memo_get<V: Clone + 'static>(k: &Key) -> Option<Stored<V>> {
+    MEMO.with(|m| {
+        m.borrow()
+            .map
+            .get(k)
+            .and_then(|b| b.downcast_ref::<Stored<V>>())
+            .cloned()
+    })
+}
+
+fn memo_set<V: Clone + 'static, I: ?Sized + Input>(it: &LCChars<I>, k: Key, r: &ParseRes<V, I>) {
+    let st = Stored::store(it, r);
+    MEMO.with(|m| m.borrow_mut().map.insert(k, Box::new(st)));
+}
+
+#[derive(Clone, Copy)]
+pub struct Memo<P> {
+    p: P,
+}
+
+impl<P> Owned for Memo<P> {}
+
+impl<I, P> Parser<I> for Memo<P>
+where
+    I: ?Sized + Input,
+    P: Parser<I> + Rule,
+    P::Out: Clone + 'static,
+{
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        if it.index().is_none() || !memo_active() || it.has_state() {
+            return self.p.parse(it);
+        }
+        let k = Key::new(TypeId::of::<P>(), it);
+        if let Some(st) = memo_get::<P::Out>(&k) {
+            return st.restore(it);
+        }
+        let r = self.p.parse(it);
+        memo_set(it, k, &r);
+        r
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+/// Stores the result of the rule against the start position for the length of a
+/// ```MemoSession```. Only takes ```Rule```s, as results are stored by type, and two parsers
+/// of the same type could parse differently (like two ```&'static str```s)
+pub fn memo<P: Rule>(p: P) -> Memo<P> {
+    Memo { p }
+}
+
+/// Used by ```parser!``` to catch a rule calling itself without moving forward, while a
+/// ```MemoSession``` is alive.
+/// Rules may only recurse at the same position, if a ```left_rec``` is growing there.
+pub fn rule_guard<'a, I, R, V, F>(
+    _r: &R,
+    name: &'static str,
+    it: &LCChars<'a, I>,
+    f: F,
+) -> ParseRes<'a, V, I>
+where
+    I: ?Sized + Input,
+    R: 'static,
+    F: FnOnce() -> ParseRes<'a, V, I>,
+{
+    let id = TypeId::of::<R>();
+    let pos = (it.input_id(), pos_key(it));
+    //Some(recursed) if a session is alive
+    let recursed = MEMO.with(|m| {
+        let m = m.borrow();
+        if m.depth == 0 {
+            return None;
+        }
+        for f in m.frames.iter().rev() {
+            match f {
+                _ if f.pos() != pos => return Some(false),
+                Frame::Grow(..) => return Some(false),
+                Frame::Rule(fid, _) if *fid == id => return Some(true),
+                _ => {}
+            }
+        }
+        Some(false)
+    });
+    let _guard = match recursed {
+        Some(true) => return Err(it.err(Expected::LeftRec(name)).brk()),
+        Some(false) => Some(FrameGuard::push(Frame::Rule(id, pos))),
+        None => None,
+    };
+    #[cfg(feature = "trace")]
+    return crate::trace::traced(name, it, f);
+    #[cfg(not(feature = "trace"))]
+    f()
+}
+
+/// Has the parser moved further than the previous best
+fn grew<V, I: ?Sized + Input>(r: &ParseRes<V, I>, best: &ParseRes<V, I>) -> bool {
+    match (r, best) {
+        (Ok(_), Err(_)) => true,
+        (Ok((nit, _, _)), Ok((bit, _, _))) => pos_key(nit) > pos_key(bit),
+        _ => false,
+    }
+}
+
+pub struct LeftRec<P> {
+    p: P,
+}
+
+impl<P> Owned for LeftRec<P> {}
+
+impl<I, P> Parser<I> for LeftRec<P>
+where
+    I: ?Sized + Input,
+    P: Parser<I> + Rule,
+    P::Out: Clone + 'static,
+{
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        let _sess = match memo_active() {
+            true => None,
+            false => Some(MemoSession::new()),
+        };
+        let id = TypeId::of::<P>();
+        let pos = (it.input_id(), pos_key(it));
+        //with a state, the stored result is only for the recursive calls while growing
+        let keep = !it.has_state();
+        let growing = || {
+            MEMO.with(|m| {
+                let m = m.borrow();
+                m.frames
+                    .iter()
+                    .any(|f| matches!(f, Frame::Grow(g, p) if *g == id && *p == pos))
+            })
+        };
+        if keep || growing() {
+            if let Some(st) = memo_get::<P::Out>(&Key::new(id, it)) {
+                return st.restore(it);
+            }
+        }
+        let mut best: ParseRes<P::Out, I> = it.err_rp(&self.p);
+        memo_set(it, Key::new(id, it), &best);
+        let _guard = FrameGuard::push(Frame::Grow(id, pos));
+        loop {
+            let r = self.p.parse(it);
+            if grew(&r, &best) {
+                memo_set(it, Key::new(id, it), &r);
+                best = r;
+                continue;
+            }
+            if best.is_err() {
+                memo_set(it, Key::new(id, it), &r);
+                best = r;
+            }
+            break;
+        }
+        if !keep {
+            MEMO.with(|m| m.borrow_mut().map.remove(&Key::new(id, it)));
+        }
+        best
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+/// Allows the parser to begin by calling the rule it belongs to.
+/// The first time through, the recursive call fails, so the parser must have another option,
+/// that result is then used for the recursive call, and so on until it stops moving forward.
+///
+/// This works for rules that recurse to themselves directly.
+/// The results are stored as with ```memo```, in a session, which is created if none exists,
+/// so it only takes ```Rule```s, give the body a rule of its own.
+///
+/// ```rust
+/// use gobble::*;
+/// parser! { (Sub->isize) left_rec(SubBody) }
+/// parser! {
+///     (SubBody->isize)
+///     or((Sub, "-", common::Int).map(|(a, _, b)| a - b), common::Int)
+/// }
+/// assert_eq!(Sub.parse_s("10-3-2"), Ok(5));
+/// ```
+///
+/// Parsers of the same type could parse differently, and would share results:
+///
+/// ```compile_fail
+/// use gobble::*;
+/// fn chain(op: &'static str) -> RcParser<'static, isize> {
+///     recursive(op, move |p| left_rec(or((p, op, common::Int).map(|(a, _, b)| a + b), common::Int)))
+/// }
+/// ```
+pub fn left_rec<P: Rule>(p: P) -> LeftRec<P> {
+    LeftRec { p }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+        static NESTED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    fn counted<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
+        CALLS.with(|c| c.set(c.get() + 1));
+        or(middle("(", Nest, ")"), common::Ident).parse(it)
+    }
+
+    parser! {
+        (Nest->String)
+        or((counted, "+", Nest).map(|(a, _, b)| a + &b), counted)
+    }
+
+    parser! {
+        (MNest->String)
+        or((MCounted, "+", MNest).map(|(a, _, b)| a + &b), MCounted)
+    }
+
+    parser! {
+        (MCounted->String)
+        memo(CountedM)
+    }
+
+    parser! {
+        (CountedM->String)
+        counted_m
+    }
+
+    fn counted_m<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
+        CALLS.with(|c| c.set(c.get() + 1));
+        or(middle("(", MNest, ")"), common::Ident).parse(it)
+    }
+
+    #[test]
+    fn test_memo_saves_reparsing() {
+        let s = "((((((a))))))";
+        CALLS.with(|c| c.set(0));
+        assert_eq!(Nest.parse_s(s), Ok("a".to_string()));
+        let plain = CALLS.with(|c| c.get());
+        assert_eq!(plain, 254);
+
+        CALLS.with(|c| c.set(0));
+        let sess = MemoSession::new();
+        assert_eq!(sess.parse_s(&MNest, s), Ok("a".to_string()));
+        assert_eq!(CALLS.with(|c| c.get()), 7);
+        assert_eq!(sess.len(), 7);
+    }
+
+    #[test]
+    fn test_memo_errors_and_session_end() {
+        {
+            let sess = MemoSession::new();
+            let e1 = sess.parse_s(&MNest, "((a+b)").unwrap_err();
+            let e2 = MNest.parse_s("((a+b)").unwrap_err();
+            assert_eq!(e1, e2);
+            assert_eq!(e1.found, "");
+            assert!(!sess.is_empty());
+        }
+        assert!(!memo_active());
+        assert_eq!(MNest.parse_s("a+(b)"), Ok("ab".to_string()));
+    }
+
+    #[test]
+    fn test_memo_keeps_inputs_apart() {
+        //without clearing, a second input at the same positions must not reuse results
+        let _sess = MemoSession::new();
+        assert_eq!(MNest.parse_s("a+bc"), Ok("abc".to_string()));
+        assert_eq!(MNest.parse_s("x+yz"), Ok("xyz".to_string()));
+        let s = String::from("a+bc");
+        assert_eq!(MNest.parse_s(&s[..]), Ok("abc".to_string()));
+        assert_eq!(MNest.parse_s(&s[..1]), Ok("a".to_string()));
+
+        //nor inside a different indented block
+        parser! { (Word->String) memo(common::Ident) }
+        let p = or(
+            ("a:", indented_block(Word)).map(|(_, v)| v),
+            Word.map(|w| vec![w]),
+        );
+        assert_eq!(p.parse_s("ab"), Ok(vec!["ab".to_string()]));
+    }
+
+    parser! { (Rows->Vec<String>) memo(RowsBody) }
+    parser! { (RowsBody->Vec<String>) plus(common::Ident.then_ig("\n")) }
+
+    #[test]
+    fn test_memo_hits_land_where_the_parse_ended() {
+        //the second branch reuses Rows, and must carry on from the same index, line and col
+        let p = || {
+            let row = |end| (Rows, end, index, line_col).map(|(_, _, i, lc)| (i, lc));
+            or(row("!"), row("\t?"))
+        };
+        let doc = "# x\nab\ncd\n\t?";
+        let it = LCChars::at(
+            &doc[4..],
+            Loc {
+                index: 4,
+                line: 1,
+                col: 0,
+            },
+        );
+        let plain = p().parse(&it).map(|(_, v, _)| v);
+        assert_eq!(plain, Ok((None, (3, 2))));
+        let sess = MemoSession::new();
+        assert_eq!(p().parse(&it).map(|(_, v, _)| v), plain);
+        assert_eq!(sess.len(), 1);
+
+        //stored errors still find the text they were at
+        let q = || or((Rows, "!").map(|(r, _)| r), (Rows, "?").map(|(r, _)| r));
+        let e = sess.parse_s(&q(), "ab\ncd ef").unwrap_err();
+        assert_eq!(e, q().parse_s("ab\ncd ef").unwrap_err());
+        assert_eq!((e.index, e.found), (Some(3), "cd ef"));
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Ex {
+        V(isize),
+        Sub(Box<Ex>, Box<Ex>),
+        Call(Box<Ex>, Vec<Ex>),
+    }
+
+    parser! { (LExpr->Ex) left_rec(LExprBody) }
+    parser! {
+        (LExprBody->Ex)
+        or!(
+            (LExpr, "-", LPost).map(|(a, _, b)| Ex::Sub(Box::new(a), Box::new(b))),
+            LPost,
+        )
+    }
+
+    parser! { (LPost->Ex) left_rec(LPostBody) }
+    parser! {
+        (LPostBody->Ex)
+        or!(
+            (LPost, "(", sep_until_ig(LExpr, ",", ")"))
+                .map(|(f, _, args)| Ex::Call(Box::new(f), args)),
+            common::Int.map(Ex::V),
+        )
+    }
+
+    parser! {
+        (Bad->Ex)
+        or(
+            (Bad, "-", common::Int).map(|(a, _, b)| Ex::Sub(Box::new(a), Box::new(Ex::V(b)))),
+            common::Int.map(Ex::V),
+        )
+    }
+
+    #[test]
+    fn test_left_rec_is_left_assoc() {
+        use Ex::*;
+        let sb = |a, b| Sub(Box::new(a), Box::new(b));
+        assert_eq!(LExpr.parse_s("1-2-3"), Ok(sb(sb(V(1), V(2)), V(3))));
+        assert_eq!(
+            LExpr.parse_s("1-2(3-4)(5)"),
+            Ok(sb(
+                V(1),
+                Call(
+                    Box::new(Call(Box::new(V(2)), vec![sb(V(3), V(4))])),
+                    vec![V(5)]
+                )
+            ))
+        );
+        assert_eq!(LExpr.parse_s("7"), Ok(V(7)));
+        assert!(LExpr.parse_s("-").is_err());
+        assert!(!memo_active());
+    }
+
+    parser! { (Counted->isize) left_rec(CountedBody).update_state(|n: &mut usize, _| *n += 1) }
+    parser! {
+        (CountedBody->isize)
+        or((Counted, "-", common::Int).map(|(a, _, b)| a - b), common::Int)
+    }
+
+    parser! { (Adds->isize) left_rec(AddsBody) }
+    parser! { (AddsBody->isize) or((Adds, "+", common::Int).map(|(a, _, b)| a + b), common::Int) }
+    parser! { (Subs->isize) left_rec(SubsBody) }
+    parser! { (SubsBody->isize) or((Subs, "-", common::Int).map(|(a, _, b)| a - b), common::Int) }
+
+    #[test]
+    fn test_left_recs_at_the_same_position() {
+        //each rule grows its own seed, though both start at 0 in one session
+        let sess = MemoSession::new();
+        let p = or((Adds, EOI).map(|(v, _)| v), (Subs, EOI).map(|(v, _)| -v));
+        assert_eq!(sess.parse_s(&p, "9-2-3"), Ok(-4));
+        assert_eq!(sess.parse_s(&p, "9+2+3"), Ok(14));
+        let p = (Adds, "/", Subs).map(|(a, _, b)| a * 100 + b);
+        assert_eq!(sess.parse_s(&p, "1+2/5-1"), Ok(304));
+    }
+
+    #[test]
+    fn test_left_rec_with_state() {
+        //the first branch changes the state before reaching the same position,
+        //so its stored result must not be used by the second
+        let p = or(
+            ("".update_state(|n: &mut usize, _| *n += 10), Counted, "!").map(|(_, v, _)| v),
+            Counted,
+        );
+        let sess = MemoSession::new();
+        //the recursive call counts once for each step grown, then once more for the whole
+        assert_eq!(p.parse_s_state("5-2", 0usize), Ok((3, 2)));
+        assert!(sess.is_empty());
+
+        //a state of the wrong type is an error, not a panic
+        fn swap<'a>(it: &LCChars<'a>) -> ParseRes<'a, ()> {
+            Ok((it.clone().with_state('x'), (), None))
+        }
+        let e = swap.parse_s_state("", 0usize).unwrap_err();
+        assert_eq!(e.exp, Expected::Named("user state"));
+    }
+
+    #[test]
+    fn test_unmarked_left_rec_errors() {
+        let sess = MemoSession::new();
+        let e = sess.parse_s(&Bad, "1-2").unwrap_err();
+        assert!(e.is_brk);
+        assert_eq!(
+            e.exp,
+            Expected::OneOf(vec![Expected::LeftRec("Bad"), Bad.expected()])
+        );
+        assert_eq!(&e.context[..], &["Bad"]);
+        assert_eq!(e.index, Some(0));
+        MEMO.with(|m| assert!(m.borrow().frames.is_empty()));
+    }
+
+    parser! { (Outer->String) (Nested, ",", common::Ident).map(|(a, _, b)| a + &b) }
+    parser! { (Nested->String) nested }
+
+    fn nested<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
+        //once only, the inner parse reaches here at index 0 too
+        if !NESTED.with(|n| n.replace(true)) {
+            Outer.parse_s("x,y").map_err(|_| it.err(Expected::Nil))?;
+        }
+        common::Ident.parse(it)
+    }
+
+    parser! { (Boom->()) boom }
+
+    fn boom<'a>(_: &LCChars<'a>) -> ParseRes<'a, ()> {
+        panic!("boom")
+    }
+
+    #[test]
+    fn test_guard_frames() {
+        let sess = MemoSession::new();
+        //parsing another input inside a rule, at the same index, is not left recursion
+        assert_eq!(sess.parse_s(&Nested, "ab"), Ok("ab".to_string()));
+
+        //frames are removed even when a rule panics
+        let r = std::panic::catch_unwind(|| Boom.parse_s(""));
+        assert!(r.is_err());
+        MEMO.with(|m| assert!(m.borrow().frames.is_empty()));
+    }
+}
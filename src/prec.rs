@@ -0,0 +1,417 @@
+//! Operator precedence parsing (Pratt style precedence climbing)
+//!
+//! Rather than contorting a grammar into right recursive chains, give ```prec_climb``` a parser for
+//! the atoms of the expression and a parser for the infix operators. Operator values describe
+//! their own binding power and how they build the tree, by implementing ```InfixOp```.
+//! Prefix and postfix operators can be added with the ```prefix``` and ```postfix``` methods.
+//!
+//! ```rust
+//! use gobble::*;
+//! #[derive(Debug, PartialEq)]
+//! enum Expr {
+//!     Val(isize),
+//!     Neg(Box<Expr>),
+//!     Bin(char, Box<Expr>, Box<Expr>),
+//! }
+//!
+//! struct Bin(char);
+//! impl InfixOp<Expr> for Bin {
+//!     fn power(&self) -> (usize, Assoc) {
+//!         match self.0 {
+//!             '^' => (3, Assoc::Right),
+//!             '*' | '/' => (2, Assoc::Left),
+//!             _ => (1, Assoc::Left),
+//!         }
+//!     }
+//!     fn build(self, l: Expr, r: Expr) -> Expr {
+//!         Expr::Bin(self.0, Box::new(l), Box::new(r))
+//!     }
+//! }
+//!
+//! struct Neg;
+//! impl PrefixOp<Expr> for Neg {
+//!     fn power(&self) -> usize {
+//!         4
+//!     }
+//!     fn build(self, v: Expr) -> Expr {
+//!         Expr::Neg(Box::new(v))
+//!     }
+//! }
+//!
+//! let p = prec_climb(ws__(common::UInt).map(|n| Expr::Val(n as isize)), "+-*/^".one().map(Bin))
+//!     .prefix(ws__('-').map(|_| Neg));
+//!
+//! let v = p.parse_s("1 + -2*3").unwrap();
+//! assert_eq!(
+//!     v,
+//!     Expr::Bin(
+//!         '+',
+//!         Box::new(Expr::Val(1)),
+//!         Box::new(Expr::Bin(
+//!             '*',
+//!             Box::new(Expr::Neg(Box::new(Expr::Val(2)))),
+//!             Box::new(Expr::Val(3))
+//!         ))
+//!     )
+//! );
+//! ```
+use crate::borrow::Owned;
+use crate::describe::Gram;
+use crate::err::*;
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::*;
+use std::convert::Infallible;
+
+/// How an infix operator groups with others of the same binding power
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Assoc {
+    /// "a-b-c" is "(a-b)-c"
+    Left,
+    /// "a^b^c" is "a^(b^c)"
+    Right,
+    /// "a<b<c" is an error
+    Neither,
+}
+
+/// Implemented by the result of an infix operator parser
+pub trait InfixOp<V> {
+    /// The binding power of the operator, higher binds tighter
+    fn power(&self) -> (usize, Assoc);
+    /// Combine the left and right operands into one value
+    fn build(self, l: V, r: V) -> V;
+}
+
+/// Implemented by the result of a prefix operator parser
+pub trait PrefixOp<V> {
+    /// The binding power of the operator, compared with that of infix operators on its right
+    fn power(&self) -> usize;
+    fn build(self, v: V) -> V;
+}
+
+/// Implemented by the result of a postfix operator parser
+pub trait PostfixOp<V> {
+    /// The binding power of the operator, compared with that of infix operators on its left
+    fn power(&self) -> usize;
+    fn build(self, v: V) -> V;
+}
+
+impl<V> InfixOp<V> for Infallible {
+    fn power(&self) -> (usize, Assoc) {
+        match *self {}
+    }
+    fn build(self, _: V, _: V) -> V {
+        match self {}
+    }
+}
+
+impl<V> PrefixOp<V> for Infallible {
+    fn power(&self) -> usize {
+        match *self {}
+    }
+    fn build(self, _: V) -> V {
+        match self {}
+    }
+}
+
+impl<V> PostfixOp<V> for Infallible {
+    fn power(&self) -> usize {
+        match *self {}
+    }
+    fn build(self, _: V) -> V {
+        match self {}
+    }
+}
+
+/// A parser that never matches, used where a table has no operators of a kind
+#[derive(Copy, Clone)]
+pub struct NoOp;
+
+impl Owned for NoOp {}
+
+impl<I: ?Sized + Input> Parser<I> for NoOp {
+    type Out = Infallible;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Infallible, I> {
+        it.err_r(Expected::Nil)
+    }
+    fn expected(&self) -> Expected {
+        Expected::Nil
+    }
+    fn describe(&self) -> Gram {
+        Gram::Alt(Vec::new())
+    }
+}
+
+/// Does 'g' match nothing at all, as ```NoOp``` describes itself
+fn is_never(g: &Gram) -> bool {
+    matches!(g, Gram::Alt(v) if v.is_empty())
+}
+
+#[derive(Clone)]
+pub struct PrecClimb<A, Pre, In, Post> {
+    atom: A,
+    pre: Pre,
+    inf: In,
+    post: Post,
+}
+
+/// Builds an expression parser from a parser for the atoms (numbers, brackets, etc) and a parser
+/// for the infix operators. The operators decide their own binding power through ```InfixOp```
+pub fn prec_climb<A, In>(atom: A, inf: In) -> PrecClimb<A, NoOp, In, NoOp> {
+    PrecClimb {
+        atom,
+        pre: NoOp,
+        inf,
+        post: NoOp,
+    }
+}
+
+impl<A, Pre, In, Post> PrecClimb<A, Pre, In, Post> {
+    /// Replace the prefix operators
+    pub fn prefix<P>(self, pre: P) -> PrecClimb<A, P, In, Post> {
+        PrecClimb {
+            atom: self.atom,
+            pre,
+            inf: self.inf,
+            post: self.post,
+        }
+    }
+
+    /// Replace the postfix operators
+    pub fn postfix<P>(self, post: P) -> PrecClimb<A, Pre, In, P> {
+        PrecClimb {
+            atom: self.atom,
+            pre: self.pre,
+            inf: self.inf,
+            post,
+        }
+    }
+
+    fn operand<'a, I>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, A::Out, I>
+    where
+        I: ?Sized + Input,
+        A: Parser<I>,
+        Pre: Parser<I>,
+        In: Parser<I>,
+        Post: Parser<I>,
+        Pre::Out: PrefixOp<A::Out>,
+        In::Out: InfixOp<A::Out>,
+        Post::Out: PostfixOp<A::Out>,
+    {
+        match self.pre.parse(it) {
+            Ok((it2, op, c1)) => {
+                let pw = op.power();
+                let (it3, v, ct) = self.climb(&it2, pw).map_err(|e| e.join_op(c1))?;
+                Ok((it3, op.build(v), ct))
+            }
+            Err(e) if e.is_brk => Err(e),
+            Err(pe) => match self.atom.parse(it) {
+                Ok(r) => Ok(r),
+                Err(e) if e.is_brk => Err(e),
+                Err(e) => Err(e.longer(pe)),
+            },
+        }
+    }
+
+    fn climb<'a, I>(&self, it: &LCChars<'a, I>, min: usize) -> ParseRes<'a, A::Out, I>
+    where
+        I: ?Sized + Input,
+        A: Parser<I>,
+        Pre: Parser<I>,
+        In: Parser<I>,
+        Post: Parser<I>,
+        Pre::Out: PrefixOp<A::Out>,
+        In::Out: InfixOp<A::Out>,
+        Post::Out: PostfixOp<A::Out>,
+    {
+        let (mut it, mut res, mut ct) = self.operand(it)?;
+        let mut last_neither = None;
+        loop {
+            match self.post.parse(&it) {
+                Ok((it2, op, c2)) if op.power() >= min => {
+                    res = op.build(res);
+                    it = it2;
+                    ct = c2;
+                    continue;
+                }
+                Ok(_) => return Ok((it, res, ct)),
+                Err(e) if e.is_brk => return Err(e),
+                Err(e) => ct = Some(e.join_op(ct)),
+            }
+            let (it2, op, c2) = match self.inf.parse(&it) {
+                Ok(r) => r,
+                Err(e) if e.is_brk => return Err(e),
+                Err(e) => return Ok((it, res, Some(e.join_op(ct)))),
+            };
+            let (pw, assoc) = op.power();
+            if pw < min {
+                return Ok((it, res, ct));
+            }
+            if last_neither == Some(pw) {
+                return it.err_r(Expected::Str("No chained non-associative operators"));
+            }
+            let next_min = match assoc {
+                Assoc::Right => pw,
+                _ => pw + 1,
+            };
+            let (it3, r, c3) = self.climb(&it2, next_min).map_err(|e| e.join_op(c2))?;
+            last_neither = match assoc {
+                Assoc::Neither => Some(pw),
+                _ => None,
+            };
+            res = op.build(res, r);
+            it = it3;
+            ct = c3;
+        }
+    }
+}
+
+impl<A, Pre, In, Post> Owned for PrecClimb<A, Pre, In, Post> {}
+
+impl<I, A, Pre, In, Post> Parser<I> for PrecClimb<A, Pre, In, Post>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    Pre: Parser<I>,
+    In: Parser<I>,
+    Post: Parser<I>,
+    Pre::Out: PrefixOp<A::Out>,
+    In::Out: InfixOp<A::Out>,
+    Post::Out: PostfixOp<A::Out>,
+{
+    type Out = A::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, A::Out, I> {
+        self.climb(it, 0)
+    }
+    fn expected(&self) -> Expected {
+        self.pre.expected().join(self.atom.expected())
+    }
+    /// operand (infix operand)*, where each operand is prefix* atom postfix*.
+    /// Binding powers are left to the operators, and kinds with no operators are left out
+    fn describe(&self) -> Gram {
+        let ops = |g: Gram| (!is_never(&g)).then(|| g.star());
+        let operand = ops(self.pre.describe())
+            .into_iter()
+            .chain(Some(self.atom.describe()))
+            .chain(ops(self.post.describe()))
+            .reduce(Gram::seq)
+            .unwrap_or(Gram::Seq(Vec::new()));
+        match self.inf.describe() {
+            inf if is_never(&inf) => operand,
+            inf => Gram::sep_plus(operand, inf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Ex {
+        V(usize),
+        B(char, Box<Ex>, Box<Ex>),
+        Pre(char, Box<Ex>),
+        Post(char, Box<Ex>),
+    }
+
+    fn b(c: char, l: Ex, r: Ex) -> Ex {
+        Ex::B(c, Box::new(l), Box::new(r))
+    }
+
+    struct Bin(char);
+    impl InfixOp<Ex> for Bin {
+        fn power(&self) -> (usize, Assoc) {
+            match self.0 {
+                '=' => (1, Assoc::Neither),
+                '+' | '-' => (2, Assoc::Left),
+                '*' | '/' => (3, Assoc::Left),
+                _ => (5, Assoc::Right),
+            }
+        }
+        fn build(self, l: Ex, r: Ex) -> Ex {
+            b(self.0, l, r)
+        }
+    }
+
+    struct Un(char);
+    impl PrefixOp<Ex> for Un {
+        fn power(&self) -> usize {
+            4
+        }
+        fn build(self, v: Ex) -> Ex {
+            Ex::Pre(self.0, Box::new(v))
+        }
+    }
+    impl PostfixOp<Ex> for Un {
+        fn power(&self) -> usize {
+            6
+        }
+        fn build(self, v: Ex) -> Ex {
+            Ex::Post(self.0, Box::new(v))
+        }
+    }
+
+    fn atom<'a>(it: &LCChars<'a>) -> ParseRes<'a, Ex> {
+        or(
+            ws__(common::UInt).map(Ex::V),
+            middle(ws__("("), expr(), ws__(")")),
+        )
+        .parse(it)
+    }
+
+    fn expr() -> impl Parser<Out = Ex> {
+        prec_climb(atom, "+-*/^=".one().map(Bin))
+            .prefix('-'.map(Un))
+            .postfix('!'.map(Un))
+    }
+
+    #[test]
+    fn test_precedence_and_assoc() {
+        use Ex::V;
+        let v = expr().parse_s("1 + 2 * 3 - 4").unwrap();
+        assert_eq!(v, b('-', b('+', V(1), b('*', V(2), V(3))), V(4)));
+
+        let v = expr().parse_s("2^3^4").unwrap();
+        assert_eq!(v, b('^', V(2), b('^', V(3), V(4))));
+
+        let v = expr().parse_s("(1+2)*3").unwrap();
+        assert_eq!(v, b('*', b('+', V(1), V(2)), V(3)));
+    }
+
+    #[test]
+    fn test_prefix_postfix() {
+        use Ex::*;
+        let v = expr().parse_s("-2^3!").unwrap();
+        assert_eq!(
+            v,
+            Pre('-', Box::new(b('^', V(2), Post('!', Box::new(V(3))))))
+        );
+        let v = expr().parse_s("-2*3").unwrap();
+        assert_eq!(v, b('*', Pre('-', Box::new(V(2))), V(3)));
+    }
+
+    #[test]
+    fn test_describe_from_parts() {
+        let n = || common::UInt.map(Ex::V);
+        let p = prec_climb(n(), "+*".one().map(Bin));
+        assert_eq!(p.describe().to_ebnf(), "UInt ([+*] UInt)*");
+        let p = p.prefix('-'.map(Un)).postfix('!'.map(Un));
+        assert_eq!(
+            p.describe().to_ebnf(),
+            "\"-\"* UInt \"!\"* ([+*] \"-\"* UInt \"!\"*)*"
+        );
+        let g = Generator::new(3).sample(&p).unwrap();
+        assert!(first(p, EOI).parse_s(&g).is_ok(), "{}", g);
+    }
+
+    #[test]
+    fn test_missing_operand_errors() {
+        let e = expr().parse_s("1 + * 3").unwrap_err();
+        assert_eq!(e.index, Some(4));
+        let e = expr().parse_s("1 = 2 = 3").unwrap_err();
+        assert_eq!(e.index, Some(6));
+        assert!(expr().parse_s("1 = 2").is_ok());
+    }
+}
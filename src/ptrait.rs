@@ -1,14 +1,22 @@
-use crate::err::{Expected, PErr};
-use crate::iter::LCChars;
+use crate::borrow::{Owned, RefParser};
+use crate::boxed::{BoxedParser, RcParser};
+use crate::describe::Gram;
+use crate::err::{Expected, PErr, StrungError};
+use crate::input::Input;
+use crate::iter::{Cols, LCChars};
 use crate::pull::PullParser;
 use crate::reader::EOI;
+use crate::span::{spanned, Loc, PSpanned};
+use crate::state::{try_map_state, update_state, TryMapState, UpdateState};
+use std::marker::PhantomData;
 
-pub type ParseRes<'a, V> = Result<(LCChars<'a>, V, Option<PErr<'a>>), PErr<'a>>;
+pub type ParseRes<'a, V, I = str> = Result<(LCChars<'a, I>, V, Option<PErr<'a>>), PErr<'a>>;
 
-/// The core trait for parsing
-pub trait Parser: Sized {
+/// The core trait for parsing.
+/// 'I' is the type of input parsed, most parsers work on str, see ```Input``` for others.
+pub trait Parser<I: ?Sized + Input = str>: Sized {
     type Out;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out>;
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I>;
 
     fn expected(&self) -> Expected {
         Expected::ObOn(
@@ -16,41 +24,125 @@ pub trait Parser: Sized {
             std::any::type_name::<Self::Out>(),
         )
     }
-    fn parse_s<'a>(&self, s: &'a str) -> Result<Self::Out, PErr<'a>> {
-        self.parse(&LCChars::str(s)).map(|(_, v, _)| v)
+
+    /// The shape of the grammar this parses, see ```Grammar```.
+    /// Without an override this is worked out from ```expected```
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.expected())
+    }
+
+    fn parse_s<'a>(&self, s: &'a I) -> Result<Self::Out, PErr<'a>> {
+        self.parse(&LCChars::new(s)).map(|(_, v, _)| v)
+    }
+
+    /// Like ```parse_s```, but errors count their columns with 'cols', see ```Cols```
+    fn parse_s_cols<'a>(&self, s: &'a I, cols: Cols) -> Result<Self::Out, PErr<'a>> {
+        self.parse(&LCChars::new(s).with_cols(cols))
+            .map(|(_, v, _)| v)
+    }
+
+    /// Parse 's' as though it starts at 'start' in a larger document, see ```LCChars::at```
+    fn parse_at<'a>(&self, s: &'a I, start: Loc) -> Result<Self::Out, PErr<'a>> {
+        self.parse(&LCChars::at(s, start)).map(|(_, v, _)| v)
+    }
+
+    /// Parse 's' carrying the user state 'st', returning the state as it was at the end.
+    /// Errors if the parser replaced the state with one of another type.
+    /// See ```get_state```
+    fn parse_s_state<'a, S: Clone + 'static>(
+        &self,
+        s: &'a I,
+        st: S,
+    ) -> Result<(Self::Out, S), PErr<'a>> {
+        let (it, v, _) = self.parse(&LCChars::new(s).with_state(st))?;
+        match it.state::<S>() {
+            Some(st) => Ok((v, st.clone())),
+            None => Err(it.err(crate::state::no_state())),
+        }
+    }
+
+    fn parse_sn<'a>(&self, s: &'a I) -> Result<(&'a I, Self::Out), PErr<'a>> {
+        self.parse(&LCChars::new(s)).map(|(i, v, _)| (i.rest(), v))
     }
 
-    fn parse_sn<'a>(&self, s: &'a str) -> Result<(&'a str, Self::Out), PErr<'a>> {
-        self.parse(&LCChars::str(s))
-            .map(|(i, v, _)| (i.as_str(), v))
+    /// Parse the string, returning the result (if any) and every error found along the way.
+    /// Errors are collected by ```recover``` parsers, so a grammar built with them can report
+    /// all of its problems at once.
+    /// If the parse fails, the final error comes last, after those recovered from on the way
+    /// to the furthest recovery
+    fn parse_s_all(&self, s: &I) -> (Option<Self::Out>, Vec<StrungError>) {
+        let it = LCChars::new(s).with_furthest_recovered();
+        match self.parse(&it) {
+            Ok((nit, v, _)) => (Some(v), nit.recovered()),
+            Err(e) => {
+                let mut errs = it.furthest_recovered();
+                errs.push(e.strung());
+                (None, errs)
+            }
+        }
     }
     /// returns a parser that will combine the results of this and the given parser
     /// into a tuple
-    fn then<P: Parser<Out = V2>, V2>(self, b: P) -> Then<Self, P> {
+    fn then<P>(self, b: P) -> Then<Self, P> {
         Then { a: self, b }
     }
 
+    /// returns a parser that uses the result of this parser to choose the next, and returns
+    /// both results as a tuple. Useful when a length or kind decides what follows.
+    /// 'f' only borrows the result, as it is kept for the tuple, to give it away
+    /// and keep only the second result use ```and_then```
+    ///
+    /// ```rust
+    /// use gobble::*;
+    /// let p = common::UInt.then_ig(":").then_with(|n| exact(Alpha.one(), *n));
+    /// assert_eq!(p.parse_s("3:abcd"), Ok((3, vec!['a', 'b', 'c'])));
+    /// ```
+    fn then_with<P, F: Fn(&Self::Out) -> P>(self, f: F) -> ThenWith<Self, F> {
+        ThenWith { a: self, f }
+    }
+
+    /// returns a parser that passes the result of this parser to 'f' to build the next,
+    /// and returns only the result of that one. Raw strings and heredocs need this,
+    /// as the terminator is only known once the start has been parsed.
+    /// Unlike ```then_with```, 'f' takes the result by value, and it is not returned
+    ///
+    /// ```rust
+    /// use gobble::*;
+    /// let raw = "r"
+    ///     .ig_then('#'.star())
+    ///     .then_ig('"')
+    ///     .and_then(|h| chars_until(Any.one(), tag(format!("\"{}", h))).map(|(s, _)| s));
+    /// assert_eq!(raw.parse_s(r###"r##"say "#" and "##"###), Ok(r##"say "#" and "##.to_string()));
+    /// ```
+    fn and_then<P, F: Fn(Self::Out) -> P>(self, f: F) -> AndThen<Self, F> {
+        AndThen { a: self, f }
+    }
+
     /// returns a Parser that will require the given parser completes, but ignores its result
     /// useful for dropping brackets and whitespace
-    fn then_ig<P: Parser<Out = V2>, V2>(self, b: P) -> ThenIg<Self, P> {
+    fn then_ig<P>(self, b: P) -> ThenIg<Self, P> {
         ThenIg { a: self, b }
     }
     /// returns a Parser that will require this parser completes, but only return the
     /// result of the given parser
     /// useful for dropping brackets and whitespace etc
-    fn ig_then<P: Parser<Out = V2>, V2>(self, b: P) -> IgThen<Self, P> {
+    fn ig_then<P>(self, b: P) -> IgThen<Self, P> {
         IgThen { a: self, b }
     }
     /// Returns a Parser that will try both child parsers, (A first) and return the first successfl
     /// result
-    fn or<P: Parser<Out = Self::Out>>(self, p: P) -> Or<Self, P> {
+    fn or<P>(self, p: P) -> Or<Self, P> {
         Or { a: self, b: p }
     }
 
     /// Returns a Parser that converts the result of a successful parse to a different type.
     /// Much like map on iterators and Result
     fn map<F: Fn(Self::Out) -> V2, V2>(self, f: F) -> Map<Self, V2, F> {
-        Map { a: self, f }
+        Map {
+            a: self,
+            f,
+            b: PhantomData,
+        }
     }
 
     /// Returns a Parser that converts the result of a successful parse to a different type.
@@ -59,7 +151,29 @@ pub trait Parser: Sized {
     /// be attacked by the TryMap object
     /// so this will pass that error up correctly
     fn try_map<F: Fn(Self::Out) -> Result<V2, Expected>, V2>(self, f: F) -> TryMap<Self, V2, F> {
-        TryMap { a: self, f }
+        TryMap {
+            a: self,
+            f,
+            b: PhantomData,
+        }
+    }
+
+    /// Change the user state with the result, after this parser succeeds
+    fn update_state<S, F>(self, f: F) -> UpdateState<Self, S, F>
+    where
+        S: Clone + 'static,
+        F: Fn(&mut S, &Self::Out),
+    {
+        update_state(self, f)
+    }
+
+    /// Like ```try_map```, but with the user state to check the result against
+    fn try_map_state<S, V, F>(self, f: F) -> TryMapState<Self, S, V, F>
+    where
+        S: 'static,
+        F: Fn(&S, Self::Out) -> Result<V, Expected>,
+    {
+        try_map_state(self, f)
     }
 
     fn asv<R: Clone>(self, r: R) -> As<Self, R> {
@@ -78,18 +192,73 @@ pub trait Parser: Sized {
         Break { p: self }
     }
 
-    fn pull<'a>(self, s: &'a str) -> PullParser<'a, Self, EOI> {
+    /// Gives the parser a name to use in errors, in place of what it would have expected,
+    /// when it fails at the point it started. This is what ```parser!``` does with the rule name
+    ///
+    /// ```rust
+    /// use gobble::*;
+    /// let p = (Alpha, '_').plus().named("identifier");
+    /// let e = p.parse_s("33").unwrap_err();
+    /// assert_eq!(e.exp, Expected::Named("identifier"));
+    /// ```
+    fn named(self, name: &'static str) -> PNamed<Self> {
+        PNamed { p: self, name }
+    }
+
+    /// If the parser fails, the error remembers that it happened within 'name'.
+    /// These build up as the error passes out through each context, so the error can say
+    /// where it was, like "FSig > argument list".
+    /// Rules made by ```parser!``` add their name when an error happens after their start
+    ///
+    /// ```rust
+    /// use gobble::*;
+    /// let args = "(".ig_then(sep_until_ig(common::Ident, ",", ")"));
+    /// let p = (common::Ident, args.context("argument list"));
+    /// let e = p.parse_s("load(fname,3)").unwrap_err();
+    /// assert_eq!(e.index, Some(11));
+    /// assert_eq!(*e.context, ["argument list"]);
+    /// ```
+    fn context(self, name: &'static str) -> PContext<Self> {
+        PContext { p: self, name }
+    }
+
+    /// Hide the type of this parser, so it can be stored with others, see ```BoxedParser```
+    fn boxed<'p>(self) -> BoxedParser<'p, Self::Out, I>
+    where
+        Self: 'p,
+    {
+        BoxedParser::new(self)
+    }
+
+    /// Like ```boxed``` but can be cloned, see ```RcParser```
+    fn rc<'p>(self) -> RcParser<'p, Self::Out, I>
+    where
+        Self: 'p,
+    {
+        RcParser::new(self)
+    }
+
+    /// Wraps the result in a ```Spanned``` with the start and end of the input it came from
+    fn spanned(self) -> PSpanned<Self> {
+        spanned(self)
+    }
+
+    fn pull<'a>(self, s: &'a I) -> PullParser<'a, Self, EOI, I> {
         PullParser::new(self, s)
     }
 
-    fn pull_to<'a, E: Parser>(self, end: E, s: &'a str) -> PullParser<'a, Self, E> {
+    fn pull_to<'a, E: Parser<I>>(self, end: E, s: &'a I) -> PullParser<'a, Self, E, I> {
         PullParser::with_end(self, end, s)
     }
 }
 
-impl<V, F: for<'a> Fn(&LCChars<'a>) -> ParseRes<'a, V>> Parser for F {
+impl<I, V, F> Parser<I> for F
+where
+    I: ?Sized + Input,
+    F: for<'a> Fn(&LCChars<'a, I>) -> ParseRes<'a, V, I>,
+{
     type Out = V;
-    fn parse<'b>(&self, i: &LCChars<'b>) -> ParseRes<'b, V> {
+    fn parse<'b>(&self, i: &LCChars<'b, I>) -> ParseRes<'b, V, I> {
         self(i)
     }
 }
@@ -102,20 +271,41 @@ impl Parser for &'static str {
     fn expected(&self) -> Expected {
         Expected::Str(self)
     }
+    fn describe(&self) -> Gram {
+        Gram::Terminal(self.to_string())
+    }
 }
 
+/// As a ```RefParser``` a tag returns the matching slice of the input, so it can sit in an
+/// ```or``` beside other borrowing parsers
+impl RefParser for &'static str {
+    type Out<'a> = &'a str;
+    fn parse_ref<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, &'a str> {
+        let (nit, _, ct) = crate::reader::do_tag(i, self)?;
+        Ok((nit.clone(), i.slice_to(&nit), ct))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::Str(self)
+    }
+}
+
+impl Owned for char {}
+
 impl Parser for char {
     type Out = char;
     fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, char> {
         let mut i2 = i.clone();
         match i2.next() {
             Some(c) if c == *self => Ok((i2, *self, None)),
-            _ => i2.err_rp(self),
+            _ => i.err_rp(self),
         }
     }
     fn expected(&self) -> Expected {
         Expected::Char(*self)
     }
+    fn describe(&self) -> Gram {
+        Gram::Terminal(self.to_string())
+    }
 }
 
 #[derive(Clone)]
@@ -124,13 +314,34 @@ pub struct Then<A, B> {
     b: B,
 }
 
-impl<A, B> Parser for Then<A, B>
+impl<I, A, B> RefParser<I> for Then<A, B>
 where
-    A: Parser,
-    B: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+{
+    type Out<'a>
+        = (A::Out<'a>, B::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (i, v1, c1) = self.a.parse_ref(i)?;
+        let (i, v2, e) = self.b.parse_ref(&i).map_err(|e| e.join_op(c1))?;
+        Ok((i, (v1, v2), e))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.a.expected_ref(), self.b.expected_ref())
+    }
+}
+
+impl<I, A, B> Parser<I> for Then<A, B>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
 {
     type Out = (A::Out, B::Out);
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (i, v1, c1) = self.a.parse(i)?;
         let (i, v2, e) = self.b.parse(&i).map_err(|e| e.join_op(c1))?;
         Ok((i, (v1, v2), e))
@@ -138,21 +349,141 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.a.expected(), self.b.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe(), self.b.describe())
+    }
 }
 
 #[derive(Clone)]
-pub struct ThenIg<A, B> {
+pub struct ThenWith<A, F> {
     a: A,
-    b: B,
+    f: F,
 }
 
-impl<A, B> Parser for ThenIg<A, B>
+/// The parser that decides must be a ```Parser```, only the one it builds may borrow
+impl<I, A, P, F> RefParser<I> for ThenWith<A, F>
 where
-    A: Parser,
-    B: Parser,
+    I: ?Sized + Input,
+    A: Parser<I>,
+    P: RefParser<I>,
+    F: Fn(&A::Out) -> P,
+{
+    type Out<'a>
+        = (A::Out, P::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (i, v1, c1) = self.a.parse(i)?;
+        let (i, v2, c2) = (self.f)(&v1).parse_ref(&i).map_err(|e| e.join_op(c1))?;
+        Ok((i, (v1, v2), c2))
+    }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected()
+    }
+}
+
+impl<I, A, P, F> Parser<I> for ThenWith<A, F>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    P: Parser<I>,
+    F: Fn(&A::Out) -> P,
+{
+    type Out = (A::Out, P::Out);
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        let (i, v1, c1) = self.a.parse(i)?;
+        let (i, v2, c2) = (self.f)(&v1).parse(&i).map_err(|e| e.join_op(c1))?;
+        Ok((i, (v1, v2), c2))
+    }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe(), Gram::Other("then_with".to_string()))
+    }
+}
+
+#[derive(Clone)]
+pub struct AndThen<A, F> {
+    a: A,
+    f: F,
+}
+
+/// The parser that decides must be a ```Parser```, only the one it builds may borrow
+impl<I, A, P, F> RefParser<I> for AndThen<A, F>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    P: RefParser<I>,
+    F: Fn(A::Out) -> P,
+{
+    type Out<'a>
+        = P::Out<'a>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, P::Out<'a>, I> {
+        let (i, v1, c1) = self.a.parse(i)?;
+        (self.f)(v1).parse_ref(&i).map_err(|e| e.join_op(c1))
+    }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected()
+    }
+}
+
+impl<I, A, P, F> Parser<I> for AndThen<A, F>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    P: Parser<I>,
+    F: Fn(A::Out) -> P,
+{
+    type Out = P::Out;
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        let (i, v1, c1) = self.a.parse(i)?;
+        (self.f)(v1).parse(&i).map_err(|e| e.join_op(c1))
+    }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe(), Gram::Other("and_then".to_string()))
+    }
+}
+
+#[derive(Clone)]
+pub struct ThenIg<A, B> {
+    pub(crate) a: A,
+    pub(crate) b: B,
+}
+
+impl<I, A, B> RefParser<I> for ThenIg<A, B>
+where
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+{
+    type Out<'a>
+        = A::Out<'a>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, A::Out<'a>, I> {
+        let (i, v1, c1) = self.a.parse_ref(i)?;
+        let (i, _, ct) = self.b.parse_ref(&i).map_err(|e| e.join_op(c1))?;
+        Ok((i, v1, ct))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.a.expected_ref(), self.b.expected_ref())
+    }
+}
+
+impl<I, A, B> Parser<I> for ThenIg<A, B>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
 {
     type Out = A::Out;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (i, v1, c1) = self.a.parse(i)?;
         let (i, _, ct) = self.b.parse(&i).map_err(|e| e.join_op(c1))?;
         Ok((i, v1, ct))
@@ -160,21 +491,45 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.a.expected(), self.b.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe(), self.b.describe())
+    }
 }
 
 #[derive(Clone)]
 pub struct IgThen<A, B> {
-    a: A,
-    b: B,
+    pub(crate) a: A,
+    pub(crate) b: B,
 }
 
-impl<A, B> Parser for IgThen<A, B>
+impl<I, A, B> RefParser<I> for IgThen<A, B>
 where
-    A: Parser,
-    B: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+{
+    type Out<'a>
+        = B::Out<'a>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, B::Out<'a>, I> {
+        let (i, _, c1) = self.a.parse_ref(i)?;
+        let (i, v2, ex) = self.b.parse_ref(&i).map_err(|e| e.join_op(c1))?;
+        Ok((i, v2, ex))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.a.expected_ref(), self.b.expected_ref())
+    }
+}
+
+impl<I, A, B> Parser<I> for IgThen<A, B>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
 {
     type Out = B::Out;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (i, _, c1) = self.a.parse(i)?;
         let (i, v2, ex) = self.b.parse(&i).map_err(|e| e.join_op(c1))?;
         Ok((i, v2, ex))
@@ -182,21 +537,53 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.a.expected(), self.b.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe(), self.b.describe())
+    }
 }
 
 #[derive(Clone)]
 pub struct Or<A, B> {
-    a: A,
-    b: B,
+    pub(crate) a: A,
+    pub(crate) b: B,
 }
 
-impl<A, B, V> Parser for Or<A, B>
+//The outputs are matched for every lifetime, which rust can only check for inputs
+//that live for all of them
+impl<I, A, B> RefParser<I> for Or<A, B>
 where
-    A: Parser<Out = V>,
-    B: Parser<Out = V>,
+    I: ?Sized + Input + 'static,
+    A: RefParser<I>,
+    B: for<'x> RefParser<I, Out<'x> = A::Out<'x>>,
+{
+    type Out<'a>
+        = A::Out<'a>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, A::Out<'a>, I> {
+        match self.a.parse_ref(i) {
+            Ok((r, v, e)) => Ok((r, v, e)),
+            Err(e) if e.is_brk => Err(e),
+            Err(e) => match self.b.parse_ref(i) {
+                Ok((r, v, ex)) => Ok((r, v, ex)),
+                Err(e2) if e2.is_brk => Err(e2),
+                Err(e2) => Err(e.longer(e2)),
+            },
+        }
+    }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref().or(self.b.expected_ref())
+    }
+}
+
+impl<I, A, B, V> Parser<I> for Or<A, B>
+where
+    I: ?Sized + Input,
+    A: Parser<I, Out = V>,
+    B: Parser<I, Out = V>,
 {
     type Out = V;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, V> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, V, I> {
         match self.a.parse(i) {
             Ok((r, v, e)) => Ok((r, v, e)),
             Err(e) if e.is_brk => Err(e),
@@ -210,34 +597,51 @@ where
     fn expected(&self) -> Expected {
         self.a.expected().or(self.b.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::alt(self.a.describe(), self.b.describe())
+    }
 }
 
 #[derive(Clone)]
-pub struct Map<A: Parser, B, F: Fn(A::Out) -> B> {
+pub struct Map<A, B, F> {
     a: A,
     f: F,
+    b: PhantomData<fn() -> B>,
 }
 
-impl<A: Parser<Out = AV>, AV, B, F: Fn(A::Out) -> B> Parser for Map<A, B, F> {
+impl<A, B, F> Owned for Map<A, B, F> {}
+
+impl<I: ?Sized + Input, A: Parser<I>, B, F: Fn(A::Out) -> B> Parser<I> for Map<A, B, F> {
     type Out = B;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, B> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, B, I> {
         let (ri, v, ex) = self.a.parse(i)?;
         Ok((ri, (self.f)(v), ex))
     }
     fn expected(&self) -> Expected {
         self.a.expected()
     }
+    fn describe(&self) -> Gram {
+        self.a.describe()
+    }
 }
 
 #[derive(Clone)]
-pub struct TryMap<A: Parser, B, F: Fn(A::Out) -> Result<B, Expected>> {
+pub struct TryMap<A, B, F> {
     a: A,
     f: F,
+    b: PhantomData<fn() -> B>,
 }
 
-impl<A: Parser, B, F: Fn(A::Out) -> Result<B, Expected>> Parser for TryMap<A, B, F> {
+impl<A, B, F> Owned for TryMap<A, B, F> {}
+
+impl<I, A, B, F> Parser<I> for TryMap<A, B, F>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    F: Fn(A::Out) -> Result<B, Expected>,
+{
     type Out = B;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, B> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, B, I> {
         let (ri, v, ct) = self.a.parse(i)?;
         match (self.f)(v) {
             Ok(v2) => Ok((ri, v2, ct)),
@@ -247,31 +651,41 @@ impl<A: Parser, B, F: Fn(A::Out) -> Result<B, Expected>> Parser for TryMap<A, B,
     fn expected(&self) -> Expected {
         self.a.expected()
     }
+    fn describe(&self) -> Gram {
+        self.a.describe()
+    }
 }
 
-pub struct As<A: Parser, R: Clone> {
+pub struct As<A, R> {
     a: A,
     r: R,
 }
-impl<A: Parser, R: Clone> Parser for As<A, R> {
+impl<A, R> Owned for As<A, R> {}
+
+impl<I: ?Sized + Input, A: Parser<I>, R: Clone> Parser<I> for As<A, R> {
     type Out = R;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, R> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, R, I> {
         let (ri, _, ct) = self.a.parse(it)?;
         Ok((ri, self.r.clone(), ct))
     }
     fn expected(&self) -> Expected {
         self.a.expected()
     }
+    fn describe(&self) -> Gram {
+        self.a.describe()
+    }
 }
 
-pub struct MapExp<P: Parser, F: Fn(Expected) -> Expected> {
+pub struct MapExp<P, F> {
     p: P,
     f: F,
 }
 
-impl<P: Parser, F: Fn(Expected) -> Expected> Parser for MapExp<P, F> {
+impl<P, F> Owned for MapExp<P, F> {}
+
+impl<I: ?Sized + Input, P: Parser<I>, F: Fn(Expected) -> Expected> Parser<I> for MapExp<P, F> {
     type Out = P::Out;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, P::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
         match self.p.parse(it) {
             Err(mut e) => {
                 e.exp = (self.f)(e.exp);
@@ -280,27 +694,117 @@ impl<P: Parser, F: Fn(Expected) -> Expected> Parser for MapExp<P, F> {
             Ok(ov) => Ok(ov),
         }
     }
+    fn expected(&self) -> Expected {
+        (self.f)(self.p.expected())
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+pub struct PContext<P> {
+    p: P,
+    name: &'static str,
+}
+
+impl<P> Owned for PContext<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for PContext<P> {
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        self.p.parse(it).map_err(|e| e.in_context(self.name))
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+pub struct PNamed<P> {
+    p: P,
+    name: &'static str,
+}
+
+impl<P> Owned for PNamed<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for PNamed<P> {
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        match self.p.parse(it) {
+            Err(e) if !e.is_brk && e.index == it.index() => it.err_rp(self),
+            r => r,
+        }
+    }
+    fn expected(&self) -> Expected {
+        Expected::Named(self.name)
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
 }
-pub struct Break<P: Parser> {
+pub struct Break<P> {
     p: P,
 }
 
-impl<P: Parser> Parser for Break<P> {
+impl<P> Owned for Break<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for Break<P> {
     type Out = P::Out;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         match self.p.parse(it) {
             Err(e) => Err(e.brk()),
             ov => ov,
         }
     }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
 }
 #[cfg(test)]
-pub mod test {
+mod test {
     use super::*;
+    use crate::chars::{Any, CharBool};
+    use crate::reader::{chars_until, tag};
     #[test]
     fn test_strs_can_be_parsers() {
         let p = "(((".ig_then(crate::common::Int);
         let n = p.parse_s("(((32").unwrap();
         assert_eq!(n, 32);
     }
+
+    #[test]
+    fn test_char_error_is_at_the_char() {
+        let e = ('a', 'b').parse_s("ac").unwrap_err();
+        assert_eq!(e.index, Some(1));
+        assert_eq!(e.found, "c");
+    }
+
+    fn heredoc<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
+        "<<".ig_then(crate::common::Ident)
+            .then_ig("\n")
+            .and_then(|t| chars_until(Any.one(), tag(format!("\n{}\n", t))))
+            .map(|(s, _)| s)
+            .parse(it)
+    }
+
+    #[test]
+    fn test_and_then_heredocs() {
+        let src = "<<END\nhello\nEND is not the end\nEND\n<<X\nEND\nX\n";
+        let (it, a, _) = heredoc(&LCChars::str(src)).unwrap();
+        let (_, b, _) = heredoc(&it).unwrap();
+        assert_eq!(a, "hello\nEND is not the end");
+        assert_eq!(b, "END");
+
+        let e = heredoc.parse_s("<<END\nhello\nEN").unwrap_err();
+        assert_eq!(e.index, None);
+
+        let e = tag("\nEND\n").parse_s("END").unwrap_err();
+        assert_eq!(e.exp, Expected::Text("\nEND\n".to_string()));
+        assert_eq!(e.exp.sentence(), "`\\nEND\\n`");
+    }
 }
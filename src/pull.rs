@@ -1,34 +1,35 @@
 use crate::err::*;
+use crate::input::Input;
 use crate::iter::LCChars;
 use crate::ptrait::*;
 use crate::reader::EOI;
 
-pub struct PullParser<'a, P: Parser, E: Parser> {
+pub struct PullParser<'a, P: Parser<I>, E: Parser<I>, I: ?Sized + Input = str> {
     p: P,
-    pub s: &'a str,
-    it: LCChars<'a>,
+    pub s: &'a I,
+    it: LCChars<'a, I>,
     end: E,
     errored: bool,
 }
 
-impl<'a, P: Parser> PullParser<'a, P, EOI> {
-    pub fn new(p: P, s: &'a str) -> Self {
+impl<'a, I: ?Sized + Input, P: Parser<I>> PullParser<'a, P, EOI, I> {
+    pub fn new(p: P, s: &'a I) -> Self {
         PullParser::with_end(p, EOI, s)
     }
 }
-impl<'a, P: Parser, E: Parser> PullParser<'a, P, E> {
-    pub fn with_end(p: P, end: E, s: &'a str) -> Self {
+impl<'a, I: ?Sized + Input, P: Parser<I>, E: Parser<I>> PullParser<'a, P, E, I> {
+    pub fn with_end(p: P, end: E, s: &'a I) -> Self {
         PullParser {
             p,
             end,
             s,
-            it: LCChars::str(s),
+            it: LCChars::new(s),
             errored: false,
         }
     }
 }
 
-impl<'a, P: Parser, E: Parser> Iterator for PullParser<'a, P, E> {
+impl<'a, I: ?Sized + Input, P: Parser<I>, E: Parser<I>> Iterator for PullParser<'a, P, E, I> {
     type Item = Result<P::Out, PErr<'a>>;
     fn next(&mut self) -> Option<Self::Item> {
         if self.errored {
@@ -1,8 +1,12 @@
+use crate::borrow::Owned;
 use crate::chars::*;
 use crate::combi::*;
+use crate::describe::Gram;
 use crate::err::*;
+use crate::input::Input;
 use crate::iter::LCChars;
-use crate::ptrait::{As, ParseRes, Parser};
+use crate::ptrait::{As, IgThen, ParseRes, Parser};
+use crate::skip::CharSkip;
 //use crate::skip::skip_while;
 
 pub type StrPos = Pos<()>;
@@ -26,13 +30,15 @@ impl<O> Pos<O> {
     }
 }
 
-pub struct PPos<P: Parser> {
+pub struct PPos<P> {
     p: P,
 }
 
-impl<P: Parser> Parser for PPos<P> {
+impl<P> Owned for PPos<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for PPos<P> {
     type Out = Pos<P::Out>;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (line, col) = it.lc();
         let start = it.index().ok_or(it.err_p(&self.p))?;
         let (rit, r, pex) = self.p.parse(it)?;
@@ -49,6 +55,12 @@ impl<P: Parser> Parser for PPos<P> {
             pex,
         ))
     }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
 }
 
 /// ```rust
@@ -58,7 +70,7 @@ impl<P: Parser> Parser for PPos<P> {
 /// assert_eq!(v,Pos{line:1,col:2,start:4,fin:Some(9),ob:()});
 /// assert_eq!(v.on_str(&s),"hello");
 /// ```
-pub fn pos_ig<P: Parser>(p: P) -> PPos<As<P, ()>> {
+pub fn pos_ig<P: Parser<I>, I: ?Sized + Input>(p: P) -> PPos<As<P, ()>> {
     PPos { p: p.ig() }
 }
 
@@ -67,16 +79,16 @@ pub fn str_pos<P: Parser>(p: P) -> PPos<As<P, ()>> {
     PPos { p: p.ig() }
 }
 
-pub fn pos<P: Parser>(p: P) -> PPos<P> {
+pub fn pos<P>(p: P) -> PPos<P> {
     PPos { p }
 }
 
-pub fn ws__<P: Parser>(p: P) -> impl Parser<Out = P::Out> {
+pub fn ws__<P>(p: P) -> Wrap<CharSkip<WS>, P> {
     wrap(WS.istar(), p)
 }
 
-pub fn ws_<P: Parser>(p: P) -> impl Parser<Out = P::Out> {
-    WS.istar().ig_then(p)
+pub fn ws_<P>(p: P) -> IgThen<CharSkip<WS>, P> {
+    crate::tuple::last(WS.istar(), p)
 }
 
 ///Convenience wrapper to say allow whitespace around whatever I'm parsing.
@@ -84,7 +96,7 @@ pub fn ws_<P: Parser>(p: P) -> impl Parser<Out = P::Out> {
     since = "0.5.0",
     note = "use ws__ instead, to match convention __ means wrap around"
 )]
-pub fn s_<P: Parser>(p: P) -> impl Parser<Out = P::Out> {
+pub fn s_<P>(p: P) -> Wrap<CharSkip<WS>, P> {
     crate::combi::wrap(WS.istar(), p)
 }
 
@@ -109,11 +121,19 @@ pub fn do_keyword<'a, P: Parser>(it: &LCChars<'a>, p: &P) -> ParseRes<'a, P::Out
     }
 }
 
+impl<P: Parser> Owned for KeyWord<P> {}
+
 impl<P: Parser> Parser for KeyWord<P> {
     type Out = P::Out;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, P::Out> {
         do_keyword(it, &self.p)
     }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
 }
 
 pub struct KeyWord<P: Parser> {
@@ -133,8 +153,7 @@ pub fn keyword<P: Parser>(p: P) -> KeyWord<P> {
 
 pub fn do_tag<'a>(it: &LCChars<'a>, tg: &'static str) -> ParseRes<'a, &'static str> {
     let mut i = it.clone();
-    let mut s_it = tg.chars();
-    while let Some(c) = s_it.next() {
+    for c in tg.chars() {
         let i2 = it.clone();
         match i.next() {
             None => return i2.err_rs(tg),
@@ -148,13 +167,54 @@ pub fn do_tag<'a>(it: &LCChars<'a>, tg: &'static str) -> ParseRes<'a, &'static s
     Ok((i, tg, None))
 }
 
-parser! {(EOI->())
-    eoi
+/// Matches text only known while parsing, such as a terminator, see ```Parser::and_then```.
+/// For text known up front, use a ```&'static str```
+pub fn tag<S: Into<String>>(s: S) -> Tag {
+    Tag { s: s.into() }
+}
+
+#[derive(Clone)]
+pub struct Tag {
+    s: String,
+}
+
+impl Owned for Tag {}
+
+impl Parser for Tag {
+    type Out = String;
+    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, String> {
+        let mut i2 = it.clone();
+        for c in self.s.chars() {
+            if i2.next() != Some(c) {
+                return it.err_rp(self);
+            }
+        }
+        Ok((i2, self.s.clone(), None))
+    }
+    fn expected(&self) -> Expected {
+        Expected::Text(self.s.clone())
+    }
+}
+
+/// Matches the end of the input
+#[derive(Copy, Clone)]
+pub struct EOI;
+
+impl Owned for EOI {}
+
+impl<I: ?Sized + Input> Parser<I> for EOI {
+    type Out = ();
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, (), I> {
+        eoi(it)
+    }
+    fn expected(&self) -> Expected {
+        Expected::EOI
+    }
 }
 
-pub fn eoi<'a>(i: &LCChars<'a>) -> ParseRes<'a, ()> {
+pub fn eoi<'a, I: ?Sized + Input>(i: &LCChars<'a, I>) -> ParseRes<'a, (), I> {
     let mut r = i.clone();
-    if r.next() == None {
+    if r.next().is_none() {
         return Ok((r, (), None));
     }
     i.err_r(Expected::EOI)
@@ -164,13 +224,15 @@ pub fn to_end() -> impl Parser<Out = ()> {
     WS.star().ig_then(eoi)
 }
 
-pub struct Peek<P: Parser> {
+pub struct Peek<P> {
     p: P,
 }
 
-impl<P: Parser> Parser for Peek<P> {
+impl<P> Owned for Peek<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for Peek<P> {
     type Out = P::Out;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, P::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
         let (_, v, c) = self.p.parse(it)?;
         Ok((it.clone(), v, c))
     }
@@ -178,9 +240,12 @@ impl<P: Parser> Parser for Peek<P> {
     fn expected(&self) -> Expected {
         self.p.expected()
     }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
 }
 
-pub fn peek<P: Parser>(p: P) -> Peek<P> {
+pub fn peek<P>(p: P) -> Peek<P> {
     Peek { p }
 }
 
@@ -189,6 +254,8 @@ pub struct CharsUntil<A: Parser<Out = char>, B: Parser> {
     b: B,
 }
 
+impl<A: Parser<Out = char>, B: Parser> Owned for CharsUntil<A, B> {}
+
 impl<A: Parser<Out = char>, B: Parser> Parser for CharsUntil<A, B> {
     type Out = (String, B::Out);
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
@@ -208,6 +275,12 @@ impl<A: Parser<Out = char>, B: Parser> Parser for CharsUntil<A, B> {
             };
         }
     }
+    fn expected(&self) -> Expected {
+        self.b.expected().or(self.a.expected())
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe().star(), self.b.describe())
+    }
 }
 
 pub fn chars_until<A: Parser<Out = char>, B: Parser>(a: A, b: B) -> CharsUntil<A, B> {
@@ -219,6 +292,8 @@ pub struct StringRepeat<A: Parser<Out = AV>, AV: Into<String> + AsRef<str>> {
     min: usize,
 }
 
+impl<A: Parser<Out = AV>, AV: Into<String> + AsRef<str>> Owned for StringRepeat<A, AV> {}
+
 impl<A: Parser<Out = AV>, AV: Into<String> + AsRef<str>> Parser for StringRepeat<A, AV> {
     type Out = String;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, String> {
@@ -251,6 +326,15 @@ impl<A: Parser<Out = AV>, AV: Into<String> + AsRef<str>> Parser for StringRepeat
             done += 1;
         }
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        match self.min {
+            0 => self.a.describe().star(),
+            n => Gram::seq(self.a.describe().times(n - 1), self.a.describe().plus()),
+        }
+    }
 }
 
 pub fn string_repeat<A: Parser<Out = AV>, AV: Into<String> + AsRef<str>>(
@@ -1,8 +1,13 @@
+use crate::borrow::{ByRef, Owned, RefParser};
+use crate::describe::Gram;
+use crate::err::Expected;
+use crate::input::Input;
 use crate::iter::LCChars;
 use crate::ptrait::*;
+use std::convert::TryInto;
 
 #[derive(Clone)]
-pub struct Exact<A: Parser> {
+pub struct Exact<A> {
     n: usize,
     a: A,
 }
@@ -14,26 +19,57 @@ pub struct Exact<A: Parser> {
 /// assert_eq!(v,vec!["hello","fish","car"]);
 ///
 /// ```
-pub fn do_exact<'a, A: Parser>(it: &LCChars<'a>, a: &A, n: usize) -> ParseRes<'a, Vec<A::Out>> {
+pub fn do_exact<'a, I: ?Sized + Input, A: Parser<I>>(
+    it: &LCChars<'a, I>,
+    a: &A,
+    n: usize,
+) -> ParseRes<'a, Vec<A::Out>, I> {
+    exact_ref(it, &ByRef(a), n)
+}
+
+fn exact_ref<'a, I: ?Sized + Input, A: RefParser<I>>(
+    it: &LCChars<'a, I>,
+    a: &A,
+    n: usize,
+) -> ParseRes<'a, Vec<A::Out<'a>>, I> {
     let mut i = it.clone();
     let mut res = Vec::new();
     for _ in 0..n {
-        match a.parse(&i) {
+        match a.parse_ref(&i) {
             Ok((it2, pres, _)) => {
                 res.push(pres);
                 i = it2;
             }
-            Err(e) => return Err(e.join(i.err_p(a))),
+            Err(e) => return Err(e.join(i.err(a.expected_ref()))),
         }
     }
-    return Ok((i, res, None));
+    Ok((i, res, None))
+}
+
+impl<I: ?Sized + Input, A: RefParser<I>> RefParser<I> for Exact<A> {
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        exact_ref(it, &self.a, self.n)
+    }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref()
+    }
 }
 
-impl<A: Parser> Parser for Exact<A> {
+impl<I: ?Sized + Input, A: Parser<I>> Parser<I> for Exact<A> {
     type Out = Vec<A::Out>;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Vec<A::Out>> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Vec<A::Out>, I> {
         do_exact(it, &self.a, self.n)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.a.describe().times(self.n)
+    }
 }
 
 pub struct Reflect<A, B, C> {
@@ -41,18 +77,31 @@ pub struct Reflect<A, B, C> {
     b: B,
     c: C,
 }
-impl<A, B, C> Parser for Reflect<A, B, C>
+impl<A, B, C> Owned for Reflect<A, B, C> {}
+
+impl<I, A, B, C> Parser<I> for Reflect<A, B, C>
 where
-    A: Parser,
-    B: Parser,
-    C: Parser,
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+    C: Parser<I>,
 {
     type Out = (Vec<A::Out>, B::Out, Vec<C::Out>);
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
-        let (ni, (va, b), _) = do_repeat_until(it, 1, &self.a, &self.b)?;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        let (ni, (va, b), _) = do_repeat_until(it, 1, &ByRef(&self.a), &ByRef(&self.b))?;
         let (fi, vc, _) = do_exact(&ni, &self.c, va.len())?;
         Ok((fi, (va, b, vc), None))
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::Seq(vec![
+            self.a.describe().plus(),
+            self.b.describe(),
+            self.c.describe().plus(),
+        ])
+    }
 }
 
 /// A function for making sure number match on both sides of an equals
@@ -71,17 +120,12 @@ where
 /// assert!(r2.is_err());
 /// ```
 ///
-pub fn reflect<A, B, C>(a: A, b: B, c: C) -> Reflect<A, B, C>
-where
-    A: Parser,
-    B: Parser,
-    C: Parser,
-{
+pub fn reflect<A, B, C>(a: A, b: B, c: C) -> Reflect<A, B, C> {
     Reflect { a, b, c }
 }
 
 #[deprecated(since = "0.4.0", note = "Use 'exact' instead")]
-pub fn repeat_n<A: Parser>(a: A, n: usize) -> Exact<A> {
+pub fn repeat_n<A>(a: A, n: usize) -> Exact<A> {
     Exact { a, n }
 }
 
@@ -93,38 +137,78 @@ pub fn repeat_n<A: Parser>(a: A, n: usize) -> Exact<A> {
 /// let v = p.parse_s("7,6,5,4,3,2,1").unwrap();
 /// assert_eq!(v,vec![7,6,5,4,3]);
 /// ```
-pub fn exact<A: Parser>(a: A, n: usize) -> Exact<A> {
+pub fn exact<A>(a: A, n: usize) -> Exact<A> {
     Exact { a, n }
 }
 
-fn do_sep<'a, A: Parser, B: Parser>(
-    i: &LCChars<'a>,
+pub struct Counted<L, A> {
+    len: L,
+    a: A,
+}
+
+impl<L, A> Owned for Counted<L, A> {}
+
+impl<I, L, A> Parser<I> for Counted<L, A>
+where
+    I: ?Sized + Input,
+    L: Parser<I>,
+    L::Out: TryInto<usize>,
+    A: Parser<I>,
+{
+    type Out = Vec<A::Out>;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Vec<A::Out>, I> {
+        let (ni, n, c1) = self.len.parse(it)?;
+        let n = n.try_into().map_err(|_| it.err_p(&self.len))?;
+        do_exact(&ni, &self.a, n).map_err(|e| e.join_op(c1))
+    }
+    fn expected(&self) -> Expected {
+        self.len.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.len.describe(), self.a.describe().star())
+    }
+}
+
+/// Parses a count with 'len', then exactly that many of 'a', for length prefixed lists
+///
+/// ```rust
+/// use gobble::*;
+/// let p = counted(common::UInt.then_ig(":"), first(common::Int, maybe(",")));
+/// assert_eq!(p.parse_s("3:4,5,6,7"), Ok(vec![4, 5, 6]));
+/// assert!(p.parse_s("3:4,5").is_err());
+/// ```
+pub fn counted<L, A>(len: L, a: A) -> Counted<L, A> {
+    Counted { len, a }
+}
+
+fn do_sep<'a, I: ?Sized + Input, A: RefParser<I>, B: RefParser<I>>(
+    i: &LCChars<'a, I>,
     a: &A,
     b: &B,
     min: usize,
-) -> ParseRes<'a, Vec<A::Out>> {
+) -> ParseRes<'a, Vec<A::Out<'a>>, I> {
     let mut res = Vec::new();
     let mut ri = i.clone();
     loop {
-        ri = match a.parse(&ri) {
+        ri = match a.parse_ref(&ri) {
             Ok((r, v, _)) => {
                 res.push(v);
                 r
             }
             Err(_) => {
-                if res.len() == 0 && min == 0 {
-                    let eo = ri.err_op(a);
+                if res.is_empty() && min == 0 {
+                    let eo = Some(ri.err(a.expected_ref()));
                     return Ok((ri, res, eo));
                 }
-                return i.err_rp(a);
+                return i.err_r(a.expected_ref());
             }
         };
         //try sep if not found, return
-        ri = match b.parse(&ri) {
+        ri = match b.parse_ref(&ri) {
             Ok((r, _, _)) => r,
             Err(e) => {
                 if res.len() < min {
-                    return ri.err_rp(b);
+                    return ri.err_r(b.expected_ref());
                 } else {
                     return Ok((ri, res, Some(e)));
                 }
@@ -134,69 +218,132 @@ fn do_sep<'a, A: Parser, B: Parser>(
 }
 
 #[derive(Clone)]
-pub struct SepStar<A: Parser, B: Parser> {
+pub struct SepStar<A, B> {
     a: A,
     b: B,
 }
 
-impl<A, B> Parser for SepStar<A, B>
+impl<I, A, B> RefParser<I> for SepStar<A, B>
 where
-    A: Parser,
-    B: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
 {
-    type Out = Vec<A::Out>;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
         do_sep(it, &self.a, &self.b, 0)
     }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref()
+    }
+}
+
+impl<I, A, B> Parser<I> for SepStar<A, B>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+{
+    type Out = Vec<A::Out>;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        do_sep(it, &ByRef(&self.a), &ByRef(&self.b), 0)
+    }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::sep_plus(self.a.describe(), self.b.describe()).opt()
+    }
 }
 
 #[deprecated(since = "0.5.0", note = "use sep_star instead")]
-pub fn sep<A: Parser, B: Parser>(a: A, b: B) -> SepStar<A, B> {
+pub fn sep<A, B>(a: A, b: B) -> SepStar<A, B> {
     SepStar { a, b }
 }
 
-pub fn sep_star<A: Parser, B: Parser>(a: A, b: B) -> SepStar<A, B> {
+pub fn sep_star<A, B>(a: A, b: B) -> SepStar<A, B> {
     SepStar { a, b }
 }
-pub fn sep_plus<A: Parser, B: Parser>(a: A, b: B) -> SepPlus<A, B> {
+pub fn sep_plus<A, B>(a: A, b: B) -> SepPlus<A, B> {
     SepPlus { a, b }
 }
 
 #[derive(Clone)]
-pub struct SepPlus<A: Parser, B: Parser> {
+pub struct SepPlus<A, B> {
     a: A,
     b: B,
 }
 
-impl<A, B> Parser for SepPlus<A, B>
+impl<I, A, B> RefParser<I> for SepPlus<A, B>
 where
-    A: Parser,
-    B: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
 {
-    type Out = Vec<A::Out>;
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
         do_sep(it, &self.a, &self.b, 1)
     }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref()
+    }
+}
+
+impl<I, A, B> Parser<I> for SepPlus<A, B>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+{
+    type Out = Vec<A::Out>;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        do_sep(it, &ByRef(&self.a), &ByRef(&self.b), 1)
+    }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::sep_plus(self.a.describe(), self.b.describe())
+    }
+}
+
+pub fn do_rep<'a, I: ?Sized + Input, A: Parser<I>>(
+    i: &LCChars<'a, I>,
+    a: &A,
+    min: usize,
+) -> ParseRes<'a, Vec<A::Out>, I> {
+    rep_ref(i, &ByRef(a), min)
 }
 
-pub fn do_rep<'a, A: Parser>(i: &LCChars<'a>, a: &A, min: usize) -> ParseRes<'a, Vec<A::Out>> {
+fn rep_ref<'a, I: ?Sized + Input, A: RefParser<I>>(
+    i: &LCChars<'a, I>,
+    a: &A,
+    min: usize,
+) -> ParseRes<'a, Vec<A::Out<'a>>, I> {
     let mut ri = i.clone();
     let mut res = Vec::new();
     //This closure exists to to make sure th
-    let f_done = |it: LCChars<'a>, fres: Vec<A::Out>| {
+    let f_done = |it: LCChars<'a, I>, fres: Vec<A::Out<'a>>| {
         if fres.len() < min {
-            it.err_rp(a)
+            it.err_r(a.expected_ref())
         } else {
-            let eo = it.err_op(a);
+            let eo = Some(it.err(a.expected_ref()));
             Ok((it, fres, eo))
         }
     };
 
     loop {
-        ri = match a.parse(&ri) {
+        ri = match a.parse_ref(&ri) {
             Ok((r, v, _)) => {
-                if ri.lc() == r.lc() {
-                    return f_done(ri, res);
+                //stop, but keep anything the parser recorded (such as recovered errors)
+                if ri.loc().index == r.loc().index {
+                    return f_done(r, res);
                 }
                 res.push(v);
                 r
@@ -213,19 +360,38 @@ pub struct RepStar<A> {
     a: A,
 }
 
-impl<A: Parser> Parser for RepStar<A> {
+impl<I: ?Sized + Input, A: RefParser<I>> RefParser<I> for RepStar<A> {
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        rep_ref(i, &self.a, 0)
+    }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref()
+    }
+}
+
+impl<I: ?Sized + Input, A: Parser<I>> Parser<I> for RepStar<A> {
     type Out = Vec<A::Out>;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         do_rep(i, &self.a, 0)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.a.describe().star()
+    }
 }
 
 #[deprecated(since = "0.5.0", note = "use star instead")]
-pub fn rep<A: Parser>(a: A) -> RepStar<A> {
+pub fn rep<A>(a: A) -> RepStar<A> {
     RepStar { a }
 }
 
-pub fn star<A: Parser>(a: A) -> RepStar<A> {
+pub fn star<A>(a: A) -> RepStar<A> {
     RepStar { a }
 }
 
@@ -234,43 +400,62 @@ pub struct RepPlus<A> {
     a: A,
 }
 
-impl<A: Parser> Parser for RepPlus<A> {
+impl<I: ?Sized + Input, A: RefParser<I>> RefParser<I> for RepPlus<A> {
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        rep_ref(i, &self.a, 1)
+    }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref()
+    }
+}
+
+impl<I: ?Sized + Input, A: Parser<I>> Parser<I> for RepPlus<A> {
     type Out = Vec<A::Out>;
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         do_rep(i, &self.a, 1)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.a.describe().plus()
+    }
 }
 
 #[deprecated(since = "0.5.0", note = "use plus instead")]
-pub fn rep_plus<A: Parser>(a: A) -> RepPlus<A> {
+pub fn rep_plus<A>(a: A) -> RepPlus<A> {
     RepPlus { a }
 }
 
-pub fn plus<A: Parser>(a: A) -> RepPlus<A> {
+pub fn plus<A>(a: A) -> RepPlus<A> {
     RepPlus { a }
 }
 
-fn do_repeat_until<'a, A: Parser, B: Parser>(
-    it: &LCChars<'a>,
+fn do_repeat_until<'a, I: ?Sized + Input, A: RefParser<I>, B: RefParser<I>>(
+    it: &LCChars<'a, I>,
     min: i32,
     a: &A,
     b: &B,
-) -> ParseRes<'a, (Vec<A::Out>, B::Out)> {
+) -> ParseRes<'a, (Vec<A::Out<'a>>, B::Out<'a>), I> {
     let mut ri = it.clone();
     let mut res = Vec::new();
     let mut done = 0;
     loop {
         let b_err = match done >= min {
-            true => match b.parse(&ri) {
+            true => match b.parse_ref(&ri) {
                 Ok((r, v, _)) => return Ok((r, (res, v), None)),
                 Err(e) => Some(e),
             },
             false => None,
         };
-        ri = match a.parse(&ri) {
+        ri = match a.parse_ref(&ri) {
             Ok((r, v, _)) => {
-                if r.lc() == ri.lc() {
-                    return ri.err_rp(a);
+                if r.loc().index == ri.loc().index {
+                    return ri.err_r(a.expected_ref());
                 }
                 res.push(v);
                 r
@@ -291,11 +476,30 @@ pub struct StarUntil<A, B> {
     b: B,
 }
 
-impl<A: Parser, B: Parser> Parser for StarUntil<A, B> {
-    type Out = (Vec<A::Out>, B::Out);
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+impl<I: ?Sized + Input, A: RefParser<I>, B: RefParser<I>> RefParser<I> for StarUntil<A, B> {
+    type Out<'a>
+        = (Vec<A::Out<'a>>, B::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
         do_repeat_until(i, 0, &self.a, &self.b)
     }
+    fn expected_ref(&self) -> Expected {
+        self.b.expected_ref().or(self.a.expected_ref())
+    }
+}
+
+impl<I: ?Sized + Input, A: Parser<I>, B: Parser<I>> Parser<I> for StarUntil<A, B> {
+    type Out = (Vec<A::Out>, B::Out);
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        do_repeat_until(i, 0, &ByRef(&self.a), &ByRef(&self.b))
+    }
+    fn expected(&self) -> Expected {
+        self.b.expected().or(self.a.expected())
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe().star(), self.b.describe())
+    }
 }
 
 pub struct PlusUntil<A, B> {
@@ -303,37 +507,135 @@ pub struct PlusUntil<A, B> {
     b: B,
 }
 
-impl<A: Parser, B: Parser> Parser for PlusUntil<A, B> {
-    type Out = (Vec<A::Out>, B::Out);
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+impl<I: ?Sized + Input, A: RefParser<I>, B: RefParser<I>> RefParser<I> for PlusUntil<A, B> {
+    type Out<'a>
+        = (Vec<A::Out<'a>>, B::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
         do_repeat_until(i, 1, &self.a, &self.b)
     }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref()
+    }
+}
+
+impl<I: ?Sized + Input, A: Parser<I>, B: Parser<I>> Parser<I> for PlusUntil<A, B> {
+    type Out = (Vec<A::Out>, B::Out);
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        do_repeat_until(i, 1, &ByRef(&self.a), &ByRef(&self.b))
+    }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe().plus(), self.b.describe())
+    }
 }
 
 ///Repeats the first parser until the second parser.
 ///returns a vec of the first parsers results
 #[deprecated(since = "0.5.2", note = "use star_until instead")]
-pub fn repeat_until<A: Parser, B: Parser>(a: A, b: B) -> StarUntil<A, B> {
+pub fn repeat_until<A, B>(a: A, b: B) -> StarUntil<A, B> {
     StarUntil { a, b }
 }
 
-pub fn star_until<A: Parser, B: Parser>(a: A, b: B) -> StarUntil<A, B> {
+pub fn star_until<A, B>(a: A, b: B) -> StarUntil<A, B> {
     StarUntil { a, b }
 }
-pub fn plus_until<A: Parser, B: Parser>(a: A, b: B) -> PlusUntil<A, B> {
+pub fn plus_until<A, B>(a: A, b: B) -> PlusUntil<A, B> {
     PlusUntil { a, b }
 }
 
 #[deprecated(since = "0.5.2", note = "use star_until_ig or plus_until_ig instead")]
-pub fn repeat_until_ig<A: Parser, B: Parser>(a: A, b: B) -> impl Parser<Out = Vec<A::Out>> {
-    star_until(a, b).map(|(a, _)| a)
+pub fn repeat_until_ig<A, B>(a: A, b: B) -> UntilIg<StarUntil<A, B>> {
+    star_until_ig(a, b)
 }
 
-pub fn star_until_ig<A: Parser, B: Parser>(a: A, b: B) -> impl Parser<Out = Vec<A::Out>> {
-    star_until(a, b).map(|(a, _)| a)
+pub fn star_until_ig<A, B>(a: A, b: B) -> UntilIg<StarUntil<A, B>> {
+    UntilIg {
+        p: star_until(a, b),
+    }
 }
-pub fn plus_until_ig<A: Parser, B: Parser>(a: A, b: B) -> impl Parser<Out = Vec<A::Out>> {
-    plus_until(a, b).map(|(a, _)| a)
+pub fn plus_until_ig<A, B>(a: A, b: B) -> UntilIg<PlusUntil<A, B>> {
+    UntilIg {
+        p: plus_until(a, b),
+    }
+}
+
+/// An until parser that drops the result of the end parser, see ```star_until_ig```
+pub struct UntilIg<P> {
+    p: P,
+}
+
+impl<I, P, V, E> Parser<I> for UntilIg<P>
+where
+    I: ?Sized + Input,
+    P: Parser<I, Out = (V, E)>,
+{
+    type Out = V;
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, V, I> {
+        let (ri, (v, _), ct) = self.p.parse(i)?;
+        Ok((ri, v, ct))
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+impl<I: ?Sized + Input, A: RefParser<I>, B: RefParser<I>> RefParser<I>
+    for UntilIg<StarUntil<A, B>>
+{
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (ri, (v, _), ct) = self.p.parse_ref(i)?;
+        Ok((ri, v, ct))
+    }
+    fn expected_ref(&self) -> Expected {
+        self.p.expected_ref()
+    }
+}
+
+impl<I: ?Sized + Input, A: RefParser<I>, B: RefParser<I>> RefParser<I>
+    for UntilIg<PlusUntil<A, B>>
+{
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (ri, (v, _), ct) = self.p.parse_ref(i)?;
+        Ok((ri, v, ct))
+    }
+    fn expected_ref(&self) -> Expected {
+        self.p.expected_ref()
+    }
+}
+
+impl<I, A, B, C> RefParser<I> for UntilIg<SepUntil<A, B, C>>
+where
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+    C: RefParser<I>,
+{
+    type Out<'a>
+        = Vec<A::Out<'a>>
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (ri, (v, _), ct) = self.p.parse_ref(i)?;
+        Ok((ri, v, ct))
+    }
+    fn expected_ref(&self) -> Expected {
+        self.p.expected_ref()
+    }
 }
 
 pub struct SepUntil<A, B, C> {
@@ -342,66 +644,101 @@ pub struct SepUntil<A, B, C> {
     c: C,
 }
 
-impl<A, B, C> Parser for SepUntil<A, B, C>
+fn do_sep_until<'a, I, A, B, C>(
+    i: &LCChars<'a, I>,
+    a: &A,
+    b: &B,
+    c: &C,
+) -> ParseRes<'a, (Vec<A::Out<'a>>, C::Out<'a>), I>
 where
-    A: Parser,
-    B: Parser,
-    C: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+    C: RefParser<I>,
 {
-    type Out = (Vec<A::Out>, C::Out);
-    fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
-        let mut ri = i.clone();
-        let mut res = Vec::new();
-        match self.c.parse(&ri) {
-            Ok((r, v, _)) => return Ok((r, (res, v), None)),
-            Err(_) => {}
-        }
-        loop {
-            ri = match self.a.parse(&ri) {
-                Ok((r, v, _)) => {
-                    res.push(v);
-                    r
-                }
-                Err(e) => return Err(e),
-            };
-            let c_err = match self.c.parse(&ri) {
-                Ok((r, v, _)) => return Ok((r, (res, v), None)),
-                Err(e) => e,
-            };
-            ri = match self.b.parse(&ri) {
-                Ok((r, _, _)) => r,
-                Err(e) => return Err(e.join(c_err)),
+    let mut ri = i.clone();
+    let mut res = Vec::new();
+    if let Ok((r, v, _)) = c.parse_ref(&ri) {
+        return Ok((r, (res, v), None));
+    }
+    loop {
+        ri = match a.parse_ref(&ri) {
+            Ok((r, v, _)) => {
+                res.push(v);
+                r
             }
+            Err(e) => return Err(e),
+        };
+        let c_err = match c.parse_ref(&ri) {
+            Ok((r, v, _)) => return Ok((r, (res, v), None)),
+            Err(e) => e,
+        };
+        ri = match b.parse_ref(&ri) {
+            Ok((r, _, _)) => r,
+            Err(e) => return Err(e.join(c_err)),
         }
     }
 }
 
+impl<I, A, B, C> RefParser<I> for SepUntil<A, B, C>
+where
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+    C: RefParser<I>,
+{
+    type Out<'a>
+        = (Vec<A::Out<'a>>, C::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        do_sep_until(i, &self.a, &self.b, &self.c)
+    }
+    fn expected_ref(&self) -> Expected {
+        self.c.expected_ref().or(self.a.expected_ref())
+    }
+}
+
+impl<I, A, B, C> Parser<I> for SepUntil<A, B, C>
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+    C: Parser<I>,
+{
+    type Out = (Vec<A::Out>, C::Out);
+    fn parse<'a>(&self, i: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        do_sep_until(i, &ByRef(&self.a), &ByRef(&self.b), &ByRef(&self.c))
+    }
+    fn expected(&self) -> Expected {
+        self.c.expected().or(self.a.expected())
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(
+            Gram::sep_plus(self.a.describe(), self.b.describe()).opt(),
+            self.c.describe(),
+        )
+    }
+}
+
 ///Allows for better errors looping until a specific finish. It does not return the close or the
 ///seperators the
 ///close is expected to be some kind of closer like '}'
 ///If you need the close you will have to use sep(..).then(..) though the errors will be less
 ///nice Recent changes mean that this now returns the ending result aswel, if you wish to ignore
 ///that use sep_until_ig
-pub fn sep_until<A, B, C>(a: A, b: B, c: C) -> SepUntil<A, B, C>
-where
-    A: Parser,
-    B: Parser,
-    C: Parser,
-{
+pub fn sep_until<A, B, C>(a: A, b: B, c: C) -> SepUntil<A, B, C> {
     SepUntil { a, b, c }
 }
 
-pub fn sep_until_ig<A, B, C>(a: A, b: B, c: C) -> impl Parser<Out = Vec<A::Out>>
-where
-    A: Parser,
-    B: Parser,
-    C: Parser,
-{
-    sep_until(a, b, c).map(|(a, _)| a)
+pub fn sep_until_ig<A, B, C>(a: A, b: B, c: C) -> UntilIg<SepUntil<A, B, C>> {
+    UntilIg {
+        p: sep_until(a, b, c),
+    }
 }
 
 #[cfg(test)]
-pub mod test {
+mod test {
     use super::*;
     //use crate::ptrait::*;
     use crate::*;
@@ -415,4 +752,30 @@ pub mod test {
         assert_eq!(b, "help".to_string());
         assert_eq!(cv, vec![")", ")", ")"]);
     }
+
+    #[test]
+    fn test_repeats_that_do_not_move_the_column() {
+        //a combining mark, or a '\r' before '\n', is consumed without changing line or col
+        let graphemes = "e\u{301}x";
+        let p = star(Any.one());
+        assert_eq!(
+            p.parse_s_cols(graphemes, Cols::graphemes()),
+            Ok(graphemes.chars().collect())
+        );
+        assert_eq!(
+            p.parse_s_cols("a\r\nb", Cols::default().crlf()),
+            Ok(vec!['a', '\r', '\n', 'b'])
+        );
+
+        let p = star_until(Any.one(), "x");
+        assert_eq!(
+            p.parse_s_cols(graphemes, Cols::graphemes()),
+            Ok((vec!['e', '\u{301}'], "x"))
+        );
+        let p = plus_until(Any.one(), "b");
+        assert_eq!(
+            p.parse_s_cols("\r\nb", Cols::default().crlf()),
+            Ok((vec!['\r', '\n'], "b"))
+        );
+    }
 }
@@ -0,0 +1,267 @@
+//! Renders errors against the source they came from, in the style of rustc.
+//!
+//! ```rust
+//! use gobble::*;
+//! let src = "let a = 3;\nlet b = ;";
+//! let p = sep_until_ig(("let ", common::Ident, " = ", common::Int), ";\n", (";", eoi));
+//! let e = p.parse_s(src).unwrap_err();
+//! assert_eq!(
+//!     render(src, &e.strung()),
+//!     r#"error: expected Int, found ";"
+//!  --> 2:9
+//!   |
+//! 2 | let b = ;
+//!   |         ^
+//! "#
+//! );
+//! ```
+//!
+//! With the "color" feature, ```render_color``` does the same with ansi colours.
+
+use crate::err::{PErr, StrungError};
+
+struct Style {
+    err: &'static str,
+    note: &'static str,
+    gutter: &'static str,
+    reset: &'static str,
+}
+
+const PLAIN: Style = Style {
+    err: "",
+    note: "",
+    gutter: "",
+    reset: "",
+};
+
+#[cfg(feature = "color")]
+const COLOR: Style = Style {
+    err: "\x1b[1;31m",
+    note: "\x1b[1;36m",
+    gutter: "\x1b[1;34m",
+    reset: "\x1b[0m",
+};
+
+/// Render the error with the line it happened on, and a caret under the column.
+/// Child errors are added as notes below.
+pub fn render(src: &str, e: &StrungError) -> String {
+    let mut res = String::new();
+    do_render(&mut res, src, e, &PLAIN);
+    res
+}
+
+/// The same as ```render```, but coloured for terminals
+#[cfg(feature = "color")]
+pub fn render_color(src: &str, e: &StrungError) -> String {
+    let mut res = String::new();
+    do_render(&mut res, src, e, &COLOR);
+    res
+}
+
+impl<'a> PErr<'a> {
+    /// Render the error against the source it was parsed from, see ```report::render```
+    pub fn render(&self, src: &str) -> String {
+        render(src, &self.clone().strung())
+    }
+}
+
+impl StrungError {
+    /// Render the error against the source it was parsed from, see ```report::render```
+    pub fn render(&self, src: &str) -> String {
+        render(src, self)
+    }
+}
+
+/// The found text up to the end of its line
+fn found_str(e: &StrungError) -> String {
+    let f: String = e.found.chars().take_while(|c| *c != '\n').collect();
+    match f.len() {
+        0 if e.found.is_empty() => "end of input".to_string(),
+        0 => "end of line".to_string(),
+        _ => format!("{:?}", f),
+    }
+}
+
+fn do_render(res: &mut String, src: &str, e: &StrungError, st: &Style) {
+    res.push_str(&format!(
+        "{}error{}: expected {}, found {}\n",
+        st.err,
+        st.reset,
+        e.exp.sentence(),
+        found_str(e)
+    ));
+    snippet(res, src, e, st.err, st);
+    context(res, e, st);
+    let mut ch = &e.child;
+    while let Some(c) = ch {
+        res.push_str(&format!(
+            "{}note{}: expected {}, found {}\n",
+            st.note,
+            st.reset,
+            c.exp.sentence(),
+            found_str(c)
+        ));
+        snippet(res, src, c, st.note, st);
+        context(res, c, st);
+        ch = &c.child;
+    }
+}
+
+fn context(res: &mut String, e: &StrungError, st: &Style) {
+    if e.context.is_empty() {
+        return;
+    }
+    let pad = " ".repeat((e.line + 1).to_string().len());
+    res.push_str(&format!(
+        "{} {}={} in {}\n",
+        pad,
+        st.gutter,
+        st.reset,
+        e.breadcrumbs()
+    ));
+}
+
+/// The line the error is on, and the part of it before the error.
+/// Found from the index where possible, as the column may count in other units (see ```Cols```)
+fn error_line<'a>(src: &'a str, e: &StrungError) -> (&'a str, &'a str) {
+    let i = match e.index {
+        Some(i) if src.is_char_boundary(i) => i,
+        None => src.len(),
+        _ => {
+            let line = src.lines().nth(e.line).unwrap_or("");
+            let n = line.char_indices().nth(e.col).map(|(i, _)| i);
+            return (line, &line[..n.unwrap_or(line.len())]);
+        }
+    };
+    let start = src[..i].rfind('\n').map(|n| n + 1).unwrap_or(0);
+    let end = src[i..].find('\n').map(|n| i + n).unwrap_or(src.len());
+    let line = src[start..end].trim_end_matches('\r');
+    (line, &src[start..i.min(start + line.len())])
+}
+
+fn snippet(res: &mut String, src: &str, e: &StrungError, mark: &str, st: &Style) {
+    let lnum = (e.line + 1).to_string();
+    let pad = " ".repeat(lnum.len());
+    let (line, before) = error_line(src, e);
+    //keep tabs so the caret lines up however the terminal shows them
+    let under: String = before
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    res.push_str(&format!(
+        "{}{}-->{} {}:{}\n",
+        pad,
+        st.gutter,
+        st.reset,
+        lnum,
+        e.col + 1
+    ));
+    res.push_str(&format!("{} {}|{}\n", pad, st.gutter, st.reset));
+    res.push_str(&format!("{}{} |{} {}\n", st.gutter, lnum, st.reset, line));
+    res.push_str(&format!(
+        "{} {}|{} {}{}^{}\n",
+        pad, st.gutter, st.reset, under, mark, st.reset
+    ));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_render_with_notes_and_tabs() {
+        let src = "a = [1,\n\t2,\n\tx]";
+        let p = (
+            common::Ident,
+            ws__("="),
+            "[".ig_then(sep_until_ig(wrap(WSL.istar(), common::Int), ",", "]")),
+        );
+        let e = p.parse_s(src).unwrap_err();
+        assert_eq!(
+            e.render(src),
+            r#"error: expected one of: Int, a char in "WSL", found "x]"
+ --> 3:2
+  |
+3 | 	x]
+  | 	^
+note: expected a char in "WS", found "[1,"
+ --> 1:5
+  |
+1 | a = [1,
+  |     ^
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_caret_with_other_cols() {
+        let src = "ab\r\n\t\u{1F600}é = x\r\n";
+        let p = (common::Ident, "\r\n\t\u{1F600}é = ", common::Int);
+        let it = LCChars::new(src).with_cols(Cols::utf16().tabs(4).crlf());
+        let e = p.parse(&it).unwrap_err().strung();
+        //the column counts in utf16 with wide tabs, the caret still sits under the 'x'
+        assert_eq!((e.line, e.col), (1, 10));
+        assert_eq!(
+            e.render(src),
+            "error: expected Int, found \"x\\r\"\n --> 2:11\n  |\n2 | \t\u{1F600}é = x\n  | \t     ^\n"
+        );
+    }
+
+    parser! {
+        (FSig->Vec<String>)
+        ("fn ", common::Ident, "(".ig_then(sep_until_ig(common::Ident, ",", ")").context("argument list"))).map(|(_, _, a)| a)
+    }
+
+    parser! {
+        (Nums->Vec<isize>)
+        sep_until_ig(wrap(Trivia::new().nested("/*", "*/"), common::Int), ",", ";")
+    }
+
+    #[test]
+    fn test_context_kept_on_breaking_errors() {
+        let e = Nums.parse_s("1, /* never closed").unwrap_err();
+        assert!(e.is_brk);
+        assert_eq!(*e.context, ["Nums"]);
+    }
+
+    #[test]
+    fn test_render_context_breadcrumbs() {
+        let src = "fn load(fname,3)";
+        let e = FSig.parse_s(src).unwrap_err();
+        assert_eq!(*e.context, ["argument list", "FSig"]);
+        assert_eq!(
+            e.render(src),
+            r#"error: expected Ident, found "3)"
+ --> 1:15
+  |
+1 | fn load(fname,3)
+  |               ^
+  = in FSig > argument list
+note: expected one of: `_`, a char in "Alpha", a char in "NumDigit", found "(fname,3)"
+ --> 1:8
+  |
+1 | fn load(fname,3)
+  |        ^
+note: expected FSig, found "fn load(fn"
+ --> 1:1
+  |
+1 | fn load(fname,3)
+  | ^
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_end_of_input() {
+        let e = ("ab", "cd").parse_s("ab").unwrap_err();
+        assert_eq!(
+            e.render("ab"),
+            r#"error: expected `cd`, found end of input
+ --> 1:3
+  |
+1 | ab
+  |   ^
+"#
+        );
+    }
+}
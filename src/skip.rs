@@ -1,6 +1,11 @@
+use crate::borrow::Owned;
 use crate::chars::*;
+use crate::describe::Gram;
+use crate::err::{Expected, PErr};
+use crate::input::Input;
 use crate::iter::LCChars;
 use crate::ptrait::{ParseRes, Parser};
+use crate::reader::do_tag;
 
 pub fn do_skip_c<'a, CB: CharBool>(
     it: &LCChars<'a>,
@@ -29,12 +34,12 @@ pub fn do_skip_c<'a, CB: CharBool>(
     }
 }
 
-pub fn do_skip_p<'a, P: Parser>(
-    it: &LCChars<'a>,
+pub fn do_skip_p<'a, I: ?Sized + Input, P: Parser<I>>(
+    it: &LCChars<'a, I>,
     p: &P,
     min: usize,
     exact: bool,
-) -> ParseRes<'a, ()> {
+) -> ParseRes<'a, (), I> {
     let mut it = it.clone();
     let mut done = 0;
     loop {
@@ -62,11 +67,19 @@ pub struct CharSkip<CB: CharBool> {
     pub cb: CB,
 }
 
+impl<CB: CharBool> Owned for CharSkip<CB> {}
+
 impl<CB: CharBool> Parser for CharSkip<CB> {
     type Out = ();
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
         do_skip_c(it, &self.cb, 0, false)
     }
+    fn expected(&self) -> Expected {
+        self.cb.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.cb.expected()).star()
+    }
 }
 
 #[derive(Clone)]
@@ -74,11 +87,19 @@ pub struct CharSkipPlus<CB: CharBool> {
     pub cb: CB,
 }
 
+impl<CB: CharBool> Owned for CharSkipPlus<CB> {}
+
 impl<CB: CharBool> Parser for CharSkipPlus<CB> {
     type Out = ();
     fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, ()> {
         do_skip_c(i, &self.cb, 1, false)
     }
+    fn expected(&self) -> Expected {
+        self.cb.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.cb.expected()).plus()
+    }
 }
 #[derive(Clone)]
 pub struct CharSkipExact<CB: CharBool> {
@@ -86,69 +107,103 @@ pub struct CharSkipExact<CB: CharBool> {
     pub n: usize,
 }
 
+impl<CB: CharBool> Owned for CharSkipExact<CB> {}
+
 impl<CB: CharBool> Parser for CharSkipExact<CB> {
     type Out = ();
     fn parse<'a>(&self, i: &LCChars<'a>) -> ParseRes<'a, ()> {
         do_skip_c(i, &self.cb, self.n, true)
     }
+    fn expected(&self) -> Expected {
+        self.cb.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::from_expected(self.cb.expected()).times(self.n)
+    }
 }
 
-pub fn ig_star<A: Parser>(a: A) -> PSkipStar<A> {
+pub fn ig_star<A>(a: A) -> PSkipStar<A> {
     PSkipStar { a }
 }
-pub fn ig_plus<A: Parser>(a: A) -> PSkipPlus<A> {
+pub fn ig_plus<A>(a: A) -> PSkipPlus<A> {
     PSkipPlus { a }
 }
-pub fn ig_exact<A: Parser>(a: A, n: usize) -> PSkipExact<A> {
+pub fn ig_exact<A>(a: A, n: usize) -> PSkipExact<A> {
     PSkipExact { a, n }
 }
 
 #[derive(Clone)]
-pub struct PSkipStar<A: Parser> {
+pub struct PSkipStar<A> {
     pub a: A,
 }
-impl<A: Parser> Parser for PSkipStar<A> {
+impl<A> Owned for PSkipStar<A> {}
+
+impl<I: ?Sized + Input, A: Parser<I>> Parser<I> for PSkipStar<A> {
     type Out = ();
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, (), I> {
         do_skip_p(it, &self.a, 0, false)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.a.describe().star()
+    }
 }
 
 #[derive(Clone)]
-pub struct PSkipPlus<A: Parser> {
+pub struct PSkipPlus<A> {
     pub a: A,
 }
-impl<A: Parser> Parser for PSkipPlus<A> {
+impl<A> Owned for PSkipPlus<A> {}
+
+impl<I: ?Sized + Input, A: Parser<I>> Parser<I> for PSkipPlus<A> {
     type Out = ();
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, (), I> {
         do_skip_p(it, &self.a, 1, false)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.a.describe().plus()
+    }
 }
 
 #[derive(Clone)]
-pub struct PSkipExact<A: Parser> {
+pub struct PSkipExact<A> {
     pub a: A,
     pub n: usize,
 }
-impl<A: Parser> Parser for PSkipExact<A> {
+impl<A> Owned for PSkipExact<A> {}
+
+impl<I: ?Sized + Input, A: Parser<I>> Parser<I> for PSkipExact<A> {
     type Out = ();
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, (), I> {
         do_skip_p(it, &self.a, self.n, true)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.a.describe().times(self.n)
+    }
 }
 
-pub fn skip_2_star<A: Parser, B: Parser>(a: A, b: B) -> Skip2Star<A, B> {
+pub fn skip_2_star<A, B>(a: A, b: B) -> Skip2Star<A, B> {
     Skip2Star { a, b }
 }
 
-pub struct Skip2Star<A: Parser, B: Parser> {
+pub struct Skip2Star<A, B> {
     a: A,
     b: B,
 }
 
-impl<A: Parser, B: Parser> Parser for Skip2Star<A, B> {
+impl<A, B> Owned for Skip2Star<A, B> {}
+
+impl<I: ?Sized + Input, A: Parser<I>, B: Parser<I>> Parser<I> for Skip2Star<A, B> {
     type Out = ();
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, (), I> {
         let mut it = it.clone();
         loop {
             if let Ok((nit, _, _)) = self.a.parse(&it) {
@@ -160,4 +215,269 @@ impl<A: Parser, B: Parser> Parser for Skip2Star<A, B> {
             }
         }
     }
+    fn expected(&self) -> Expected {
+        self.a.expected().or(self.b.expected())
+    }
+    fn describe(&self) -> Gram {
+        Gram::alt(self.a.describe(), self.b.describe()).star()
+    }
+}
+
+/// A comment syntax for ```Trivia```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Comment {
+    /// From the marker to the end of the line, eg "//", "#" or "--".
+    /// The line ending, "\n" or "\r\n", is not part of the comment
+    Line(&'static str),
+    /// Between an open and close marker, eg "/*" and "*/"
+    Block(&'static str, &'static str),
+    /// A block where inner open markers need their own close
+    Nested(&'static str, &'static str),
+}
+
+/// Finds one comment, returning the text inside the markers.
+/// Ok(None) means there was no comment here, an unclosed block is an error.
+fn do_comment<'a>(
+    it: &LCChars<'a>,
+    c: &Comment,
+) -> Result<Option<(LCChars<'a>, &'a str)>, PErr<'a>> {
+    let (open, close, nested) = match c {
+        Comment::Line(m) => {
+            let mut it = match do_tag(it, m) {
+                Ok((nit, _, _)) => nit,
+                Err(_) => return Ok(None),
+            };
+            let st = it.clone();
+            loop {
+                let prev = it.clone();
+                match it.next() {
+                    Some('\n') | None => return Ok(Some((prev.clone(), st.slice_to(&prev)))),
+                    Some('\r') if it.as_str().starts_with('\n') => {
+                        return Ok(Some((prev.clone(), st.slice_to(&prev))))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        Comment::Block(o, c) => (o, c, false),
+        Comment::Nested(o, c) => (o, c, true),
+    };
+    let mut it = match do_tag(it, open) {
+        Ok((nit, _, _)) => nit,
+        Err(_) => return Ok(None),
+    };
+    let st = it.clone();
+    let mut depth = 1;
+    loop {
+        if let Ok((nit, _, _)) = do_tag(&it, close) {
+            depth -= 1;
+            if depth == 0 {
+                return Ok(Some((nit, st.slice_to(&it))));
+            }
+            it = nit;
+            continue;
+        }
+        if nested {
+            if let Ok((nit, _, _)) = do_tag(&it, open) {
+                depth += 1;
+                it = nit;
+                continue;
+            }
+        }
+        if it.next().is_none() {
+            let mut e = it.err(Expected::Str(close));
+            e.is_brk = true;
+            return Err(e);
+        }
+    }
+}
+
+/// Skips whitespace and comments, in whichever syntaxes are added.
+/// A block comment that is never closed is an error.
+///
+/// ```rust
+/// use gobble::*;
+/// let tv = Trivia::new().line("//").nested("/*", "*/");
+/// let p = sep_until_ig(wrap(tv.clone(), common::Int), ",", ";");
+/// let src = "1, // one
+///     2 /* two /* nested */ */, 3;";
+/// assert_eq!(p.parse_s(src), Ok(vec![1, 2, 3]));
+///
+/// assert!(p.parse_s("1, /* never closed").unwrap_err().is_brk);
+///
+/// //Keep the comments, eg for docs
+/// let docs = Trivia::new().line("///").line("//").text();
+/// assert_eq!(
+///     docs.parse_s("/// a doc\n// plain\n"),
+///     Ok(vec![" a doc".to_string(), " plain".to_string()])
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trivia {
+    newlines: bool,
+    comments: Vec<Comment>,
+}
+
+impl Default for Trivia {
+    fn default() -> Self {
+        Trivia {
+            newlines: true,
+            comments: Vec::new(),
+        }
+    }
+}
+
+impl Trivia {
+    /// Whitespace including newlines, and no comments until some are added
+    pub fn new() -> Self {
+        Trivia::default()
+    }
+
+    /// Only skip spaces and tabs, for grammars where newlines matter
+    pub fn inline(mut self) -> Self {
+        self.newlines = false;
+        self
+    }
+
+    /// Panics if a marker is empty, as an empty marker would match everywhere
+    pub fn comment(mut self, c: Comment) -> Self {
+        let ok = match c {
+            Comment::Line(m) => !m.is_empty(),
+            Comment::Block(o, c) | Comment::Nested(o, c) => !o.is_empty() && !c.is_empty(),
+        };
+        assert!(ok, "empty comment marker in {:?}", c);
+        self.comments.push(c);
+        self
+    }
+
+    /// Comments from 'marker' to the end of the line.
+    /// These are tried in the order added, so add "///" before "//" to tell them apart.
+    pub fn line(self, marker: &'static str) -> Self {
+        self.comment(Comment::Line(marker))
+    }
+
+    pub fn block(self, open: &'static str, close: &'static str) -> Self {
+        self.comment(Comment::Block(open, close))
+    }
+
+    pub fn nested(self, open: &'static str, close: &'static str) -> Self {
+        self.comment(Comment::Nested(open, close))
+    }
+
+    /// A parser returning the text of each comment skipped, without the markers
+    pub fn text(self) -> TriviaText {
+        TriviaText { t: self }
+    }
+
+    fn do_trivia<'a>(
+        &self,
+        it: &LCChars<'a>,
+        mut found: Option<&mut Vec<String>>,
+    ) -> Result<LCChars<'a>, PErr<'a>> {
+        let mut it = it.clone();
+        'outer: loop {
+            it = match self.newlines {
+                true => do_skip_c(&it, &WSL, 0, false)?.0,
+                false => do_skip_c(&it, &WS, 0, false)?.0,
+            };
+            for c in &self.comments {
+                if let Some((nit, text)) = do_comment(&it, c)? {
+                    if let Some(f) = &mut found {
+                        f.push(text.to_string());
+                    }
+                    it = nit;
+                    continue 'outer;
+                }
+            }
+            return Ok(it);
+        }
+    }
+}
+
+impl Owned for Trivia {}
+
+impl Parser for Trivia {
+    type Out = ();
+    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, ()> {
+        Ok((self.do_trivia(it, None)?, (), None))
+    }
+    fn expected(&self) -> Expected {
+        Expected::Named("whitespace")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TriviaText {
+    t: Trivia,
+}
+
+impl Owned for TriviaText {}
+
+impl Parser for TriviaText {
+    type Out = Vec<String>;
+    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Vec<String>> {
+        let mut res = Vec::new();
+        let nit = self.t.do_trivia(it, Some(&mut res))?;
+        Ok((nit, res, None))
+    }
+    fn expected(&self) -> Expected {
+        Expected::Named("whitespace")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_trivia_comment_styles() {
+        let sql = Trivia::new().line("--").block("/*", "*/");
+        let p = sep_until_ig(wrap(sql.clone(), common::Ident), ",", eoi);
+        let src = "a -- first\n, /* /* not nested */ b,\n\tc --last";
+        assert_eq!(
+            p.parse_s(src),
+            Ok(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+
+        let sh = Trivia::new().inline().line("#");
+        let lines = sep_until_ig(wrap(sh, common::Int), "\n", eoi);
+        assert_eq!(lines.parse_s("1 # one\n2\t#two"), Ok(vec![1, 2]));
+
+        //inline trivia leaves the newline for the grammar
+        let e = (Trivia::new().inline(), "x").parse_s(" \nx").unwrap_err();
+        assert_eq!(e.index, Some(1));
+    }
+
+    #[test]
+    fn test_trivia_crlf_line_comments() {
+        let tv = Trivia::new().line("//");
+        let p = sep_until_ig(wrap(tv.clone(), common::Int), ",", eoi);
+        assert_eq!(p.parse_s("1, // one\r\n2 //two\r\n"), Ok(vec![1, 2]));
+        assert_eq!(
+            tv.text().parse_s("// one\r\n// a\rb\n"),
+            Ok(vec![" one".to_string(), " a\rb".to_string()])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "empty comment marker")]
+    fn test_trivia_rejects_empty_markers() {
+        let _ = Trivia::new().block("/*", "");
+    }
+
+    #[test]
+    fn test_trivia_nested_and_unclosed() {
+        let tv = Trivia::new().nested("/*", "*/").text();
+        assert_eq!(
+            tv.parse_s("/* a /* b */ c */ /**/x"),
+            Ok(vec![" a /* b */ c ".to_string(), "".to_string()])
+        );
+        let e = tv.parse_s("/* a /* b */ c").unwrap_err();
+        assert!(e.is_brk);
+        assert_eq!((e.index, e.exp), (None, Expected::Str("*/")));
+
+        //the break is not swallowed by or
+        let p = or(wrap(Trivia::new().block("/*", "*/"), "a"), "/*b");
+        assert!(p.parse_s("/*b").is_err());
+    }
 }
@@ -0,0 +1,224 @@
+//! Spans record where in the input a result came from, with the line, column and index
+//! of both ends, so that later stages can point back at the source.
+//!
+//! ```rust
+//! use gobble::*;
+//! let src = "let x =\n  42;";
+//! let p = ("let ", common::Ident.spanned(), ws__("="), WSL.istar(), common::Int.spanned());
+//! let (_, name, _, _, val) = p.parse_s(src).unwrap();
+//! assert_eq!(name.ob, "x");
+//! assert_eq!(name.span.start, Loc { index: 4, line: 0, col: 4 });
+//! assert_eq!(val.span.start, Loc { index: 10, line: 1, col: 2 });
+//! assert_eq!(val.span.end, Loc { index: 12, line: 1, col: 4 });
+//!
+//! let whole = name.span.merge(val.span);
+//! assert_eq!(whole.on_str(src), "x =\n  42");
+//! ```
+use crate::borrow::Owned;
+use crate::describe::Gram;
+use crate::err::Expected;
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+
+/// A point in the input. Index is in the units of the input, (bytes for str)
+/// Ordering is by index, which also orders line and column for points in the same input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Loc {
+    pub index: usize,
+    pub line: usize,
+    pub col: usize,
+}
+
+/// The range between two points, the end is not included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub start: Loc,
+    pub end: Loc,
+}
+
+impl Span {
+    /// The smallest span that covers both
+    pub fn merge(self, b: Span) -> Span {
+        Span {
+            start: self.start.min(b.start),
+            end: self.end.max(b.end),
+        }
+    }
+
+    /// The smallest span covering all of them, or None if there are none
+    pub fn covering<S: IntoIterator<Item = Span>>(spans: S) -> Option<Span> {
+        spans.into_iter().reduce(Span::merge)
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.start.index <= index && index < self.end.index
+    }
+
+    /// The length in the units of the input
+    pub fn len(&self) -> usize {
+        self.end.index - self.start.index
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///This version assumes that this is the string it came from
+    pub fn on_str<'a>(&self, s: &'a str) -> &'a str {
+        &s[self.start.index..self.end.index]
+    }
+}
+
+/// A result along with the span of input it was parsed from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned<T> {
+    pub span: Span,
+    pub ob: T,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(span: Span, ob: T) -> Self {
+        Spanned { span, ob }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Spanned<U> {
+        Spanned {
+            span: self.span,
+            ob: f(self.ob),
+        }
+    }
+
+    pub fn as_ref(&self) -> Spanned<&T> {
+        Spanned {
+            span: self.span,
+            ob: &self.ob,
+        }
+    }
+
+    /// Combine two spanned values into one covering both, eg for a binary operation.
+    /// 'f' gets both with their spans, so they can become children of the result
+    pub fn merge<B, U, F>(self, b: Spanned<B>, f: F) -> Spanned<U>
+    where
+        F: FnOnce(Spanned<T>, Spanned<B>) -> U,
+    {
+        Spanned {
+            span: self.span.merge(b.span),
+            ob: f(self, b),
+        }
+    }
+
+    ///This version assumes that this is the string it came from
+    pub fn on_str<'a>(&self, s: &'a str) -> &'a str {
+        self.span.on_str(s)
+    }
+}
+
+#[derive(Clone)]
+pub struct PSpanned<P> {
+    p: P,
+}
+
+impl<P> Owned for PSpanned<P> {}
+
+impl<I: ?Sized + Input, P: Parser<I>> Parser<I> for PSpanned<P> {
+    type Out = Spanned<P::Out>;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
+        let start = it.loc();
+        let (rit, ob, pex) = self.p.parse(it)?;
+        let span = Span {
+            start,
+            end: rit.loc(),
+        };
+        Ok((rit, Spanned { span, ob }, pex))
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+pub fn spanned<P>(p: P) -> PSpanned<P> {
+    PSpanned { p }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[derive(Debug, PartialEq)]
+    pub enum Expr {
+        Num(isize),
+        Add(Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    }
+
+    fn expr<'a>(it: &LCChars<'a>) -> ParseRes<'a, Spanned<Expr>> {
+        let num = || wrap(WSL.istar(), common::Int.map(Expr::Num).spanned());
+        (num(), star("+".ig_then(num())))
+            .map(|(a, v)| {
+                v.into_iter().fold(a, |a, b| {
+                    a.merge(b, |a, b| Expr::Add(Box::new(a), Box::new(b)))
+                })
+            })
+            .parse(it)
+    }
+
+    #[test]
+    fn test_spans_cover_nested_nodes() {
+        let src = "1 +\n 23\n+ 4";
+        let e = expr.parse_s(src).unwrap();
+        assert_eq!(e.on_str(src), src);
+        assert_eq!(
+            e.span.end,
+            Loc {
+                index: 11,
+                line: 2,
+                col: 3
+            }
+        );
+        match e.ob {
+            Expr::Add(l, r) => {
+                assert_eq!(l.on_str(src), "1 +\n 23");
+                assert_eq!(
+                    l.span.end,
+                    Loc {
+                        index: 7,
+                        line: 1,
+                        col: 3
+                    }
+                );
+                assert_eq!(r.ob, Expr::Num(4));
+                assert_eq!(
+                    r.span.start,
+                    Loc {
+                        index: 10,
+                        line: 2,
+                        col: 2
+                    }
+                );
+            }
+            _ => panic!("expected an Add"),
+        }
+    }
+
+    #[test]
+    fn test_span_helpers() {
+        let src = "ab cd ef";
+        let v = star(ws_(Alpha.plus().spanned())).parse_s(src).unwrap();
+        let spans: Vec<Span> = v.iter().map(|s| s.span).collect();
+        let all = Span::covering(spans.clone()).unwrap();
+        assert_eq!(all.on_str(src), src);
+        assert_eq!(all.len(), 8);
+        assert!(spans[1].contains(4));
+        assert!(!spans[1].contains(5));
+        assert_eq!(Span::covering(Vec::new()), None);
+
+        //Pos keeps working on the same input
+        let p = pos_ig(Alpha.plus()).parse_s("abc").unwrap();
+        assert_eq!((p.fin, p.on_str("abc")), (None, "abc"));
+        let s = Alpha.plus().spanned().parse_s("abc").unwrap();
+        assert_eq!(s.span.end.index, 3);
+    }
+}
@@ -0,0 +1,224 @@
+//! User state carried through the parse, for grammars where what has already been parsed
+//! changes how the rest is read, such as names declared with "typedef" in C.
+//!
+//! The state lives in ```LCChars``` and is replaced rather than changed in place, so when
+//! ```or```, ```maybe``` or any other parser backtracks, the state goes back with it.
+//! Start a parse with ```Parser::parse_s_state```, read the state with ```get_state``` or
+//! ```try_map_state```, and change it with ```update_state```.
+//!
+//! Each update clones the state, so for large tables prefer something cheap to clone,
+//! such as a persistent list, or an ```Rc``` of a map.
+//!
+//! ```rust
+//! use gobble::*;
+//! let word = ws_(Alpha.plus()).update_state(|n: &mut usize, _| *n += 1);
+//! let (v, n) = star(word).parse_s_state("one two three", 0usize).unwrap();
+//! assert_eq!((v.len(), n), (3, 3));
+//!
+//! //The failed branch leaves no trace in the state
+//! let p = or(
+//!     ("x".update_state(|n: &mut usize, _| *n += 1), "y").ig(),
+//!     "xz".ig(),
+//! );
+//! assert_eq!(p.parse_s_state("xz", 0usize), Ok(((), 0)));
+//! ```
+use crate::borrow::Owned;
+use crate::describe::Gram;
+use crate::err::Expected;
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::{ParseRes, Parser};
+use std::marker::PhantomData;
+
+pub(crate) fn no_state() -> Expected {
+    Expected::Named("user state")
+}
+
+pub struct GetState<S> {
+    s: PhantomData<fn() -> S>,
+}
+
+impl<S> Clone for GetState<S> {
+    fn clone(&self) -> Self {
+        get_state()
+    }
+}
+
+impl<S> Owned for GetState<S> {}
+
+impl<I: ?Sized + Input, S: Clone + 'static> Parser<I> for GetState<S> {
+    type Out = S;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, S, I> {
+        match it.state::<S>() {
+            Some(s) => Ok((it.clone(), s.clone(), None)),
+            None => it.err_r(no_state()),
+        }
+    }
+    fn expected(&self) -> Expected {
+        no_state()
+    }
+}
+
+/// Returns a copy of the user state without moving, errors if there is no state of type 'S'
+pub fn get_state<S>() -> GetState<S> {
+    GetState { s: PhantomData }
+}
+
+#[derive(Clone)]
+pub struct UpdateState<P, S, F> {
+    p: P,
+    f: F,
+    s: PhantomData<fn() -> S>,
+}
+
+impl<P, S, F> Owned for UpdateState<P, S, F> {}
+
+impl<I, P, S, F> Parser<I> for UpdateState<P, S, F>
+where
+    I: ?Sized + Input,
+    P: Parser<I>,
+    S: Clone + 'static,
+    F: Fn(&mut S, &P::Out),
+{
+    type Out = P::Out;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, P::Out, I> {
+        let (mut rit, v, ct) = self.p.parse(it)?;
+        let mut s = match rit.state::<S>() {
+            Some(s) => s.clone(),
+            None => return it.err_r(no_state()),
+        };
+        (self.f)(&mut s, &v);
+        rit.set_state(s);
+        Ok((rit, v, ct))
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+pub(crate) fn update_state<P, S, F>(p: P, f: F) -> UpdateState<P, S, F> {
+    UpdateState {
+        p,
+        f,
+        s: PhantomData,
+    }
+}
+
+#[derive(Clone)]
+pub struct TryMapState<P, S, V, F> {
+    p: P,
+    f: F,
+    s: PhantomData<fn() -> (S, V)>,
+}
+
+impl<P, S, V, F> Owned for TryMapState<P, S, V, F> {}
+
+impl<I, P, S, V, F> Parser<I> for TryMapState<P, S, V, F>
+where
+    I: ?Sized + Input,
+    P: Parser<I>,
+    S: 'static,
+    F: Fn(&S, P::Out) -> Result<V, Expected>,
+{
+    type Out = V;
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, V, I> {
+        let (rit, v, ct) = self.p.parse(it)?;
+        let s = match rit.state::<S>() {
+            Some(s) => s,
+            None => return it.err_r(no_state()),
+        };
+        match (self.f)(s, v) {
+            Ok(v2) => Ok((rit, v2, ct)),
+            Err(e) => rit.err_r(e),
+        }
+    }
+    fn expected(&self) -> Expected {
+        self.p.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.p.describe()
+    }
+}
+
+pub(crate) fn try_map_state<P, S, V, F>(p: P, f: F) -> TryMapState<P, S, V, F> {
+    TryMapState {
+        p,
+        f,
+        s: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[derive(Debug, PartialEq)]
+    pub enum Stmt {
+        Typedef(String),
+        Decl(String, String),
+        Mul(String, String),
+    }
+
+    type Types = Vec<String>;
+
+    fn type_name<'a>(it: &LCChars<'a>) -> ParseRes<'a, String> {
+        common::Ident
+            .try_map_state(|ts: &Types, n| match ts.contains(&n) {
+                true => Ok(n),
+                false => Err(Expected::Named("type name")),
+            })
+            .parse(it)
+    }
+
+    fn stmt<'a>(it: &LCChars<'a>) -> ParseRes<'a, Stmt> {
+        or3(
+            "typedef int "
+                .ig_then(common::Ident)
+                .update_state(|ts: &mut Types, n| ts.push(n.clone()))
+                .map(Stmt::Typedef),
+            (type_name, ws__("*"), common::Ident).map(|(t, _, n)| Stmt::Decl(t, n)),
+            (common::Ident, ws__("*"), common::Ident).map(|(a, _, b)| Stmt::Mul(a, b)),
+        )
+        .parse(it)
+    }
+
+    #[test]
+    fn test_typedef_names_change_the_parse() {
+        let src = "typedef int T; T * x; y * z;\ntypedef int y; y * w;";
+        let p = star_until_ig(wrap(WSL.istar(), first(stmt, ";")), eoi);
+        let (v, ts) = p.parse_s_state(src, Types::new()).unwrap();
+        let s = |s: &str| s.to_string();
+        assert_eq!(
+            v,
+            vec![
+                Stmt::Typedef(s("T")),
+                Stmt::Decl(s("T"), s("x")),
+                Stmt::Mul(s("y"), s("z")),
+                Stmt::Typedef(s("y")),
+                Stmt::Decl(s("y"), s("w")),
+            ]
+        );
+        assert_eq!(ts, vec![s("T"), s("y")]);
+    }
+
+    #[test]
+    fn test_state_rolls_back() {
+        let p = or(
+            first(
+                common::Ident.update_state(|ts: &mut Types, n| ts.push(n.clone())),
+                "!",
+            ),
+            common::Ident,
+        );
+        let (v, ts) = p.parse_s_state("abc?", Types::new()).unwrap();
+        assert_eq!((v.as_str(), ts.len()), ("abc", 0));
+
+        //without a state, or with one of another type, stateful parsers fail
+        assert!(get_state::<usize>().parse_s("").is_err());
+        assert!(type_name.parse_s_state("T", 0usize).is_err());
+        assert_eq!(get_state::<usize>().parse_s_state("", 3usize), Ok((3, 3)));
+    }
+}
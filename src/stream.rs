@@ -0,0 +1,320 @@
+//! Parse items from anything that implements ```std::io::Read```, a chunk at a time.
+//!
+//! ```PullParser``` needs the whole input in memory, which is no good for large files or sockets.
+//! ```StreamParser``` reads chunks into a buffer, and parses items from the front of it,
+//! dropping the text of each item once it is returned.
+//!
+//! A parser that reaches the end of the buffer might do something different with more input,
+//! (```Int``` on "12" might be looking at "123"), so the result is only used once the parser
+//! stops before the end of the buffer, or the reader has no more to give. See ```is_incomplete```.
+//!
+//! Each retry parses the item again from its start. To keep that from growing with the square
+//! of the item's length, a read after an incomplete parse asks for at least as many bytes as
+//! are already buffered, so a reader that fills its buffer (like a file) doubles what there is
+//! to parse each time. Readers that return less (like a slow socket) cost a parse per read.
+//! Columns are counted with ```Cols::default()``` unless set with ```with_cols```.
+//!
+//! ```rust
+//! use gobble::*;
+//! let src = "12, 34,5 ,";
+//! let items: Result<Vec<isize>, StreamErr> =
+//!     StreamParser::new(first(common::Int, ws__(",")), src.as_bytes())
+//!         .chunk_size(2)
+//!         .collect();
+//! assert_eq!(items.unwrap(), vec![12, 34, 5]);
+//! ```
+use crate::err::{PErr, StrungError};
+use crate::input::Input;
+use crate::iter::{Cols, LCChars};
+use crate::ptrait::{ParseRes, Parser};
+use crate::reader::EOI;
+use crate::span::Loc;
+use std::io::{self, ErrorKind, Read};
+use thiserror::*;
+
+#[derive(Debug, Error)]
+pub enum StreamErr {
+    #[error("{0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Parse(StrungError),
+}
+
+/// Did the result reach the end of the input, so that more input might have changed it.
+/// This is true for errors at the end, and for successes that stopped at the end,
+/// or could have continued there
+pub fn is_incomplete<V, I: ?Sized + Input>(r: &ParseRes<V, I>) -> bool {
+    match r {
+        Ok((it, _, c)) => it.index().is_none() || c.as_ref().is_some_and(at_end),
+        Err(e) => at_end(e),
+    }
+}
+
+fn at_end(e: &PErr) -> bool {
+    e.index.is_none()
+}
+
+pub struct StreamParser<R: Read, P: Parser, E: Parser = EOI> {
+    r: R,
+    p: P,
+    end: E,
+    ///Text read but not yet parsed
+    buf: String,
+    ///The start of a char split between chunks
+    pending: Vec<u8>,
+    chunk: usize,
+    cols: Cols,
+    eof: bool,
+    errored: bool,
+    ///The position of the start of buf in the stream
+    start: Loc,
+}
+
+impl<R: Read, P: Parser> StreamParser<R, P, EOI> {
+    pub fn new(p: P, r: R) -> Self {
+        StreamParser::with_end(p, EOI, r)
+    }
+}
+
+impl<R: Read, P: Parser, E: Parser> StreamParser<R, P, E> {
+    /// Parse items until 'end' matches where an item would start
+    pub fn with_end(p: P, end: E, r: R) -> Self {
+        StreamParser {
+            r,
+            p,
+            end,
+            buf: String::new(),
+            pending: Vec::new(),
+            chunk: 4096,
+            cols: Cols::default(),
+            eof: false,
+            errored: false,
+            start: Loc::default(),
+        }
+    }
+
+    /// How many bytes to ask the reader for at a time, (default 4096)
+    pub fn chunk_size(mut self, n: usize) -> Self {
+        self.chunk = n.max(1);
+        self
+    }
+
+    /// Count columns with 'cols', see ```Cols```
+    pub fn with_cols(mut self, cols: Cols) -> Self {
+        self.cols = cols;
+        self
+    }
+
+    /// The text read but not yet parsed
+    pub fn buffered(&self) -> &str {
+        &self.buf
+    }
+
+    /// Reads a chunk onto the end of the buffer, keeping back any partial char.
+    /// Asks for at least the length of the buffer, so a long item is not re-parsed per chunk
+    fn fill(&mut self) -> io::Result<()> {
+        let mut b = vec![0; self.chunk.max(self.buf.len())];
+        let n = loop {
+            match self.r.read(&mut b) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        };
+        if n == 0 {
+            self.eof = true;
+            if !self.pending.is_empty() {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    "stream ends mid char",
+                ));
+            }
+            return Ok(());
+        }
+        self.pending.extend_from_slice(&b[..n]);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(s) => s.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(e) => return Err(io::Error::new(ErrorKind::InvalidData, e)),
+        };
+        let rest = self.pending.split_off(valid);
+        let done = std::mem::replace(&mut self.pending, rest);
+        match String::from_utf8(done) {
+            Ok(s) => self.buf.push_str(&s),
+            Err(e) => return Err(io::Error::new(ErrorKind::InvalidData, e)),
+        }
+        Ok(())
+    }
+
+    /// Drops the buffer up to 'end', which becomes the start of the buffer
+    fn consume(&mut self, end: Loc) {
+        self.buf.drain(..end.index - self.start.index);
+        self.start = end;
+    }
+}
+
+impl<R: Read, P: Parser, E: Parser> Iterator for StreamParser<R, P, E> {
+    type Item = Result<P::Out, StreamErr>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.errored {
+            return None;
+        }
+        loop {
+            //Work out what to do while the buffer is borrowed, then do it
+            let (done, failed) = {
+                let it = LCChars::at(&self.buf[..], self.start).with_cols(self.cols);
+                let r = self.p.parse(&it);
+                if !self.eof && is_incomplete(&r) {
+                    (None, None)
+                } else {
+                    match r {
+                        Ok((it2, v, _)) => (Some((it2.loc(), v)), None),
+                        Err(e) => {
+                            let er = self.end.parse(&it);
+                            match (self.eof || !is_incomplete(&er), er) {
+                                (false, _) => (None, None),
+                                (true, Ok(_)) => return None,
+                                (true, Err(_)) => (None, Some(e.strung())),
+                            }
+                        }
+                    }
+                }
+            };
+            if let Some((end, v)) = done {
+                self.consume(end);
+                return Some(Ok(v));
+            }
+            if let Some(e) = failed {
+                self.errored = true;
+                return Some(Err(StreamErr::Parse(e)));
+            }
+            if let Err(e) = self.fill() {
+                self.errored = true;
+                return Some(Err(StreamErr::Io(e)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::io::Read;
+
+    /// Gives one byte per read, like a slow socket
+    struct Drip<'a>(&'a [u8]);
+
+    impl<'a> Read for Drip<'a> {
+        fn read(&mut self, b: &mut [u8]) -> std::io::Result<usize> {
+            match (self.0.split_first(), b.is_empty()) {
+                (Some((f, rest)), false) => {
+                    b[0] = *f;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    parser! {
+        (LogLine->(String, isize))
+        (not("= \n").plus(), ws__("="), common::Int, "\n").map(|(k, _, v, _)| (k, v))
+    }
+
+    #[test]
+    fn test_stream_one_byte_chunks() {
+        let src = "cat = 3\ndogs=45\nhé = -12\n";
+        let v: Vec<_> = StreamParser::new(LogLine, Drip(src.as_bytes()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            v,
+            vec![
+                ("cat".to_string(), 3),
+                ("dogs".to_string(), 45),
+                ("hé".to_string(), -12)
+            ]
+        );
+        //The same as parsing it all at once
+        let whole: Vec<_> = LogLine.pull(src).collect::<Result<_, _>>().unwrap();
+        assert_eq!(v, whole);
+    }
+
+    #[test]
+    fn test_stream_drops_parsed_text() {
+        let mut sp = StreamParser::new(LogLine, Drip(b"a=1\nb=2\n")).chunk_size(3);
+        assert_eq!(sp.next().unwrap().unwrap(), ("a".to_string(), 1));
+        assert!(sp.buffered().len() < 3);
+        assert_eq!(sp.next().unwrap().unwrap(), ("b".to_string(), 2));
+        assert!(sp.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_error_positions() {
+        let src = "a=1\nb=2\nc=x\nd=4\n";
+        let mut sp = StreamParser::new(LogLine, Drip(src.as_bytes()));
+        assert!(sp.next().unwrap().is_ok());
+        assert!(sp.next().unwrap().is_ok());
+        let e = match sp.next() {
+            Some(Err(StreamErr::Parse(e))) => e,
+            r => panic!("expected parse error, got {:?}", r.map(|r| r.is_ok())),
+        };
+        let whole = LogLine.pull(src).nth(2).unwrap().unwrap_err();
+        assert_eq!(
+            (e.index, e.line, e.col),
+            (whole.index, whole.line, whole.col)
+        );
+        assert_eq!((e.line, e.col), (2, 2));
+        assert!(sp.next().is_none());
+    }
+
+    /// Counts the reads made of the inner reader
+    struct Counted<'a>(&'a [u8], usize);
+
+    impl<'a> Read for Counted<'a> {
+        fn read(&mut self, b: &mut [u8]) -> std::io::Result<usize> {
+            self.1 += 1;
+            self.0.read(b)
+        }
+    }
+
+    #[test]
+    fn test_stream_long_items_read_in_growing_chunks() {
+        let src = format!("k={}\n", "9".repeat(10_000));
+        let mut r = Counted(src.as_bytes(), 0);
+        let v: Vec<_> = StreamParser::new((Alpha.plus(), "=", NumDigit.plus(), "\n"), &mut r)
+            .chunk_size(8)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(v[0].2.len(), 10_000);
+        //8, 8, 16, 32 .. is about 12 reads, where a read per chunk would be over 1000
+        assert!(r.1 < 20, "{} reads", r.1);
+    }
+
+    #[test]
+    fn test_stream_cols() {
+        let src = "a=1\né😀=x\n";
+        let mut sp = StreamParser::new(LogLine, Drip(src.as_bytes())).with_cols(Cols::utf16());
+        assert!(sp.next().unwrap().is_ok());
+        match sp.next() {
+            Some(Err(StreamErr::Parse(e))) => assert_eq!((e.line, e.col), (1, 4)),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_stream_incomplete_at_end() {
+        //The last line has no newline, so it never completes
+        let mut sp = StreamParser::new(LogLine, Drip(b"a=1\nb=2"));
+        assert!(sp.next().unwrap().is_ok());
+        match sp.next() {
+            Some(Err(StreamErr::Parse(e))) => assert_eq!(e.index, None),
+            _ => panic!("expected an error at the end of input"),
+        }
+
+        assert!(is_incomplete(&common::Int.parse(&LCChars::str("12"))));
+        assert!(!is_incomplete(&common::Int.parse(&LCChars::str("12;"))));
+        assert!(!is_incomplete(&common::Int.parse(&LCChars::str("x"))));
+    }
+}
@@ -1,3 +1,8 @@
+use crate::borrow::Owned;
+use crate::borrow::RefParser;
+use crate::describe::Gram;
+use crate::err::Expected;
+use crate::input::Input;
 use crate::iter::LCChars;
 use crate::ptrait::*;
 
@@ -6,6 +11,8 @@ pub struct String2P<A: Parser, B: Parser> {
     b: B,
 }
 
+impl<A: Parser, B: Parser> Owned for String2P<A, B> {}
+
 impl<A, B> Parser for String2P<A, B>
 where
     A: Parser,
@@ -21,6 +28,12 @@ where
         s.push_str(bv.as_ref());
         Ok((itres, s, c2))
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe(), self.b.describe())
+    }
 }
 
 pub fn strings_plus_until<A: Parser<Out = String>, B: Parser>(
@@ -68,11 +81,19 @@ pub fn do_strings_until<'a, A: Parser<Out = String>, B: Parser>(
     }
 }
 
+impl<A: Parser<Out = String>, B> Owned for StringsPlusUntil<A, B> {}
+
 impl<A: Parser<Out = String>, B: Parser> Parser for StringsPlusUntil<A, B> {
     type Out = (String, B::Out);
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
         do_strings_until(it, &self.a, &self.b, 1)
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        Gram::seq(self.a.describe().plus(), self.b.describe())
+    }
 }
 
 pub fn string_2_parts<A, B>(a: A, b: B) -> String2P<A, B>
@@ -95,6 +116,8 @@ pub struct AsString<A: Parser> {
     a: A,
 }
 
+impl<A: Parser> Owned for AsString<A> {}
+
 impl<A: Parser> Parser for AsString<A> {
     type Out = String;
     fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, String> {
@@ -105,4 +128,34 @@ impl<A: Parser> Parser for AsString<A> {
                 _ => (nit, it.as_str().to_string(), ct),
             })
     }
+    fn expected(&self) -> Expected {
+        self.a.expected()
+    }
+    fn describe(&self) -> Gram {
+        self.a.describe()
+    }
+}
+
+/// Like ```string```, but returns the slice of the input matched by 'a' without copying it.
+/// Works for any input, not only str.
+pub fn string_ref<A>(a: A) -> StrRef<A> {
+    StrRef { a }
+}
+
+pub struct StrRef<A> {
+    a: A,
+}
+
+impl<I: ?Sized + Input, A: RefParser<I>> RefParser<I> for StrRef<A> {
+    type Out<'a>
+        = &'a I
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, &'a I, I> {
+        let (nit, _, ct) = self.a.parse_ref(it)?;
+        Ok((nit.clone(), it.slice_to(&nit), ct))
+    }
+    fn expected_ref(&self) -> Expected {
+        self.a.expected_ref()
+    }
 }
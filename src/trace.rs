@@ -0,0 +1,407 @@
+//! Tracing and profiling of ```parser!``` rules, only built with the "trace" feature.
+//!
+//! While a ```TraceSession``` is alive, every ```parser!``` rule and every ```debug``` parser on
+//! this thread tells its ```TraceSink``` when it starts, and when it finishes with the
+//! position it reached and whether it matched. ```TraceLog``` keeps the events, and prints
+//! them indented by depth, ```Profile``` counts calls, failures and time for each rule, and
+//! ```TracePrint``` writes each event to stderr as it happens.
+//!
+//! Without the feature, this module does not exist and rules do no extra work.
+//!
+//! ```rust
+//! use gobble::*;
+//! parser! { (Num->isize) common::Int }
+//! parser! { (Sum->isize) (Num, star(ws__("+").ig_then(Num))).map(|(a, v)| a + v.iter().sum::<isize>()) }
+//!
+//! let sess = TraceSession::new(Profile::default());
+//! assert_eq!(Sum.parse_s("1 + 2 + x"), Ok(3));
+//! let prof = sess.finish();
+//! assert_eq!(prof.rules["Num"].calls, 3);
+//! assert_eq!(prof.rules["Num"].fails, 1);
+//! assert_eq!(prof.rules["Sum"].calls, 1);
+//! ```
+use crate::input::Input;
+use crate::iter::LCChars;
+use crate::ptrait::ParseRes;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Receives the start and end of each traced rule. 'depth' is how many traced rules are
+/// currently running outside of this one, and 'lc' is the (line, column) from ```LCChars::lc```
+pub trait TraceSink {
+    fn enter(&mut self, rule: &'static str, depth: usize, lc: (usize, usize));
+    /// 'lc' is where the rule finished if 'ok', otherwise where it started.
+    /// 'time' includes the time spent in the rules it called
+    fn exit(
+        &mut self,
+        rule: &'static str,
+        depth: usize,
+        lc: (usize, usize),
+        ok: bool,
+        time: Duration,
+    );
+}
+
+thread_local! {
+    static SINKS: RefCell<Vec<Rc<RefCell<dyn TraceSink>>>> = const { RefCell::new(Vec::new()) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// While alive, traced rules on this thread report to the sink.
+/// Sessions nest, the newest one still alive gets the events. Each session removes only its
+/// own sink when dropped or finished, so they may end in any order
+pub struct TraceSession<S: TraceSink + 'static> {
+    sink: Option<Rc<RefCell<S>>>,
+}
+
+impl<S: TraceSink + 'static> TraceSession<S> {
+    pub fn new(sink: S) -> Self {
+        let sink = Rc::new(RefCell::new(sink));
+        let dyn_sink: Rc<RefCell<dyn TraceSink>> = sink.clone();
+        SINKS.with(|s| s.borrow_mut().push(dyn_sink));
+        TraceSession { sink: Some(sink) }
+    }
+
+    /// Stop tracing and return the sink with everything it recorded
+    pub fn finish(mut self) -> S {
+        let sink = self
+            .sink
+            .take()
+            .expect("a session always has a sink until finished");
+        remove_sink(&sink);
+        match Rc::try_unwrap(sink) {
+            Ok(s) => s.into_inner(),
+            Err(_) => panic!("trace sink still in use"),
+        }
+    }
+}
+
+impl<S: TraceSink + 'static> Drop for TraceSession<S> {
+    fn drop(&mut self) {
+        if let Some(sink) = self.sink.take() {
+            remove_sink(&sink);
+        }
+    }
+}
+
+fn remove_sink<S: TraceSink + 'static>(sink: &Rc<RefCell<S>>) {
+    let sink: Rc<RefCell<dyn TraceSink>> = sink.clone();
+    SINKS.with(|s| s.borrow_mut().retain(|x| !Rc::ptr_eq(x, &sink)));
+}
+
+/// Puts DEPTH back as it was before the rule, even if the rule panics
+struct DepthGuard(usize);
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|d| d.set(self.0));
+    }
+}
+
+/// Runs 'f' as the rule 'rule', reporting it to the current sink if there is one
+pub fn traced<'a, I, V, F>(rule: &'static str, it: &LCChars<'a, I>, f: F) -> ParseRes<'a, V, I>
+where
+    I: ?Sized + Input,
+    F: FnOnce() -> ParseRes<'a, V, I>,
+{
+    let sink = match SINKS.with(|s| s.borrow().last().cloned()) {
+        Some(s) => s,
+        None => return f(),
+    };
+    let depth = DEPTH.with(|d| d.replace(d.get() + 1));
+    let guard = DepthGuard(depth);
+    let lc = it.lc();
+    sink.borrow_mut().enter(rule, depth, lc);
+    let start = Instant::now();
+    let r = f();
+    let time = start.elapsed();
+    drop(guard);
+    let (lc, ok) = match &r {
+        Ok((nit, _, _)) => (nit.lc(), true),
+        Err(_) => (lc, false),
+    };
+    sink.borrow_mut().exit(rule, depth, lc, ok, time);
+    r
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    Enter {
+        rule: &'static str,
+        depth: usize,
+        lc: (usize, usize),
+    },
+    Exit {
+        rule: &'static str,
+        depth: usize,
+        lc: (usize, usize),
+        ok: bool,
+    },
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TraceEvent::Enter { rule, depth, lc } => {
+                write!(f, "{:w$}{} {}:{}", "", rule, lc.0, lc.1, w = depth * 2)
+            }
+            TraceEvent::Exit {
+                rule,
+                depth,
+                lc,
+                ok,
+            } => {
+                let res = if *ok { "ok" } else { "fail" };
+                write!(
+                    f,
+                    "{:w$}{} {} {}:{}",
+                    "",
+                    res,
+                    rule,
+                    lc.0,
+                    lc.1,
+                    w = depth * 2
+                )
+            }
+        }
+    }
+}
+
+/// Keeps every event in order, displayed one per line indented by depth
+#[derive(Debug, Clone, Default)]
+pub struct TraceLog {
+    pub events: Vec<TraceEvent>,
+}
+
+impl TraceSink for TraceLog {
+    fn enter(&mut self, rule: &'static str, depth: usize, lc: (usize, usize)) {
+        self.events.push(TraceEvent::Enter { rule, depth, lc });
+    }
+    fn exit(
+        &mut self,
+        rule: &'static str,
+        depth: usize,
+        lc: (usize, usize),
+        ok: bool,
+        _: Duration,
+    ) {
+        self.events.push(TraceEvent::Exit {
+            rule,
+            depth,
+            lc,
+            ok,
+        });
+    }
+}
+
+impl fmt::Display for TraceLog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for e in &self.events {
+            writeln!(f, "{}", e)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes each event to stderr as it happens
+#[derive(Debug, Clone, Default)]
+pub struct TracePrint;
+
+impl TraceSink for TracePrint {
+    fn enter(&mut self, rule: &'static str, depth: usize, lc: (usize, usize)) {
+        eprintln!("{}", TraceEvent::Enter { rule, depth, lc });
+    }
+    fn exit(
+        &mut self,
+        rule: &'static str,
+        depth: usize,
+        lc: (usize, usize),
+        ok: bool,
+        _: Duration,
+    ) {
+        let e = TraceEvent::Exit {
+            rule,
+            depth,
+            lc,
+            ok,
+        };
+        eprintln!("{}", e);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleStats {
+    pub calls: usize,
+    /// Times the rule failed, leaving its caller to backtrack
+    pub fails: usize,
+    /// Includes the time spent in the rules it called, so the times of a rule and the rules
+    /// it calls overlap, and a rule that calls itself counts the inner calls more than once
+    pub time: Duration,
+    /// Only the time spent in the rule itself, the self times of all rules add up to the
+    /// time spent in traced rules
+    pub self_time: Duration,
+}
+
+/// Totals for each rule, displayed by self time, slowest first
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    pub rules: BTreeMap<&'static str, RuleStats>,
+    /// The time spent in the rules called by each rule still running
+    inner: Vec<Duration>,
+}
+
+impl TraceSink for Profile {
+    fn enter(&mut self, rule: &'static str, _: usize, _: (usize, usize)) {
+        self.rules.entry(rule).or_default().calls += 1;
+        self.inner.push(Duration::ZERO);
+    }
+    fn exit(&mut self, rule: &'static str, _: usize, _: (usize, usize), ok: bool, time: Duration) {
+        let inner = self.inner.pop().unwrap_or_default();
+        if let Some(outer) = self.inner.last_mut() {
+            *outer += time;
+        }
+        let st = self.rules.entry(rule).or_default();
+        st.time += time;
+        st.self_time += time.saturating_sub(inner);
+        if !ok {
+            st.fails += 1;
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut v: Vec<_> = self.rules.iter().collect();
+        v.sort_by_key(|(_, st)| std::cmp::Reverse(st.self_time));
+        for (n, st) in v {
+            writeln!(
+                f,
+                "{}: calls {}, fails {}, self {:?}, time {:?}",
+                n, st.calls, st.fails, st.self_time, st.time
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::time::Duration;
+
+    parser! { (Item->String) or(common::Ident, middle("(", List, ")").map(|v| v.join(" "))) }
+    parser! { (List->Vec<String>) sep_plus(Item, ",") }
+
+    #[test]
+    fn test_trace_log() {
+        let sess = TraceSession::new(TraceLog::default());
+        assert!(List.parse_s("a,(b)").is_ok());
+        let log = sess.finish();
+        assert_eq!(
+            log.to_string(),
+            "List 0:0
+  Item 0:0
+    Ident 0:0
+    ok Ident 0:1
+  ok Item 0:1
+  Item 0:2
+    Ident 0:2
+    fail Ident 0:2
+    List 0:3
+      Item 0:3
+        Ident 0:3
+        ok Ident 0:4
+      ok Item 0:4
+    ok List 0:4
+  ok Item 0:5
+ok List 0:5
+"
+        );
+
+        //only the newest session gets the events
+        let sess = TraceSession::new(TraceLog::default());
+        {
+            let _inner = TraceSession::new(TraceLog::default());
+            assert!(Item.parse_s("(").is_err());
+        }
+        assert!(debug(Alpha.plus(), "dbg").parse_s("x").is_ok());
+        let log = sess.finish();
+        assert_eq!(log.to_string(), "dbg 0:0\nok dbg 0:1\n");
+    }
+
+    parser! { (Boom->()) boom }
+
+    fn boom<'a>(_: &LCChars<'a>) -> ParseRes<'a, ()> {
+        panic!("boom")
+    }
+
+    #[test]
+    fn test_sessions_end_in_any_order() {
+        let outer = TraceSession::new(TraceLog::default());
+        let inner = TraceSession::new(TraceLog::default());
+        //finishing the outer session leaves the inner one getting events
+        assert_eq!(outer.finish().to_string(), "");
+        assert!(common::Ident.parse_s("a").is_ok());
+        let sess = TraceSession::new(TraceLog::default());
+        drop(inner);
+        assert!(common::Ident.parse_s("b").is_ok());
+        assert_eq!(sess.finish().to_string(), "Ident 0:0\nok Ident 0:1\n");
+
+        //the depth is restored when a rule panics
+        let sess = TraceSession::new(TraceLog::default());
+        assert!(std::panic::catch_unwind(|| (Item, Boom).parse_s("a")).is_err());
+        assert!(common::Ident.parse_s("c").is_ok());
+        let log = sess.finish();
+        let n = log.events.len();
+        assert_eq!(
+            &log.events[n - 2..],
+            &[
+                TraceEvent::Enter {
+                    rule: "Ident",
+                    depth: 0,
+                    lc: (0, 0)
+                },
+                TraceEvent::Exit {
+                    rule: "Ident",
+                    depth: 0,
+                    lc: (0, 1),
+                    ok: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_profile_counts() {
+        let sess = TraceSession::new(Profile::default());
+        assert!(List.parse_s("((a,b),c)").is_ok());
+        let prof = sess.finish();
+        let stats = |n| {
+            let st = &prof.rules[n];
+            (st.calls, st.fails)
+        };
+        assert_eq!(stats("List"), (3, 0));
+        assert_eq!(stats("Item"), (5, 0));
+        //Ident is tried first on each bracket
+        assert_eq!(stats("Ident"), (5, 2));
+        assert!(prof.to_string().contains("Ident: calls 5, fails 2, self"));
+    }
+
+    parser! { (Top->Vec<String>) List }
+
+    #[test]
+    fn test_profile_self_time() {
+        let sess = TraceSession::new(Profile::default());
+        assert!(Top.parse_s("((a,b),(c,(d)))").is_ok());
+        let prof = sess.finish();
+        //self times do not overlap, so add up to the time of the outermost rule
+        let total: Duration = prof.rules.values().map(|st| st.self_time).sum();
+        assert_eq!(total, prof.rules["Top"].time);
+        for st in prof.rules.values() {
+            assert!(st.self_time <= st.time);
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //! work
 //! use gobble::traits::*;
 
+pub use crate::borrow::{Owned, RefParser};
 pub use crate::chars::CharBool;
+pub use crate::input::Input;
 pub use crate::iter::LCChars;
 pub use crate::ptrait::Parser;
@@ -1,14 +1,38 @@
+use crate::borrow::RefParser;
+use crate::describe::Gram;
 use crate::err::Expected;
+use crate::input::Input;
 use crate::iter::*;
 use crate::ptrait::*;
 
-impl<A, B> Parser for (A, B)
+impl<I, A, B> RefParser<I> for (A, B)
 where
-    A: Parser,
-    B: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+{
+    type Out<'a>
+        = (A::Out<'a>, B::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (it2, av, c1) = self.0.parse_ref(it)?;
+        let (it3, bv, c2) = self.1.parse_ref(&it2).map_err(|e| e.join_op(c1))?;
+        Ok((it3, (av, bv), c2))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.0.expected_ref(), self.1.expected_ref())
+    }
+}
+
+impl<I, A, B> Parser<I> for (A, B)
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
 {
     type Out = (A::Out, B::Out);
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (it2, av, c1) = self.0.parse(it)?;
         let (it3, bv, c2) = self.1.parse(&it2).map_err(|e| e.join_op(c1))?;
         Ok((it3, (av, bv), c2))
@@ -16,16 +40,42 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.0.expected(), self.1.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::Seq(vec![self.0.describe(), self.1.describe()])
+    }
+}
+
+impl<I, A, B, C> RefParser<I> for (A, B, C)
+where
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+    C: RefParser<I>,
+{
+    type Out<'a>
+        = (A::Out<'a>, B::Out<'a>, C::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (it2, av, c1) = self.0.parse_ref(it)?;
+        let (it3, bv, c2) = self.1.parse_ref(&it2).map_err(|e| e.join_op(c1))?;
+        let (it4, cv, c3) = self.2.parse_ref(&it3).map_err(|e| e.join_op(c2))?;
+        Ok((it4, (av, bv, cv), c3))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.0.expected_ref(), self.1.expected_ref())
+    }
 }
 
-impl<A, B, C> Parser for (A, B, C)
+impl<I, A, B, C> Parser<I> for (A, B, C)
 where
-    A: Parser,
-    B: Parser,
-    C: Parser,
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+    C: Parser<I>,
 {
     type Out = (A::Out, B::Out, C::Out);
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (it2, av, c1) = self.0.parse(it)?;
         let (it3, bv, c2) = self.1.parse(&it2).map_err(|e| e.join_op(c1))?;
         let (it4, cv, c3) = self.2.parse(&it3).map_err(|e| e.join_op(c2))?;
@@ -34,17 +84,49 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.0.expected(), self.1.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::Seq(vec![
+            self.0.describe(),
+            self.1.describe(),
+            self.2.describe(),
+        ])
+    }
+}
+
+impl<I, A, B, C, D> RefParser<I> for (A, B, C, D)
+where
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+    C: RefParser<I>,
+    D: RefParser<I>,
+{
+    type Out<'a>
+        = (A::Out<'a>, B::Out<'a>, C::Out<'a>, D::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (it2, av, c1) = self.0.parse_ref(it)?;
+        let (it3, bv, c2) = self.1.parse_ref(&it2).map_err(|e| e.join_op(c1))?;
+        let (it4, cv, c3) = self.2.parse_ref(&it3).map_err(|e| e.join_op(c2))?;
+        let (it5, dv, c4) = self.3.parse_ref(&it4).map_err(|e| e.join_op(c3))?;
+        Ok((it5, (av, bv, cv, dv), c4))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.0.expected_ref(), self.1.expected_ref())
+    }
 }
 
-impl<A, B, C, D> Parser for (A, B, C, D)
+impl<I, A, B, C, D> Parser<I> for (A, B, C, D)
 where
-    A: Parser,
-    B: Parser,
-    C: Parser,
-    D: Parser,
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+    C: Parser<I>,
+    D: Parser<I>,
 {
     type Out = (A::Out, B::Out, C::Out, D::Out);
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (it2, av, c1) = self.0.parse(it)?;
         let (it3, bv, c2) = self.1.parse(&it2).map_err(|e| e.join_op(c1))?;
         let (it4, cv, c3) = self.2.parse(&it3).map_err(|e| e.join_op(c2))?;
@@ -54,17 +136,52 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.0.expected(), self.1.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::Seq(vec![
+            self.0.describe(),
+            self.1.describe(),
+            self.2.describe(),
+            self.3.describe(),
+        ])
+    }
 }
-impl<A, B, C, D, E> Parser for (A, B, C, D, E)
+impl<I, A, B, C, D, E> RefParser<I> for (A, B, C, D, E)
 where
-    A: Parser,
-    B: Parser,
-    C: Parser,
-    D: Parser,
-    E: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+    C: RefParser<I>,
+    D: RefParser<I>,
+    E: RefParser<I>,
+{
+    type Out<'a>
+        = (A::Out<'a>, B::Out<'a>, C::Out<'a>, D::Out<'a>, E::Out<'a>)
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (it2, av, c1) = self.0.parse_ref(it)?;
+        let (it3, bv, c2) = self.1.parse_ref(&it2).map_err(|e| e.join_op(c1))?;
+        let (it4, cv, c3) = self.2.parse_ref(&it3).map_err(|e| e.join_op(c2))?;
+        let (it5, dv, c4) = self.3.parse_ref(&it4).map_err(|e| e.join_op(c3))?;
+        let (it6, ev, c5) = self.4.parse_ref(&it5).map_err(|e| e.join_op(c4))?;
+        Ok((it6, (av, bv, cv, dv, ev), c5))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.0.expected_ref(), self.1.expected_ref())
+    }
+}
+
+impl<I, A, B, C, D, E> Parser<I> for (A, B, C, D, E)
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+    C: Parser<I>,
+    D: Parser<I>,
+    E: Parser<I>,
 {
     type Out = (A::Out, B::Out, C::Out, D::Out, E::Out);
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (it2, av, c1) = self.0.parse(it)?;
         let (it3, bv, c2) = self.1.parse(&it2).map_err(|e| e.join_op(c1))?;
         let (it4, cv, c3) = self.2.parse(&it3).map_err(|e| e.join_op(c2))?;
@@ -75,18 +192,63 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.0.expected(), self.1.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::Seq(vec![
+            self.0.describe(),
+            self.1.describe(),
+            self.2.describe(),
+            self.3.describe(),
+            self.4.describe(),
+        ])
+    }
 }
-impl<A, B, C, D, E, F> Parser for (A, B, C, D, E, F)
+impl<I, A, B, C, D, E, F> RefParser<I> for (A, B, C, D, E, F)
 where
-    A: Parser,
-    B: Parser,
-    C: Parser,
-    D: Parser,
-    E: Parser,
-    F: Parser,
+    I: ?Sized + Input,
+    A: RefParser<I>,
+    B: RefParser<I>,
+    C: RefParser<I>,
+    D: RefParser<I>,
+    E: RefParser<I>,
+    F: RefParser<I>,
+{
+    type Out<'a>
+        = (
+        A::Out<'a>,
+        B::Out<'a>,
+        C::Out<'a>,
+        D::Out<'a>,
+        E::Out<'a>,
+        F::Out<'a>,
+    )
+    where
+        I: 'a;
+    fn parse_ref<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out<'a>, I> {
+        let (it2, av, c1) = self.0.parse_ref(it)?;
+        let (it3, bv, c2) = self.1.parse_ref(&it2).map_err(|e| e.join_op(c1))?;
+        let (it4, cv, c3) = self.2.parse_ref(&it3).map_err(|e| e.join_op(c2))?;
+        let (it5, dv, c4) = self.3.parse_ref(&it4).map_err(|e| e.join_op(c3))?;
+        let (it6, ev, c5) = self.4.parse_ref(&it5).map_err(|e| e.join_op(c4))?;
+        let (it7, fv, c6) = self.5.parse_ref(&it6).map_err(|e| e.join_op(c5))?;
+        Ok((it7, (av, bv, cv, dv, ev, fv), c6))
+    }
+    fn expected_ref(&self) -> Expected {
+        Expected::first(self.0.expected_ref(), self.1.expected_ref())
+    }
+}
+
+impl<I, A, B, C, D, E, F> Parser<I> for (A, B, C, D, E, F)
+where
+    I: ?Sized + Input,
+    A: Parser<I>,
+    B: Parser<I>,
+    C: Parser<I>,
+    D: Parser<I>,
+    E: Parser<I>,
+    F: Parser<I>,
 {
     type Out = (A::Out, B::Out, C::Out, D::Out, E::Out, F::Out);
-    fn parse<'a>(&self, it: &LCChars<'a>) -> ParseRes<'a, Self::Out> {
+    fn parse<'a>(&self, it: &LCChars<'a, I>) -> ParseRes<'a, Self::Out, I> {
         let (it2, av, c1) = self.0.parse(it)?;
         let (it3, bv, c2) = self.1.parse(&it2).map_err(|e| e.join_op(c1))?;
         let (it4, cv, c3) = self.2.parse(&it3).map_err(|e| e.join_op(c2))?;
@@ -98,78 +260,57 @@ where
     fn expected(&self) -> Expected {
         Expected::first(self.0.expected(), self.1.expected())
     }
+    fn describe(&self) -> Gram {
+        Gram::Seq(vec![
+            self.0.describe(),
+            self.1.describe(),
+            self.2.describe(),
+            self.3.describe(),
+            self.4.describe(),
+            self.5.describe(),
+        ])
+    }
 }
 
-pub fn first<A, B>(a: A, b: B) -> impl Parser<Out = A::Out>
-where
-    A: Parser,
-    B: Parser,
-{
-    a.then_ig(b)
+pub fn first<A, B>(a: A, b: B) -> ThenIg<A, B> {
+    ThenIg { a, b }
 }
-pub fn last<A, B>(a: A, b: B) -> impl Parser<Out = B::Out>
-where
-    A: Parser,
-    B: Parser,
-{
-    a.ig_then(b)
+
+pub fn last<A, B>(a: A, b: B) -> IgThen<A, B> {
+    IgThen { a, b }
 }
 
-pub fn middle<A, B, C>(a: A, b: B, c: C) -> impl Parser<Out = B::Out>
-where
-    A: Parser,
-    B: Parser,
-    C: Parser,
-{
-    a.ig_then(b).then_ig(c)
+pub fn middle<A, B, C>(a: A, b: B, c: C) -> ThenIg<IgThen<A, B>, C> {
+    first(last(a, b), c)
 }
 
-pub fn or<A, B, V>(a: A, b: B) -> impl Parser<Out = V>
-where
-    A: Parser<Out = V>,
-    B: Parser<Out = V>,
-{
-    a.or(b)
+pub fn or<A, B>(a: A, b: B) -> Or<A, B> {
+    Or { a, b }
 }
 
 /// While you can use the numbered 'or's you may find the 'or!' macro helpful as that works with
 /// any number of options without needing to count, and returns the exact same result
-pub fn or3<A, B, C, V>(a: A, b: B, c: C) -> impl Parser<Out = V>
-where
-    A: Parser<Out = V>,
-    B: Parser<Out = V>,
-    C: Parser<Out = V>,
-{
-    a.or(b).or(c)
+pub fn or3<A, B, C>(a: A, b: B, c: C) -> Or<Or<A, B>, C> {
+    or(or(a, b), c)
 }
 
-pub fn or4<A, B, C, D, V>(a: A, b: B, c: C, d: D) -> impl Parser<Out = V>
-where
-    A: Parser<Out = V>,
-    B: Parser<Out = V>,
-    C: Parser<Out = V>,
-    D: Parser<Out = V>,
-{
-    a.or(b).or(c).or(d)
+pub fn or4<A, B, C, D>(a: A, b: B, c: C, d: D) -> Or<Or<Or<A, B>, C>, D> {
+    or(or3(a, b, c), d)
 }
-pub fn or5<A, B, C, D, E, V>(a: A, b: B, c: C, d: D, e: E) -> impl Parser<Out = V>
-where
-    A: Parser<Out = V>,
-    B: Parser<Out = V>,
-    C: Parser<Out = V>,
-    D: Parser<Out = V>,
-    E: Parser<Out = V>,
-{
-    a.or(b).or(c).or(d).or(e)
+
+#[allow(clippy::type_complexity)]
+pub fn or5<A, B, C, D, E>(a: A, b: B, c: C, d: D, e: E) -> Or<Or<Or<Or<A, B>, C>, D>, E> {
+    or(or4(a, b, c, d), e)
 }
-pub fn or6<A, B, C, D, E, F, V>(a: A, b: B, c: C, d: D, e: E, f: F) -> impl Parser<Out = V>
-where
-    A: Parser<Out = V>,
-    B: Parser<Out = V>,
-    C: Parser<Out = V>,
-    D: Parser<Out = V>,
-    E: Parser<Out = V>,
-    F: Parser<Out = V>,
-{
-    a.or(b).or(c).or(d).or(e).or(f)
+
+#[allow(clippy::type_complexity)]
+pub fn or6<A, B, C, D, E, F>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+) -> Or<Or<Or<Or<Or<A, B>, C>, D>, E>, F> {
+    or(or5(a, b, c, d, e), f)
 }
@@ -0,0 +1,174 @@
+//! Unicode aware character classes.
+//!
+//! The classes in ```chars``` only know ASCII, so ```common::Ident``` will not accept
+//! "café" or "变量". The classes here follow Unicode instead: ```UAlpha```, ```UNum```,
+//! ```UWS``` and ```UWSL``` use the std char methods, while ```XidStart```, ```XidContinue```,
+//! every general category (```Cat```) and every ```Script``` are looked up in tables built
+//! from the Unicode data, see "unicode/gen_tables.pl".
+//!
+//! The tables are Unicode 14.0.0 (```UNICODE_VERSION```), the version bundled with perl,
+//! while the std methods follow ```char::UNICODE_VERSION```, which may be newer.
+//! So a char added since 14.0.0 can be ```UAlpha``` while its category is still ```Cat::Cn```.
+//!
+//! ```rust
+//! use gobble::*;
+//! use gobble::common::UIdent;
+//! assert_eq!(UIdent.parse_s("变量 = 3"), Ok("变量".to_string()));
+//! assert_eq!(UIdent.parse_s("café_2"), Ok("café_2".to_string()));
+//! assert!(UIdent.parse_s("2x").is_err());
+//!
+//! assert_eq!(Script::Greek.plus().parse_s("λόγοςxyz"), Ok("λόγος".to_string()));
+//! assert_eq!((Cat::Lu, Cat::Lt).plus().parse_s("ÀÉb"), Ok("ÀÉ".to_string()));
+//! assert_eq!(general_category('€'), Cat::Sc);
+//! ```
+mod tables;
+
+use crate::chars::CharBool;
+use crate::err::Expected;
+pub use tables::{Cat, Script, UNICODE_VERSION};
+
+fn in_ranges(ranges: &[(u32, u32)], c: char) -> bool {
+    let c = c as u32;
+    let i = ranges.partition_point(|(a, _)| *a <= c);
+    i > 0 && c <= ranges[i - 1].1
+}
+
+/// The value of the last entry starting at or before 'c', tables always start at 0
+fn in_map<V: Copy>(map: &[(u32, V)], c: char) -> V {
+    let c = c as u32;
+    let i = map.partition_point(|(a, _)| *a <= c);
+    map[i - 1].1
+}
+
+pub fn is_xid_start(c: char) -> bool {
+    in_ranges(tables::XID_START, c)
+}
+
+pub fn is_xid_continue(c: char) -> bool {
+    in_ranges(tables::XID_CONTINUE, c)
+}
+
+pub fn general_category(c: char) -> Cat {
+    in_map(tables::CATEGORIES, c)
+}
+
+pub fn script(c: char) -> Script {
+    in_map(tables::SCRIPTS, c)
+}
+
+/// Whitespace that does not end a line
+pub fn is_uws(c: char) -> bool {
+    c.is_whitespace() && !"\n\r\u{b}\u{c}\u{85}\u{2028}\u{2029}".contains(c)
+}
+
+char_bool!(UAlpha, char::is_alphabetic);
+char_bool!(UNum, char::is_numeric);
+char_bool!(UWS, is_uws);
+char_bool!(UWSL, char::is_whitespace);
+char_bool!(XidStart, is_xid_start);
+char_bool!(XidContinue, is_xid_continue);
+
+fn is_regional(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+/// The Hangul_Syllable_Type values
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq)]
+enum Jamo {
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+}
+
+fn jamo(c: char) -> Option<Jamo> {
+    match c as u32 {
+        0x1100..=0x115F | 0xA960..=0xA97C => Some(Jamo::L),
+        0x1160..=0x11A7 | 0xD7B0..=0xD7C6 => Some(Jamo::V),
+        0x11A8..=0x11FF | 0xD7CB..=0xD7FB => Some(Jamo::T),
+        n @ 0xAC00..=0xD7A3 if (n - 0xAC00) % 28 == 0 => Some(Jamo::LV),
+        0xAC00..=0xD7A3 => Some(Jamo::LVT),
+        _ => None,
+    }
+}
+
+/// Does the grapheme cluster holding 'c' carry on into 'rest'.
+/// Follows the Unicode rules for extended grapheme clusters as far as looking ahead allows:
+/// marks, emoji modifiers and joiners extend a cluster, a joiner joins whatever follows it,
+/// Hangul jamo join into syllables, and regional indicators pair up from the end of a run
+pub(crate) fn grapheme_joins(c: char, rest: &str) -> bool {
+    let n = match rest.chars().next() {
+        Some(n) => n,
+        None => return false,
+    };
+    if n == '\u{200d}'
+        || ('\u{1f3fb}'..='\u{1f3ff}').contains(&n)
+        || matches!(general_category(n), Cat::Mn | Cat::Me | Cat::Mc)
+    {
+        return true;
+    }
+    if c == '\u{200d}' {
+        return true;
+    }
+    if is_regional(c) && is_regional(n) {
+        return rest.chars().take_while(|r| is_regional(*r)).count() % 2 == 1;
+    }
+    use Jamo::*;
+    matches!(
+        (jamo(c), jamo(n)),
+        (Some(L), Some(L | V | LV | LVT)) | (Some(LV | V), Some(V | T)) | (Some(LVT | T), Some(T))
+    )
+}
+
+impl CharBool for Cat {
+    fn char_bool(&self, c: char) -> bool {
+        general_category(c) == *self
+    }
+    fn expected(&self) -> Expected {
+        Expected::CharIn(self.name())
+    }
+}
+
+impl CharBool for Script {
+    fn char_bool(&self, c: char) -> bool {
+        script(c) == *self
+    }
+    fn expected(&self) -> Expected {
+        Expected::CharIn(self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_unicode_classes() {
+        assert!(UAlpha.char_bool('é') && !Alpha.char_bool('é'));
+        assert!(UNum.char_bool('٣') && !NumDigit.char_bool('٣'));
+        assert!(UWS.char_bool('\u{3000}') && !UWS.char_bool('\u{2028}'));
+        assert!(UWSL.char_bool('\u{2028}'));
+        assert!(XidContinue.char_bool('\u{300}') && !XidStart.char_bool('\u{300}'));
+        assert_eq!(general_category('a'), Cat::Ll);
+        assert_eq!(general_category('\u{10FFFF}'), Cat::Cn);
+        assert_eq!(script('a'), Script::Latin);
+        assert_eq!(script('ж'), Script::Cyrillic);
+        assert_eq!(script('1'), Script::Common);
+        assert_eq!(Script::OldItalic.name(), "Old_Italic");
+
+        //the tables may be older than std, a letter added since is still unassigned there
+        assert_eq!(UNICODE_VERSION, (14, 0, 0));
+        assert_eq!(general_category('\u{1e4d0}'), Cat::Cn);
+        assert!(UAlpha.char_bool('\u{1e4d0}'));
+        assert!(UNum.char_bool('½') && UNum.char_bool('Ⅻ'));
+
+        //ascii classes are unchanged
+        assert_eq!(common::Ident.parse_s("café"), Ok("caf".to_string()));
+        assert_eq!(
+            common::UIdent.parse_s("_x\u{301}1 "),
+            Ok("_x\u{301}1".to_string())
+        );
+    }
+}
@@ -0,0 +1,58 @@
+#!/usr/bin/env perl
+# Writes tables.rs from the Unicode data bundled with perl:
+#   perl src/unicode/gen_tables.pl > src/unicode/tables.rs && cargo fmt
+use strict;
+use warnings;
+use Unicode::UCD qw(prop_invlist prop_invmap prop_value_aliases);
+
+my $ver = Unicode::UCD::UnicodeVersion();
+print "//! Generated by gen_tables.pl from Unicode $ver, do not edit by hand\n\n";
+my @ver = split /\./, $ver;
+print "/// The version of Unicode the tables were built from\n";
+print "pub const UNICODE_VERSION: (u8, u8, u8) = (", join(", ", @ver), ");\n\n";
+
+for my $prop ("XID_Start", "XID_Continue") {
+    my @inv = prop_invlist($prop);
+    print "pub const ", uc($prop), ": &[(u32, u32)] = &[\n";
+    for (my $i = 0; $i < @inv; $i += 2) {
+        my $end = $i + 1 < @inv ? $inv[$i + 1] - 1 : 0x10FFFF;
+        printf "    (0x%X, 0x%X),\n", $inv[$i], $end;
+    }
+    print "];\n\n";
+}
+
+sub enum_table {
+    my ($prop, $enum, $table, $variant, $doc) = @_;
+    my ($list, $map) = prop_invmap($prop);
+    my %seen;
+    my @names = sort grep { !$seen{$_}++ } @$map;
+    print "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]\n";
+    print "pub enum $enum {\n";
+    for my $n (@names) {
+        my $d = $doc->($n);
+        print "    /// $d\n" if $d;
+        print "    ", $variant->($n), ",\n";
+    }
+    print "}\n\n";
+    print "impl $enum {\n";
+    print "    /// The name as written in the Unicode data files\n";
+    print "    pub fn name(self) -> &'static str {\n        match self {\n";
+    print "            $enum\::", $variant->($_), " => \"$_\",\n" for @names;
+    print "        }\n    }\n}\n\n";
+    print "pub const $table: &[(u32, $enum)] = &[\n";
+    for my $i (0 .. $#$list) {
+        printf "    (0x%X, %s::%s),\n", $list->[$i], $enum, $variant->($map->[$i]);
+    }
+    print "];\n\n";
+}
+
+enum_table(
+    "General_Category", "Cat", "CATEGORIES",
+    sub { $_[0] },
+    sub { (prop_value_aliases("gc", $_[0]))[1] },
+);
+enum_table(
+    "Script", "Script", "SCRIPTS",
+    sub { my $n = $_[0]; $n =~ s/_//g; $n },
+    sub { undef },
+);
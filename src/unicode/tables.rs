@@ -0,0 +1,7480 @@
+//! Generated by gen_tables.pl from Unicode 14.0.0, do not edit by hand
+
+/// The version of Unicode the tables were built from
+pub const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+
+pub const XID_START: &[(u32, u32)] = &[
+    (0x41, 0x5A),
+    (0x61, 0x7A),
+    (0xAA, 0xAA),
+    (0xB5, 0xB5),
+    (0xBA, 0xBA),
+    (0xC0, 0xD6),
+    (0xD8, 0xF6),
+    (0xF8, 0x2C1),
+    (0x2C6, 0x2D1),
+    (0x2E0, 0x2E4),
+    (0x2EC, 0x2EC),
+    (0x2EE, 0x2EE),
+    (0x370, 0x374),
+    (0x376, 0x377),
+    (0x37B, 0x37D),
+    (0x37F, 0x37F),
+    (0x386, 0x386),
+    (0x388, 0x38A),
+    (0x38C, 0x38C),
+    (0x38E, 0x3A1),
+    (0x3A3, 0x3F5),
+    (0x3F7, 0x481),
+    (0x48A, 0x52F),
+    (0x531, 0x556),
+    (0x559, 0x559),
+    (0x560, 0x588),
+    (0x5D0, 0x5EA),
+    (0x5EF, 0x5F2),
+    (0x620, 0x64A),
+    (0x66E, 0x66F),
+    (0x671, 0x6D3),
+    (0x6D5, 0x6D5),
+    (0x6E5, 0x6E6),
+    (0x6EE, 0x6EF),
+    (0x6FA, 0x6FC),
+    (0x6FF, 0x6FF),
+    (0x710, 0x710),
+    (0x712, 0x72F),
+    (0x74D, 0x7A5),
+    (0x7B1, 0x7B1),
+    (0x7CA, 0x7EA),
+    (0x7F4, 0x7F5),
+    (0x7FA, 0x7FA),
+    (0x800, 0x815),
+    (0x81A, 0x81A),
+    (0x824, 0x824),
+    (0x828, 0x828),
+    (0x840, 0x858),
+    (0x860, 0x86A),
+    (0x870, 0x887),
+    (0x889, 0x88E),
+    (0x8A0, 0x8C9),
+    (0x904, 0x939),
+    (0x93D, 0x93D),
+    (0x950, 0x950),
+    (0x958, 0x961),
+    (0x971, 0x980),
+    (0x985, 0x98C),
+    (0x98F, 0x990),
+    (0x993, 0x9A8),
+    (0x9AA, 0x9B0),
+    (0x9B2, 0x9B2),
+    (0x9B6, 0x9B9),
+    (0x9BD, 0x9BD),
+    (0x9CE, 0x9CE),
+    (0x9DC, 0x9DD),
+    (0x9DF, 0x9E1),
+    (0x9F0, 0x9F1),
+    (0x9FC, 0x9FC),
+    (0xA05, 0xA0A),
+    (0xA0F, 0xA10),
+    (0xA13, 0xA28),
+    (0xA2A, 0xA30),
+    (0xA32, 0xA33),
+    (0xA35, 0xA36),
+    (0xA38, 0xA39),
+    (0xA59, 0xA5C),
+    (0xA5E, 0xA5E),
+    (0xA72, 0xA74),
+    (0xA85, 0xA8D),
+    (0xA8F, 0xA91),
+    (0xA93, 0xAA8),
+    (0xAAA, 0xAB0),
+    (0xAB2, 0xAB3),
+    (0xAB5, 0xAB9),
+    (0xABD, 0xABD),
+    (0xAD0, 0xAD0),
+    (0xAE0, 0xAE1),
+    (0xAF9, 0xAF9),
+    (0xB05, 0xB0C),
+    (0xB0F, 0xB10),
+    (0xB13, 0xB28),
+    (0xB2A, 0xB30),
+    (0xB32, 0xB33),
+    (0xB35, 0xB39),
+    (0xB3D, 0xB3D),
+    (0xB5C, 0xB5D),
+    (0xB5F, 0xB61),
+    (0xB71, 0xB71),
+    (0xB83, 0xB83),
+    (0xB85, 0xB8A),
+    (0xB8E, 0xB90),
+    (0xB92, 0xB95),
+    (0xB99, 0xB9A),
+    (0xB9C, 0xB9C),
+    (0xB9E, 0xB9F),
+    (0xBA3, 0xBA4),
+    (0xBA8, 0xBAA),
+    (0xBAE, 0xBB9),
+    (0xBD0, 0xBD0),
+    (0xC05, 0xC0C),
+    (0xC0E, 0xC10),
+    (0xC12, 0xC28),
+    (0xC2A, 0xC39),
+    (0xC3D, 0xC3D),
+    (0xC58, 0xC5A),
+    (0xC5D, 0xC5D),
+    (0xC60, 0xC61),
+    (0xC80, 0xC80),
+    (0xC85, 0xC8C),
+    (0xC8E, 0xC90),
+    (0xC92, 0xCA8),
+    (0xCAA, 0xCB3),
+    (0xCB5, 0xCB9),
+    (0xCBD, 0xCBD),
+    (0xCDD, 0xCDE),
+    (0xCE0, 0xCE1),
+    (0xCF1, 0xCF2),
+    (0xD04, 0xD0C),
+    (0xD0E, 0xD10),
+    (0xD12, 0xD3A),
+    (0xD3D, 0xD3D),
+    (0xD4E, 0xD4E),
+    (0xD54, 0xD56),
+    (0xD5F, 0xD61),
+    (0xD7A, 0xD7F),
+    (0xD85, 0xD96),
+    (0xD9A, 0xDB1),
+    (0xDB3, 0xDBB),
+    (0xDBD, 0xDBD),
+    (0xDC0, 0xDC6),
+    (0xE01, 0xE30),
+    (0xE32, 0xE32),
+    (0xE40, 0xE46),
+    (0xE81, 0xE82),
+    (0xE84, 0xE84),
+    (0xE86, 0xE8A),
+    (0xE8C, 0xEA3),
+    (0xEA5, 0xEA5),
+    (0xEA7, 0xEB0),
+    (0xEB2, 0xEB2),
+    (0xEBD, 0xEBD),
+    (0xEC0, 0xEC4),
+    (0xEC6, 0xEC6),
+    (0xEDC, 0xEDF),
+    (0xF00, 0xF00),
+    (0xF40, 0xF47),
+    (0xF49, 0xF6C),
+    (0xF88, 0xF8C),
+    (0x1000, 0x102A),
+    (0x103F, 0x103F),
+    (0x1050, 0x1055),
+    (0x105A, 0x105D),
+    (0x1061, 0x1061),
+    (0x1065, 0x1066),
+    (0x106E, 0x1070),
+    (0x1075, 0x1081),
+    (0x108E, 0x108E),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x10D0, 0x10FA),
+    (0x10FC, 0x1248),
+    (0x124A, 0x124D),
+    (0x1250, 0x1256),
+    (0x1258, 0x1258),
+    (0x125A, 0x125D),
+    (0x1260, 0x1288),
+    (0x128A, 0x128D),
+    (0x1290, 0x12B0),
+    (0x12B2, 0x12B5),
+    (0x12B8, 0x12BE),
+    (0x12C0, 0x12C0),
+    (0x12C2, 0x12C5),
+    (0x12C8, 0x12D6),
+    (0x12D8, 0x1310),
+    (0x1312, 0x1315),
+    (0x1318, 0x135A),
+    (0x1380, 0x138F),
+    (0x13A0, 0x13F5),
+    (0x13F8, 0x13FD),
+    (0x1401, 0x166C),
+    (0x166F, 0x167F),
+    (0x1681, 0x169A),
+    (0x16A0, 0x16EA),
+    (0x16EE, 0x16F8),
+    (0x1700, 0x1711),
+    (0x171F, 0x1731),
+    (0x1740, 0x1751),
+    (0x1760, 0x176C),
+    (0x176E, 0x1770),
+    (0x1780, 0x17B3),
+    (0x17D7, 0x17D7),
+    (0x17DC, 0x17DC),
+    (0x1820, 0x1878),
+    (0x1880, 0x18A8),
+    (0x18AA, 0x18AA),
+    (0x18B0, 0x18F5),
+    (0x1900, 0x191E),
+    (0x1950, 0x196D),
+    (0x1970, 0x1974),
+    (0x1980, 0x19AB),
+    (0x19B0, 0x19C9),
+    (0x1A00, 0x1A16),
+    (0x1A20, 0x1A54),
+    (0x1AA7, 0x1AA7),
+    (0x1B05, 0x1B33),
+    (0x1B45, 0x1B4C),
+    (0x1B83, 0x1BA0),
+    (0x1BAE, 0x1BAF),
+    (0x1BBA, 0x1BE5),
+    (0x1C00, 0x1C23),
+    (0x1C4D, 0x1C4F),
+    (0x1C5A, 0x1C7D),
+    (0x1C80, 0x1C88),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1CE9, 0x1CEC),
+    (0x1CEE, 0x1CF3),
+    (0x1CF5, 0x1CF6),
+    (0x1CFA, 0x1CFA),
+    (0x1D00, 0x1DBF),
+    (0x1E00, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FE0, 0x1FEC),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFC),
+    (0x2071, 0x2071),
+    (0x207F, 0x207F),
+    (0x2090, 0x209C),
+    (0x2102, 0x2102),
+    (0x2107, 0x2107),
+    (0x210A, 0x2113),
+    (0x2115, 0x2115),
+    (0x2118, 0x211D),
+    (0x2124, 0x2124),
+    (0x2126, 0x2126),
+    (0x2128, 0x2128),
+    (0x212A, 0x2139),
+    (0x213C, 0x213F),
+    (0x2145, 0x2149),
+    (0x214E, 0x214E),
+    (0x2160, 0x2188),
+    (0x2C00, 0x2CE4),
+    (0x2CEB, 0x2CEE),
+    (0x2CF2, 0x2CF3),
+    (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27),
+    (0x2D2D, 0x2D2D),
+    (0x2D30, 0x2D67),
+    (0x2D6F, 0x2D6F),
+    (0x2D80, 0x2D96),
+    (0x2DA0, 0x2DA6),
+    (0x2DA8, 0x2DAE),
+    (0x2DB0, 0x2DB6),
+    (0x2DB8, 0x2DBE),
+    (0x2DC0, 0x2DC6),
+    (0x2DC8, 0x2DCE),
+    (0x2DD0, 0x2DD6),
+    (0x2DD8, 0x2DDE),
+    (0x3005, 0x3007),
+    (0x3021, 0x3029),
+    (0x3031, 0x3035),
+    (0x3038, 0x303C),
+    (0x3041, 0x3096),
+    (0x309D, 0x309F),
+    (0x30A1, 0x30FA),
+    (0x30FC, 0x30FF),
+    (0x3105, 0x312F),
+    (0x3131, 0x318E),
+    (0x31A0, 0x31BF),
+    (0x31F0, 0x31FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0xA48C),
+    (0xA4D0, 0xA4FD),
+    (0xA500, 0xA60C),
+    (0xA610, 0xA61F),
+    (0xA62A, 0xA62B),
+    (0xA640, 0xA66E),
+    (0xA67F, 0xA69D),
+    (0xA6A0, 0xA6EF),
+    (0xA717, 0xA71F),
+    (0xA722, 0xA788),
+    (0xA78B, 0xA7CA),
+    (0xA7D0, 0xA7D1),
+    (0xA7D3, 0xA7D3),
+    (0xA7D5, 0xA7D9),
+    (0xA7F2, 0xA801),
+    (0xA803, 0xA805),
+    (0xA807, 0xA80A),
+    (0xA80C, 0xA822),
+    (0xA840, 0xA873),
+    (0xA882, 0xA8B3),
+    (0xA8F2, 0xA8F7),
+    (0xA8FB, 0xA8FB),
+    (0xA8FD, 0xA8FE),
+    (0xA90A, 0xA925),
+    (0xA930, 0xA946),
+    (0xA960, 0xA97C),
+    (0xA984, 0xA9B2),
+    (0xA9CF, 0xA9CF),
+    (0xA9E0, 0xA9E4),
+    (0xA9E6, 0xA9EF),
+    (0xA9FA, 0xA9FE),
+    (0xAA00, 0xAA28),
+    (0xAA40, 0xAA42),
+    (0xAA44, 0xAA4B),
+    (0xAA60, 0xAA76),
+    (0xAA7A, 0xAA7A),
+    (0xAA7E, 0xAAAF),
+    (0xAAB1, 0xAAB1),
+    (0xAAB5, 0xAAB6),
+    (0xAAB9, 0xAABD),
+    (0xAAC0, 0xAAC0),
+    (0xAAC2, 0xAAC2),
+    (0xAADB, 0xAADD),
+    (0xAAE0, 0xAAEA),
+    (0xAAF2, 0xAAF4),
+    (0xAB01, 0xAB06),
+    (0xAB09, 0xAB0E),
+    (0xAB11, 0xAB16),
+    (0xAB20, 0xAB26),
+    (0xAB28, 0xAB2E),
+    (0xAB30, 0xAB5A),
+    (0xAB5C, 0xAB69),
+    (0xAB70, 0xABE2),
+    (0xAC00, 0xD7A3),
+    (0xD7B0, 0xD7C6),
+    (0xD7CB, 0xD7FB),
+    (0xF900, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFB1D, 0xFB1D),
+    (0xFB1F, 0xFB28),
+    (0xFB2A, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFBB1),
+    (0xFBD3, 0xFC5D),
+    (0xFC64, 0xFD3D),
+    (0xFD50, 0xFD8F),
+    (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDF9),
+    (0xFE71, 0xFE71),
+    (0xFE73, 0xFE73),
+    (0xFE77, 0xFE77),
+    (0xFE79, 0xFE79),
+    (0xFE7B, 0xFE7B),
+    (0xFE7D, 0xFE7D),
+    (0xFE7F, 0xFEFC),
+    (0xFF21, 0xFF3A),
+    (0xFF41, 0xFF5A),
+    (0xFF66, 0xFF9D),
+    (0xFFA0, 0xFFBE),
+    (0xFFC2, 0xFFC7),
+    (0xFFCA, 0xFFCF),
+    (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC),
+    (0x10000, 0x1000B),
+    (0x1000D, 0x10026),
+    (0x10028, 0x1003A),
+    (0x1003C, 0x1003D),
+    (0x1003F, 0x1004D),
+    (0x10050, 0x1005D),
+    (0x10080, 0x100FA),
+    (0x10140, 0x10174),
+    (0x10280, 0x1029C),
+    (0x102A0, 0x102D0),
+    (0x10300, 0x1031F),
+    (0x1032D, 0x1034A),
+    (0x10350, 0x10375),
+    (0x10380, 0x1039D),
+    (0x103A0, 0x103C3),
+    (0x103C8, 0x103CF),
+    (0x103D1, 0x103D5),
+    (0x10400, 0x1049D),
+    (0x104B0, 0x104D3),
+    (0x104D8, 0x104FB),
+    (0x10500, 0x10527),
+    (0x10530, 0x10563),
+    (0x10570, 0x1057A),
+    (0x1057C, 0x1058A),
+    (0x1058C, 0x10592),
+    (0x10594, 0x10595),
+    (0x10597, 0x105A1),
+    (0x105A3, 0x105B1),
+    (0x105B3, 0x105B9),
+    (0x105BB, 0x105BC),
+    (0x10600, 0x10736),
+    (0x10740, 0x10755),
+    (0x10760, 0x10767),
+    (0x10780, 0x10785),
+    (0x10787, 0x107B0),
+    (0x107B2, 0x107BA),
+    (0x10800, 0x10805),
+    (0x10808, 0x10808),
+    (0x1080A, 0x10835),
+    (0x10837, 0x10838),
+    (0x1083C, 0x1083C),
+    (0x1083F, 0x10855),
+    (0x10860, 0x10876),
+    (0x10880, 0x1089E),
+    (0x108E0, 0x108F2),
+    (0x108F4, 0x108F5),
+    (0x10900, 0x10915),
+    (0x10920, 0x10939),
+    (0x10980, 0x109B7),
+    (0x109BE, 0x109BF),
+    (0x10A00, 0x10A00),
+    (0x10A10, 0x10A13),
+    (0x10A15, 0x10A17),
+    (0x10A19, 0x10A35),
+    (0x10A60, 0x10A7C),
+    (0x10A80, 0x10A9C),
+    (0x10AC0, 0x10AC7),
+    (0x10AC9, 0x10AE4),
+    (0x10B00, 0x10B35),
+    (0x10B40, 0x10B55),
+    (0x10B60, 0x10B72),
+    (0x10B80, 0x10B91),
+    (0x10C00, 0x10C48),
+    (0x10C80, 0x10CB2),
+    (0x10CC0, 0x10CF2),
+    (0x10D00, 0x10D23),
+    (0x10E80, 0x10EA9),
+    (0x10EB0, 0x10EB1),
+    (0x10F00, 0x10F1C),
+    (0x10F27, 0x10F27),
+    (0x10F30, 0x10F45),
+    (0x10F70, 0x10F81),
+    (0x10FB0, 0x10FC4),
+    (0x10FE0, 0x10FF6),
+    (0x11003, 0x11037),
+    (0x11071, 0x11072),
+    (0x11075, 0x11075),
+    (0x11083, 0x110AF),
+    (0x110D0, 0x110E8),
+    (0x11103, 0x11126),
+    (0x11144, 0x11144),
+    (0x11147, 0x11147),
+    (0x11150, 0x11172),
+    (0x11176, 0x11176),
+    (0x11183, 0x111B2),
+    (0x111C1, 0x111C4),
+    (0x111DA, 0x111DA),
+    (0x111DC, 0x111DC),
+    (0x11200, 0x11211),
+    (0x11213, 0x1122B),
+    (0x11280, 0x11286),
+    (0x11288, 0x11288),
+    (0x1128A, 0x1128D),
+    (0x1128F, 0x1129D),
+    (0x1129F, 0x112A8),
+    (0x112B0, 0x112DE),
+    (0x11305, 0x1130C),
+    (0x1130F, 0x11310),
+    (0x11313, 0x11328),
+    (0x1132A, 0x11330),
+    (0x11332, 0x11333),
+    (0x11335, 0x11339),
+    (0x1133D, 0x1133D),
+    (0x11350, 0x11350),
+    (0x1135D, 0x11361),
+    (0x11400, 0x11434),
+    (0x11447, 0x1144A),
+    (0x1145F, 0x11461),
+    (0x11480, 0x114AF),
+    (0x114C4, 0x114C5),
+    (0x114C7, 0x114C7),
+    (0x11580, 0x115AE),
+    (0x115D8, 0x115DB),
+    (0x11600, 0x1162F),
+    (0x11644, 0x11644),
+    (0x11680, 0x116AA),
+    (0x116B8, 0x116B8),
+    (0x11700, 0x1171A),
+    (0x11740, 0x11746),
+    (0x11800, 0x1182B),
+    (0x118A0, 0x118DF),
+    (0x118FF, 0x11906),
+    (0x11909, 0x11909),
+    (0x1190C, 0x11913),
+    (0x11915, 0x11916),
+    (0x11918, 0x1192F),
+    (0x1193F, 0x1193F),
+    (0x11941, 0x11941),
+    (0x119A0, 0x119A7),
+    (0x119AA, 0x119D0),
+    (0x119E1, 0x119E1),
+    (0x119E3, 0x119E3),
+    (0x11A00, 0x11A00),
+    (0x11A0B, 0x11A32),
+    (0x11A3A, 0x11A3A),
+    (0x11A50, 0x11A50),
+    (0x11A5C, 0x11A89),
+    (0x11A9D, 0x11A9D),
+    (0x11AB0, 0x11AF8),
+    (0x11C00, 0x11C08),
+    (0x11C0A, 0x11C2E),
+    (0x11C40, 0x11C40),
+    (0x11C72, 0x11C8F),
+    (0x11D00, 0x11D06),
+    (0x11D08, 0x11D09),
+    (0x11D0B, 0x11D30),
+    (0x11D46, 0x11D46),
+    (0x11D60, 0x11D65),
+    (0x11D67, 0x11D68),
+    (0x11D6A, 0x11D89),
+    (0x11D98, 0x11D98),
+    (0x11EE0, 0x11EF2),
+    (0x11FB0, 0x11FB0),
+    (0x12000, 0x12399),
+    (0x12400, 0x1246E),
+    (0x12480, 0x12543),
+    (0x12F90, 0x12FF0),
+    (0x13000, 0x1342E),
+    (0x14400, 0x14646),
+    (0x16800, 0x16A38),
+    (0x16A40, 0x16A5E),
+    (0x16A70, 0x16ABE),
+    (0x16AD0, 0x16AED),
+    (0x16B00, 0x16B2F),
+    (0x16B40, 0x16B43),
+    (0x16B63, 0x16B77),
+    (0x16B7D, 0x16B8F),
+    (0x16E40, 0x16E7F),
+    (0x16F00, 0x16F4A),
+    (0x16F50, 0x16F50),
+    (0x16F93, 0x16F9F),
+    (0x16FE0, 0x16FE1),
+    (0x16FE3, 0x16FE3),
+    (0x17000, 0x187F7),
+    (0x18800, 0x18CD5),
+    (0x18D00, 0x18D08),
+    (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122),
+    (0x1B150, 0x1B152),
+    (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB),
+    (0x1BC00, 0x1BC6A),
+    (0x1BC70, 0x1BC7C),
+    (0x1BC80, 0x1BC88),
+    (0x1BC90, 0x1BC99),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D6C0),
+    (0x1D6C2, 0x1D6DA),
+    (0x1D6DC, 0x1D6FA),
+    (0x1D6FC, 0x1D714),
+    (0x1D716, 0x1D734),
+    (0x1D736, 0x1D74E),
+    (0x1D750, 0x1D76E),
+    (0x1D770, 0x1D788),
+    (0x1D78A, 0x1D7A8),
+    (0x1D7AA, 0x1D7C2),
+    (0x1D7C4, 0x1D7CB),
+    (0x1DF00, 0x1DF1E),
+    (0x1E100, 0x1E12C),
+    (0x1E137, 0x1E13D),
+    (0x1E14E, 0x1E14E),
+    (0x1E290, 0x1E2AD),
+    (0x1E2C0, 0x1E2EB),
+    (0x1E7E0, 0x1E7E6),
+    (0x1E7E8, 0x1E7EB),
+    (0x1E7ED, 0x1E7EE),
+    (0x1E7F0, 0x1E7FE),
+    (0x1E800, 0x1E8C4),
+    (0x1E900, 0x1E943),
+    (0x1E94B, 0x1E94B),
+    (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F),
+    (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32),
+    (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39),
+    (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42),
+    (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F),
+    (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54),
+    (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59),
+    (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62),
+    (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A),
+    (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77),
+    (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B),
+    (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9),
+    (0x1EEAB, 0x1EEBB),
+    (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B738),
+    (0x2B740, 0x2B81D),
+    (0x2B820, 0x2CEA1),
+    (0x2CEB0, 0x2EBE0),
+    (0x2F800, 0x2FA1D),
+    (0x30000, 0x3134A),
+];
+
+pub const XID_CONTINUE: &[(u32, u32)] = &[
+    (0x30, 0x39),
+    (0x41, 0x5A),
+    (0x5F, 0x5F),
+    (0x61, 0x7A),
+    (0xAA, 0xAA),
+    (0xB5, 0xB5),
+    (0xB7, 0xB7),
+    (0xBA, 0xBA),
+    (0xC0, 0xD6),
+    (0xD8, 0xF6),
+    (0xF8, 0x2C1),
+    (0x2C6, 0x2D1),
+    (0x2E0, 0x2E4),
+    (0x2EC, 0x2EC),
+    (0x2EE, 0x2EE),
+    (0x300, 0x374),
+    (0x376, 0x377),
+    (0x37B, 0x37D),
+    (0x37F, 0x37F),
+    (0x386, 0x38A),
+    (0x38C, 0x38C),
+    (0x38E, 0x3A1),
+    (0x3A3, 0x3F5),
+    (0x3F7, 0x481),
+    (0x483, 0x487),
+    (0x48A, 0x52F),
+    (0x531, 0x556),
+    (0x559, 0x559),
+    (0x560, 0x588),
+    (0x591, 0x5BD),
+    (0x5BF, 0x5BF),
+    (0x5C1, 0x5C2),
+    (0x5C4, 0x5C5),
+    (0x5C7, 0x5C7),
+    (0x5D0, 0x5EA),
+    (0x5EF, 0x5F2),
+    (0x610, 0x61A),
+    (0x620, 0x669),
+    (0x66E, 0x6D3),
+    (0x6D5, 0x6DC),
+    (0x6DF, 0x6E8),
+    (0x6EA, 0x6FC),
+    (0x6FF, 0x6FF),
+    (0x710, 0x74A),
+    (0x74D, 0x7B1),
+    (0x7C0, 0x7F5),
+    (0x7FA, 0x7FA),
+    (0x7FD, 0x7FD),
+    (0x800, 0x82D),
+    (0x840, 0x85B),
+    (0x860, 0x86A),
+    (0x870, 0x887),
+    (0x889, 0x88E),
+    (0x898, 0x8E1),
+    (0x8E3, 0x963),
+    (0x966, 0x96F),
+    (0x971, 0x983),
+    (0x985, 0x98C),
+    (0x98F, 0x990),
+    (0x993, 0x9A8),
+    (0x9AA, 0x9B0),
+    (0x9B2, 0x9B2),
+    (0x9B6, 0x9B9),
+    (0x9BC, 0x9C4),
+    (0x9C7, 0x9C8),
+    (0x9CB, 0x9CE),
+    (0x9D7, 0x9D7),
+    (0x9DC, 0x9DD),
+    (0x9DF, 0x9E3),
+    (0x9E6, 0x9F1),
+    (0x9FC, 0x9FC),
+    (0x9FE, 0x9FE),
+    (0xA01, 0xA03),
+    (0xA05, 0xA0A),
+    (0xA0F, 0xA10),
+    (0xA13, 0xA28),
+    (0xA2A, 0xA30),
+    (0xA32, 0xA33),
+    (0xA35, 0xA36),
+    (0xA38, 0xA39),
+    (0xA3C, 0xA3C),
+    (0xA3E, 0xA42),
+    (0xA47, 0xA48),
+    (0xA4B, 0xA4D),
+    (0xA51, 0xA51),
+    (0xA59, 0xA5C),
+    (0xA5E, 0xA5E),
+    (0xA66, 0xA75),
+    (0xA81, 0xA83),
+    (0xA85, 0xA8D),
+    (0xA8F, 0xA91),
+    (0xA93, 0xAA8),
+    (0xAAA, 0xAB0),
+    (0xAB2, 0xAB3),
+    (0xAB5, 0xAB9),
+    (0xABC, 0xAC5),
+    (0xAC7, 0xAC9),
+    (0xACB, 0xACD),
+    (0xAD0, 0xAD0),
+    (0xAE0, 0xAE3),
+    (0xAE6, 0xAEF),
+    (0xAF9, 0xAFF),
+    (0xB01, 0xB03),
+    (0xB05, 0xB0C),
+    (0xB0F, 0xB10),
+    (0xB13, 0xB28),
+    (0xB2A, 0xB30),
+    (0xB32, 0xB33),
+    (0xB35, 0xB39),
+    (0xB3C, 0xB44),
+    (0xB47, 0xB48),
+    (0xB4B, 0xB4D),
+    (0xB55, 0xB57),
+    (0xB5C, 0xB5D),
+    (0xB5F, 0xB63),
+    (0xB66, 0xB6F),
+    (0xB71, 0xB71),
+    (0xB82, 0xB83),
+    (0xB85, 0xB8A),
+    (0xB8E, 0xB90),
+    (0xB92, 0xB95),
+    (0xB99, 0xB9A),
+    (0xB9C, 0xB9C),
+    (0xB9E, 0xB9F),
+    (0xBA3, 0xBA4),
+    (0xBA8, 0xBAA),
+    (0xBAE, 0xBB9),
+    (0xBBE, 0xBC2),
+    (0xBC6, 0xBC8),
+    (0xBCA, 0xBCD),
+    (0xBD0, 0xBD0),
+    (0xBD7, 0xBD7),
+    (0xBE6, 0xBEF),
+    (0xC00, 0xC0C),
+    (0xC0E, 0xC10),
+    (0xC12, 0xC28),
+    (0xC2A, 0xC39),
+    (0xC3C, 0xC44),
+    (0xC46, 0xC48),
+    (0xC4A, 0xC4D),
+    (0xC55, 0xC56),
+    (0xC58, 0xC5A),
+    (0xC5D, 0xC5D),
+    (0xC60, 0xC63),
+    (0xC66, 0xC6F),
+    (0xC80, 0xC83),
+    (0xC85, 0xC8C),
+    (0xC8E, 0xC90),
+    (0xC92, 0xCA8),
+    (0xCAA, 0xCB3),
+    (0xCB5, 0xCB9),
+    (0xCBC, 0xCC4),
+    (0xCC6, 0xCC8),
+    (0xCCA, 0xCCD),
+    (0xCD5, 0xCD6),
+    (0xCDD, 0xCDE),
+    (0xCE0, 0xCE3),
+    (0xCE6, 0xCEF),
+    (0xCF1, 0xCF2),
+    (0xD00, 0xD0C),
+    (0xD0E, 0xD10),
+    (0xD12, 0xD44),
+    (0xD46, 0xD48),
+    (0xD4A, 0xD4E),
+    (0xD54, 0xD57),
+    (0xD5F, 0xD63),
+    (0xD66, 0xD6F),
+    (0xD7A, 0xD7F),
+    (0xD81, 0xD83),
+    (0xD85, 0xD96),
+    (0xD9A, 0xDB1),
+    (0xDB3, 0xDBB),
+    (0xDBD, 0xDBD),
+    (0xDC0, 0xDC6),
+    (0xDCA, 0xDCA),
+    (0xDCF, 0xDD4),
+    (0xDD6, 0xDD6),
+    (0xDD8, 0xDDF),
+    (0xDE6, 0xDEF),
+    (0xDF2, 0xDF3),
+    (0xE01, 0xE3A),
+    (0xE40, 0xE4E),
+    (0xE50, 0xE59),
+    (0xE81, 0xE82),
+    (0xE84, 0xE84),
+    (0xE86, 0xE8A),
+    (0xE8C, 0xEA3),
+    (0xEA5, 0xEA5),
+    (0xEA7, 0xEBD),
+    (0xEC0, 0xEC4),
+    (0xEC6, 0xEC6),
+    (0xEC8, 0xECD),
+    (0xED0, 0xED9),
+    (0xEDC, 0xEDF),
+    (0xF00, 0xF00),
+    (0xF18, 0xF19),
+    (0xF20, 0xF29),
+    (0xF35, 0xF35),
+    (0xF37, 0xF37),
+    (0xF39, 0xF39),
+    (0xF3E, 0xF47),
+    (0xF49, 0xF6C),
+    (0xF71, 0xF84),
+    (0xF86, 0xF97),
+    (0xF99, 0xFBC),
+    (0xFC6, 0xFC6),
+    (0x1000, 0x1049),
+    (0x1050, 0x109D),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x10D0, 0x10FA),
+    (0x10FC, 0x1248),
+    (0x124A, 0x124D),
+    (0x1250, 0x1256),
+    (0x1258, 0x1258),
+    (0x125A, 0x125D),
+    (0x1260, 0x1288),
+    (0x128A, 0x128D),
+    (0x1290, 0x12B0),
+    (0x12B2, 0x12B5),
+    (0x12B8, 0x12BE),
+    (0x12C0, 0x12C0),
+    (0x12C2, 0x12C5),
+    (0x12C8, 0x12D6),
+    (0x12D8, 0x1310),
+    (0x1312, 0x1315),
+    (0x1318, 0x135A),
+    (0x135D, 0x135F),
+    (0x1369, 0x1371),
+    (0x1380, 0x138F),
+    (0x13A0, 0x13F5),
+    (0x13F8, 0x13FD),
+    (0x1401, 0x166C),
+    (0x166F, 0x167F),
+    (0x1681, 0x169A),
+    (0x16A0, 0x16EA),
+    (0x16EE, 0x16F8),
+    (0x1700, 0x1715),
+    (0x171F, 0x1734),
+    (0x1740, 0x1753),
+    (0x1760, 0x176C),
+    (0x176E, 0x1770),
+    (0x1772, 0x1773),
+    (0x1780, 0x17D3),
+    (0x17D7, 0x17D7),
+    (0x17DC, 0x17DD),
+    (0x17E0, 0x17E9),
+    (0x180B, 0x180D),
+    (0x180F, 0x1819),
+    (0x1820, 0x1878),
+    (0x1880, 0x18AA),
+    (0x18B0, 0x18F5),
+    (0x1900, 0x191E),
+    (0x1920, 0x192B),
+    (0x1930, 0x193B),
+    (0x1946, 0x196D),
+    (0x1970, 0x1974),
+    (0x1980, 0x19AB),
+    (0x19B0, 0x19C9),
+    (0x19D0, 0x19DA),
+    (0x1A00, 0x1A1B),
+    (0x1A20, 0x1A5E),
+    (0x1A60, 0x1A7C),
+    (0x1A7F, 0x1A89),
+    (0x1A90, 0x1A99),
+    (0x1AA7, 0x1AA7),
+    (0x1AB0, 0x1ABD),
+    (0x1ABF, 0x1ACE),
+    (0x1B00, 0x1B4C),
+    (0x1B50, 0x1B59),
+    (0x1B6B, 0x1B73),
+    (0x1B80, 0x1BF3),
+    (0x1C00, 0x1C37),
+    (0x1C40, 0x1C49),
+    (0x1C4D, 0x1C7D),
+    (0x1C80, 0x1C88),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CFA),
+    (0x1D00, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FE0, 0x1FEC),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFC),
+    (0x203F, 0x2040),
+    (0x2054, 0x2054),
+    (0x2071, 0x2071),
+    (0x207F, 0x207F),
+    (0x2090, 0x209C),
+    (0x20D0, 0x20DC),
+    (0x20E1, 0x20E1),
+    (0x20E5, 0x20F0),
+    (0x2102, 0x2102),
+    (0x2107, 0x2107),
+    (0x210A, 0x2113),
+    (0x2115, 0x2115),
+    (0x2118, 0x211D),
+    (0x2124, 0x2124),
+    (0x2126, 0x2126),
+    (0x2128, 0x2128),
+    (0x212A, 0x2139),
+    (0x213C, 0x213F),
+    (0x2145, 0x2149),
+    (0x214E, 0x214E),
+    (0x2160, 0x2188),
+    (0x2C00, 0x2CE4),
+    (0x2CEB, 0x2CF3),
+    (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27),
+    (0x2D2D, 0x2D2D),
+    (0x2D30, 0x2D67),
+    (0x2D6F, 0x2D6F),
+    (0x2D7F, 0x2D96),
+    (0x2DA0, 0x2DA6),
+    (0x2DA8, 0x2DAE),
+    (0x2DB0, 0x2DB6),
+    (0x2DB8, 0x2DBE),
+    (0x2DC0, 0x2DC6),
+    (0x2DC8, 0x2DCE),
+    (0x2DD0, 0x2DD6),
+    (0x2DD8, 0x2DDE),
+    (0x2DE0, 0x2DFF),
+    (0x3005, 0x3007),
+    (0x3021, 0x302F),
+    (0x3031, 0x3035),
+    (0x3038, 0x303C),
+    (0x3041, 0x3096),
+    (0x3099, 0x309A),
+    (0x309D, 0x309F),
+    (0x30A1, 0x30FA),
+    (0x30FC, 0x30FF),
+    (0x3105, 0x312F),
+    (0x3131, 0x318E),
+    (0x31A0, 0x31BF),
+    (0x31F0, 0x31FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0xA48C),
+    (0xA4D0, 0xA4FD),
+    (0xA500, 0xA60C),
+    (0xA610, 0xA62B),
+    (0xA640, 0xA66F),
+    (0xA674, 0xA67D),
+    (0xA67F, 0xA6F1),
+    (0xA717, 0xA71F),
+    (0xA722, 0xA788),
+    (0xA78B, 0xA7CA),
+    (0xA7D0, 0xA7D1),
+    (0xA7D3, 0xA7D3),
+    (0xA7D5, 0xA7D9),
+    (0xA7F2, 0xA827),
+    (0xA82C, 0xA82C),
+    (0xA840, 0xA873),
+    (0xA880, 0xA8C5),
+    (0xA8D0, 0xA8D9),
+    (0xA8E0, 0xA8F7),
+    (0xA8FB, 0xA8FB),
+    (0xA8FD, 0xA92D),
+    (0xA930, 0xA953),
+    (0xA960, 0xA97C),
+    (0xA980, 0xA9C0),
+    (0xA9CF, 0xA9D9),
+    (0xA9E0, 0xA9FE),
+    (0xAA00, 0xAA36),
+    (0xAA40, 0xAA4D),
+    (0xAA50, 0xAA59),
+    (0xAA60, 0xAA76),
+    (0xAA7A, 0xAAC2),
+    (0xAADB, 0xAADD),
+    (0xAAE0, 0xAAEF),
+    (0xAAF2, 0xAAF6),
+    (0xAB01, 0xAB06),
+    (0xAB09, 0xAB0E),
+    (0xAB11, 0xAB16),
+    (0xAB20, 0xAB26),
+    (0xAB28, 0xAB2E),
+    (0xAB30, 0xAB5A),
+    (0xAB5C, 0xAB69),
+    (0xAB70, 0xABEA),
+    (0xABEC, 0xABED),
+    (0xABF0, 0xABF9),
+    (0xAC00, 0xD7A3),
+    (0xD7B0, 0xD7C6),
+    (0xD7CB, 0xD7FB),
+    (0xF900, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFB1D, 0xFB28),
+    (0xFB2A, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFBB1),
+    (0xFBD3, 0xFC5D),
+    (0xFC64, 0xFD3D),
+    (0xFD50, 0xFD8F),
+    (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDF9),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFE33, 0xFE34),
+    (0xFE4D, 0xFE4F),
+    (0xFE71, 0xFE71),
+    (0xFE73, 0xFE73),
+    (0xFE77, 0xFE77),
+    (0xFE79, 0xFE79),
+    (0xFE7B, 0xFE7B),
+    (0xFE7D, 0xFE7D),
+    (0xFE7F, 0xFEFC),
+    (0xFF10, 0xFF19),
+    (0xFF21, 0xFF3A),
+    (0xFF3F, 0xFF3F),
+    (0xFF41, 0xFF5A),
+    (0xFF66, 0xFFBE),
+    (0xFFC2, 0xFFC7),
+    (0xFFCA, 0xFFCF),
+    (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC),
+    (0x10000, 0x1000B),
+    (0x1000D, 0x10026),
+    (0x10028, 0x1003A),
+    (0x1003C, 0x1003D),
+    (0x1003F, 0x1004D),
+    (0x10050, 0x1005D),
+    (0x10080, 0x100FA),
+    (0x10140, 0x10174),
+    (0x101FD, 0x101FD),
+    (0x10280, 0x1029C),
+    (0x102A0, 0x102D0),
+    (0x102E0, 0x102E0),
+    (0x10300, 0x1031F),
+    (0x1032D, 0x1034A),
+    (0x10350, 0x1037A),
+    (0x10380, 0x1039D),
+    (0x103A0, 0x103C3),
+    (0x103C8, 0x103CF),
+    (0x103D1, 0x103D5),
+    (0x10400, 0x1049D),
+    (0x104A0, 0x104A9),
+    (0x104B0, 0x104D3),
+    (0x104D8, 0x104FB),
+    (0x10500, 0x10527),
+    (0x10530, 0x10563),
+    (0x10570, 0x1057A),
+    (0x1057C, 0x1058A),
+    (0x1058C, 0x10592),
+    (0x10594, 0x10595),
+    (0x10597, 0x105A1),
+    (0x105A3, 0x105B1),
+    (0x105B3, 0x105B9),
+    (0x105BB, 0x105BC),
+    (0x10600, 0x10736),
+    (0x10740, 0x10755),
+    (0x10760, 0x10767),
+    (0x10780, 0x10785),
+    (0x10787, 0x107B0),
+    (0x107B2, 0x107BA),
+    (0x10800, 0x10805),
+    (0x10808, 0x10808),
+    (0x1080A, 0x10835),
+    (0x10837, 0x10838),
+    (0x1083C, 0x1083C),
+    (0x1083F, 0x10855),
+    (0x10860, 0x10876),
+    (0x10880, 0x1089E),
+    (0x108E0, 0x108F2),
+    (0x108F4, 0x108F5),
+    (0x10900, 0x10915),
+    (0x10920, 0x10939),
+    (0x10980, 0x109B7),
+    (0x109BE, 0x109BF),
+    (0x10A00, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A13),
+    (0x10A15, 0x10A17),
+    (0x10A19, 0x10A35),
+    (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F),
+    (0x10A60, 0x10A7C),
+    (0x10A80, 0x10A9C),
+    (0x10AC0, 0x10AC7),
+    (0x10AC9, 0x10AE6),
+    (0x10B00, 0x10B35),
+    (0x10B40, 0x10B55),
+    (0x10B60, 0x10B72),
+    (0x10B80, 0x10B91),
+    (0x10C00, 0x10C48),
+    (0x10C80, 0x10CB2),
+    (0x10CC0, 0x10CF2),
+    (0x10D00, 0x10D27),
+    (0x10D30, 0x10D39),
+    (0x10E80, 0x10EA9),
+    (0x10EAB, 0x10EAC),
+    (0x10EB0, 0x10EB1),
+    (0x10F00, 0x10F1C),
+    (0x10F27, 0x10F27),
+    (0x10F30, 0x10F50),
+    (0x10F70, 0x10F85),
+    (0x10FB0, 0x10FC4),
+    (0x10FE0, 0x10FF6),
+    (0x11000, 0x11046),
+    (0x11066, 0x11075),
+    (0x1107F, 0x110BA),
+    (0x110C2, 0x110C2),
+    (0x110D0, 0x110E8),
+    (0x110F0, 0x110F9),
+    (0x11100, 0x11134),
+    (0x11136, 0x1113F),
+    (0x11144, 0x11147),
+    (0x11150, 0x11173),
+    (0x11176, 0x11176),
+    (0x11180, 0x111C4),
+    (0x111C9, 0x111CC),
+    (0x111CE, 0x111DA),
+    (0x111DC, 0x111DC),
+    (0x11200, 0x11211),
+    (0x11213, 0x11237),
+    (0x1123E, 0x1123E),
+    (0x11280, 0x11286),
+    (0x11288, 0x11288),
+    (0x1128A, 0x1128D),
+    (0x1128F, 0x1129D),
+    (0x1129F, 0x112A8),
+    (0x112B0, 0x112EA),
+    (0x112F0, 0x112F9),
+    (0x11300, 0x11303),
+    (0x11305, 0x1130C),
+    (0x1130F, 0x11310),
+    (0x11313, 0x11328),
+    (0x1132A, 0x11330),
+    (0x11332, 0x11333),
+    (0x11335, 0x11339),
+    (0x1133B, 0x11344),
+    (0x11347, 0x11348),
+    (0x1134B, 0x1134D),
+    (0x11350, 0x11350),
+    (0x11357, 0x11357),
+    (0x1135D, 0x11363),
+    (0x11366, 0x1136C),
+    (0x11370, 0x11374),
+    (0x11400, 0x1144A),
+    (0x11450, 0x11459),
+    (0x1145E, 0x11461),
+    (0x11480, 0x114C5),
+    (0x114C7, 0x114C7),
+    (0x114D0, 0x114D9),
+    (0x11580, 0x115B5),
+    (0x115B8, 0x115C0),
+    (0x115D8, 0x115DD),
+    (0x11600, 0x11640),
+    (0x11644, 0x11644),
+    (0x11650, 0x11659),
+    (0x11680, 0x116B8),
+    (0x116C0, 0x116C9),
+    (0x11700, 0x1171A),
+    (0x1171D, 0x1172B),
+    (0x11730, 0x11739),
+    (0x11740, 0x11746),
+    (0x11800, 0x1183A),
+    (0x118A0, 0x118E9),
+    (0x118FF, 0x11906),
+    (0x11909, 0x11909),
+    (0x1190C, 0x11913),
+    (0x11915, 0x11916),
+    (0x11918, 0x11935),
+    (0x11937, 0x11938),
+    (0x1193B, 0x11943),
+    (0x11950, 0x11959),
+    (0x119A0, 0x119A7),
+    (0x119AA, 0x119D7),
+    (0x119DA, 0x119E1),
+    (0x119E3, 0x119E4),
+    (0x11A00, 0x11A3E),
+    (0x11A47, 0x11A47),
+    (0x11A50, 0x11A99),
+    (0x11A9D, 0x11A9D),
+    (0x11AB0, 0x11AF8),
+    (0x11C00, 0x11C08),
+    (0x11C0A, 0x11C36),
+    (0x11C38, 0x11C40),
+    (0x11C50, 0x11C59),
+    (0x11C72, 0x11C8F),
+    (0x11C92, 0x11CA7),
+    (0x11CA9, 0x11CB6),
+    (0x11D00, 0x11D06),
+    (0x11D08, 0x11D09),
+    (0x11D0B, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D47),
+    (0x11D50, 0x11D59),
+    (0x11D60, 0x11D65),
+    (0x11D67, 0x11D68),
+    (0x11D6A, 0x11D8E),
+    (0x11D90, 0x11D91),
+    (0x11D93, 0x11D98),
+    (0x11DA0, 0x11DA9),
+    (0x11EE0, 0x11EF6),
+    (0x11FB0, 0x11FB0),
+    (0x12000, 0x12399),
+    (0x12400, 0x1246E),
+    (0x12480, 0x12543),
+    (0x12F90, 0x12FF0),
+    (0x13000, 0x1342E),
+    (0x14400, 0x14646),
+    (0x16800, 0x16A38),
+    (0x16A40, 0x16A5E),
+    (0x16A60, 0x16A69),
+    (0x16A70, 0x16ABE),
+    (0x16AC0, 0x16AC9),
+    (0x16AD0, 0x16AED),
+    (0x16AF0, 0x16AF4),
+    (0x16B00, 0x16B36),
+    (0x16B40, 0x16B43),
+    (0x16B50, 0x16B59),
+    (0x16B63, 0x16B77),
+    (0x16B7D, 0x16B8F),
+    (0x16E40, 0x16E7F),
+    (0x16F00, 0x16F4A),
+    (0x16F4F, 0x16F87),
+    (0x16F8F, 0x16F9F),
+    (0x16FE0, 0x16FE1),
+    (0x16FE3, 0x16FE4),
+    (0x16FF0, 0x16FF1),
+    (0x17000, 0x187F7),
+    (0x18800, 0x18CD5),
+    (0x18D00, 0x18D08),
+    (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122),
+    (0x1B150, 0x1B152),
+    (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB),
+    (0x1BC00, 0x1BC6A),
+    (0x1BC70, 0x1BC7C),
+    (0x1BC80, 0x1BC88),
+    (0x1BC90, 0x1BC99),
+    (0x1BC9D, 0x1BC9E),
+    (0x1CF00, 0x1CF2D),
+    (0x1CF30, 0x1CF46),
+    (0x1D165, 0x1D169),
+    (0x1D16D, 0x1D172),
+    (0x1D17B, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D6C0),
+    (0x1D6C2, 0x1D6DA),
+    (0x1D6DC, 0x1D6FA),
+    (0x1D6FC, 0x1D714),
+    (0x1D716, 0x1D734),
+    (0x1D736, 0x1D74E),
+    (0x1D750, 0x1D76E),
+    (0x1D770, 0x1D788),
+    (0x1D78A, 0x1D7A8),
+    (0x1D7AA, 0x1D7C2),
+    (0x1D7C4, 0x1D7CB),
+    (0x1D7CE, 0x1D7FF),
+    (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C),
+    (0x1DA75, 0x1DA75),
+    (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF),
+    (0x1DF00, 0x1DF1E),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E100, 0x1E12C),
+    (0x1E130, 0x1E13D),
+    (0x1E140, 0x1E149),
+    (0x1E14E, 0x1E14E),
+    (0x1E290, 0x1E2AE),
+    (0x1E2C0, 0x1E2F9),
+    (0x1E7E0, 0x1E7E6),
+    (0x1E7E8, 0x1E7EB),
+    (0x1E7ED, 0x1E7EE),
+    (0x1E7F0, 0x1E7FE),
+    (0x1E800, 0x1E8C4),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E900, 0x1E94B),
+    (0x1E950, 0x1E959),
+    (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F),
+    (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32),
+    (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39),
+    (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42),
+    (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F),
+    (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54),
+    (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59),
+    (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62),
+    (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A),
+    (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77),
+    (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B),
+    (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9),
+    (0x1EEAB, 0x1EEBB),
+    (0x1FBF0, 0x1FBF9),
+    (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B738),
+    (0x2B740, 0x2B81D),
+    (0x2B820, 0x2CEA1),
+    (0x2CEB0, 0x2EBE0),
+    (0x2F800, 0x2FA1D),
+    (0x30000, 0x3134A),
+    (0xE0100, 0xE01EF),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Cat {
+    /// Control
+    Cc,
+    /// Format
+    Cf,
+    /// Unassigned
+    Cn,
+    /// Private_Use
+    Co,
+    /// Surrogate
+    Cs,
+    /// Lowercase_Letter
+    Ll,
+    /// Modifier_Letter
+    Lm,
+    /// Other_Letter
+    Lo,
+    /// Titlecase_Letter
+    Lt,
+    /// Uppercase_Letter
+    Lu,
+    /// Spacing_Mark
+    Mc,
+    /// Enclosing_Mark
+    Me,
+    /// Nonspacing_Mark
+    Mn,
+    /// Decimal_Number
+    Nd,
+    /// Letter_Number
+    Nl,
+    /// Other_Number
+    No,
+    /// Connector_Punctuation
+    Pc,
+    /// Dash_Punctuation
+    Pd,
+    /// Close_Punctuation
+    Pe,
+    /// Final_Punctuation
+    Pf,
+    /// Initial_Punctuation
+    Pi,
+    /// Other_Punctuation
+    Po,
+    /// Open_Punctuation
+    Ps,
+    /// Currency_Symbol
+    Sc,
+    /// Modifier_Symbol
+    Sk,
+    /// Math_Symbol
+    Sm,
+    /// Other_Symbol
+    So,
+    /// Line_Separator
+    Zl,
+    /// Paragraph_Separator
+    Zp,
+    /// Space_Separator
+    Zs,
+}
+
+impl Cat {
+    /// The name as written in the Unicode data files
+    pub fn name(self) -> &'static str {
+        match self {
+            Cat::Cc => "Cc",
+            Cat::Cf => "Cf",
+            Cat::Cn => "Cn",
+            Cat::Co => "Co",
+            Cat::Cs => "Cs",
+            Cat::Ll => "Ll",
+            Cat::Lm => "Lm",
+            Cat::Lo => "Lo",
+            Cat::Lt => "Lt",
+            Cat::Lu => "Lu",
+            Cat::Mc => "Mc",
+            Cat::Me => "Me",
+            Cat::Mn => "Mn",
+            Cat::Nd => "Nd",
+            Cat::Nl => "Nl",
+            Cat::No => "No",
+            Cat::Pc => "Pc",
+            Cat::Pd => "Pd",
+            Cat::Pe => "Pe",
+            Cat::Pf => "Pf",
+            Cat::Pi => "Pi",
+            Cat::Po => "Po",
+            Cat::Ps => "Ps",
+            Cat::Sc => "Sc",
+            Cat::Sk => "Sk",
+            Cat::Sm => "Sm",
+            Cat::So => "So",
+            Cat::Zl => "Zl",
+            Cat::Zp => "Zp",
+            Cat::Zs => "Zs",
+        }
+    }
+}
+
+pub const CATEGORIES: &[(u32, Cat)] = &[
+    (0x0, Cat::Cc),
+    (0x20, Cat::Zs),
+    (0x21, Cat::Po),
+    (0x24, Cat::Sc),
+    (0x25, Cat::Po),
+    (0x28, Cat::Ps),
+    (0x29, Cat::Pe),
+    (0x2A, Cat::Po),
+    (0x2B, Cat::Sm),
+    (0x2C, Cat::Po),
+    (0x2D, Cat::Pd),
+    (0x2E, Cat::Po),
+    (0x30, Cat::Nd),
+    (0x3A, Cat::Po),
+    (0x3C, Cat::Sm),
+    (0x3F, Cat::Po),
+    (0x41, Cat::Lu),
+    (0x5B, Cat::Ps),
+    (0x5C, Cat::Po),
+    (0x5D, Cat::Pe),
+    (0x5E, Cat::Sk),
+    (0x5F, Cat::Pc),
+    (0x60, Cat::Sk),
+    (0x61, Cat::Ll),
+    (0x7B, Cat::Ps),
+    (0x7C, Cat::Sm),
+    (0x7D, Cat::Pe),
+    (0x7E, Cat::Sm),
+    (0x7F, Cat::Cc),
+    (0xA0, Cat::Zs),
+    (0xA1, Cat::Po),
+    (0xA2, Cat::Sc),
+    (0xA6, Cat::So),
+    (0xA7, Cat::Po),
+    (0xA8, Cat::Sk),
+    (0xA9, Cat::So),
+    (0xAA, Cat::Lo),
+    (0xAB, Cat::Pi),
+    (0xAC, Cat::Sm),
+    (0xAD, Cat::Cf),
+    (0xAE, Cat::So),
+    (0xAF, Cat::Sk),
+    (0xB0, Cat::So),
+    (0xB1, Cat::Sm),
+    (0xB2, Cat::No),
+    (0xB4, Cat::Sk),
+    (0xB5, Cat::Ll),
+    (0xB6, Cat::Po),
+    (0xB8, Cat::Sk),
+    (0xB9, Cat::No),
+    (0xBA, Cat::Lo),
+    (0xBB, Cat::Pf),
+    (0xBC, Cat::No),
+    (0xBF, Cat::Po),
+    (0xC0, Cat::Lu),
+    (0xD7, Cat::Sm),
+    (0xD8, Cat::Lu),
+    (0xDF, Cat::Ll),
+    (0xF7, Cat::Sm),
+    (0xF8, Cat::Ll),
+    (0x100, Cat::Lu),
+    (0x101, Cat::Ll),
+    (0x102, Cat::Lu),
+    (0x103, Cat::Ll),
+    (0x104, Cat::Lu),
+    (0x105, Cat::Ll),
+    (0x106, Cat::Lu),
+    (0x107, Cat::Ll),
+    (0x108, Cat::Lu),
+    (0x109, Cat::Ll),
+    (0x10A, Cat::Lu),
+    (0x10B, Cat::Ll),
+    (0x10C, Cat::Lu),
+    (0x10D, Cat::Ll),
+    (0x10E, Cat::Lu),
+    (0x10F, Cat::Ll),
+    (0x110, Cat::Lu),
+    (0x111, Cat::Ll),
+    (0x112, Cat::Lu),
+    (0x113, Cat::Ll),
+    (0x114, Cat::Lu),
+    (0x115, Cat::Ll),
+    (0x116, Cat::Lu),
+    (0x117, Cat::Ll),
+    (0x118, Cat::Lu),
+    (0x119, Cat::Ll),
+    (0x11A, Cat::Lu),
+    (0x11B, Cat::Ll),
+    (0x11C, Cat::Lu),
+    (0x11D, Cat::Ll),
+    (0x11E, Cat::Lu),
+    (0x11F, Cat::Ll),
+    (0x120, Cat::Lu),
+    (0x121, Cat::Ll),
+    (0x122, Cat::Lu),
+    (0x123, Cat::Ll),
+    (0x124, Cat::Lu),
+    (0x125, Cat::Ll),
+    (0x126, Cat::Lu),
+    (0x127, Cat::Ll),
+    (0x128, Cat::Lu),
+    (0x129, Cat::Ll),
+    (0x12A, Cat::Lu),
+    (0x12B, Cat::Ll),
+    (0x12C, Cat::Lu),
+    (0x12D, Cat::Ll),
+    (0x12E, Cat::Lu),
+    (0x12F, Cat::Ll),
+    (0x130, Cat::Lu),
+    (0x131, Cat::Ll),
+    (0x132, Cat::Lu),
+    (0x133, Cat::Ll),
+    (0x134, Cat::Lu),
+    (0x135, Cat::Ll),
+    (0x136, Cat::Lu),
+    (0x137, Cat::Ll),
+    (0x139, Cat::Lu),
+    (0x13A, Cat::Ll),
+    (0x13B, Cat::Lu),
+    (0x13C, Cat::Ll),
+    (0x13D, Cat::Lu),
+    (0x13E, Cat::Ll),
+    (0x13F, Cat::Lu),
+    (0x140, Cat::Ll),
+    (0x141, Cat::Lu),
+    (0x142, Cat::Ll),
+    (0x143, Cat::Lu),
+    (0x144, Cat::Ll),
+    (0x145, Cat::Lu),
+    (0x146, Cat::Ll),
+    (0x147, Cat::Lu),
+    (0x148, Cat::Ll),
+    (0x14A, Cat::Lu),
+    (0x14B, Cat::Ll),
+    (0x14C, Cat::Lu),
+    (0x14D, Cat::Ll),
+    (0x14E, Cat::Lu),
+    (0x14F, Cat::Ll),
+    (0x150, Cat::Lu),
+    (0x151, Cat::Ll),
+    (0x152, Cat::Lu),
+    (0x153, Cat::Ll),
+    (0x154, Cat::Lu),
+    (0x155, Cat::Ll),
+    (0x156, Cat::Lu),
+    (0x157, Cat::Ll),
+    (0x158, Cat::Lu),
+    (0x159, Cat::Ll),
+    (0x15A, Cat::Lu),
+    (0x15B, Cat::Ll),
+    (0x15C, Cat::Lu),
+    (0x15D, Cat::Ll),
+    (0x15E, Cat::Lu),
+    (0x15F, Cat::Ll),
+    (0x160, Cat::Lu),
+    (0x161, Cat::Ll),
+    (0x162, Cat::Lu),
+    (0x163, Cat::Ll),
+    (0x164, Cat::Lu),
+    (0x165, Cat::Ll),
+    (0x166, Cat::Lu),
+    (0x167, Cat::Ll),
+    (0x168, Cat::Lu),
+    (0x169, Cat::Ll),
+    (0x16A, Cat::Lu),
+    (0x16B, Cat::Ll),
+    (0x16C, Cat::Lu),
+    (0x16D, Cat::Ll),
+    (0x16E, Cat::Lu),
+    (0x16F, Cat::Ll),
+    (0x170, Cat::Lu),
+    (0x171, Cat::Ll),
+    (0x172, Cat::Lu),
+    (0x173, Cat::Ll),
+    (0x174, Cat::Lu),
+    (0x175, Cat::Ll),
+    (0x176, Cat::Lu),
+    (0x177, Cat::Ll),
+    (0x178, Cat::Lu),
+    (0x17A, Cat::Ll),
+    (0x17B, Cat::Lu),
+    (0x17C, Cat::Ll),
+    (0x17D, Cat::Lu),
+    (0x17E, Cat::Ll),
+    (0x181, Cat::Lu),
+    (0x183, Cat::Ll),
+    (0x184, Cat::Lu),
+    (0x185, Cat::Ll),
+    (0x186, Cat::Lu),
+    (0x188, Cat::Ll),
+    (0x189, Cat::Lu),
+    (0x18C, Cat::Ll),
+    (0x18E, Cat::Lu),
+    (0x192, Cat::Ll),
+    (0x193, Cat::Lu),
+    (0x195, Cat::Ll),
+    (0x196, Cat::Lu),
+    (0x199, Cat::Ll),
+    (0x19C, Cat::Lu),
+    (0x19E, Cat::Ll),
+    (0x19F, Cat::Lu),
+    (0x1A1, Cat::Ll),
+    (0x1A2, Cat::Lu),
+    (0x1A3, Cat::Ll),
+    (0x1A4, Cat::Lu),
+    (0x1A5, Cat::Ll),
+    (0x1A6, Cat::Lu),
+    (0x1A8, Cat::Ll),
+    (0x1A9, Cat::Lu),
+    (0x1AA, Cat::Ll),
+    (0x1AC, Cat::Lu),
+    (0x1AD, Cat::Ll),
+    (0x1AE, Cat::Lu),
+    (0x1B0, Cat::Ll),
+    (0x1B1, Cat::Lu),
+    (0x1B4, Cat::Ll),
+    (0x1B5, Cat::Lu),
+    (0x1B6, Cat::Ll),
+    (0x1B7, Cat::Lu),
+    (0x1B9, Cat::Ll),
+    (0x1BB, Cat::Lo),
+    (0x1BC, Cat::Lu),
+    (0x1BD, Cat::Ll),
+    (0x1C0, Cat::Lo),
+    (0x1C4, Cat::Lu),
+    (0x1C5, Cat::Lt),
+    (0x1C6, Cat::Ll),
+    (0x1C7, Cat::Lu),
+    (0x1C8, Cat::Lt),
+    (0x1C9, Cat::Ll),
+    (0x1CA, Cat::Lu),
+    (0x1CB, Cat::Lt),
+    (0x1CC, Cat::Ll),
+    (0x1CD, Cat::Lu),
+    (0x1CE, Cat::Ll),
+    (0x1CF, Cat::Lu),
+    (0x1D0, Cat::Ll),
+    (0x1D1, Cat::Lu),
+    (0x1D2, Cat::Ll),
+    (0x1D3, Cat::Lu),
+    (0x1D4, Cat::Ll),
+    (0x1D5, Cat::Lu),
+    (0x1D6, Cat::Ll),
+    (0x1D7, Cat::Lu),
+    (0x1D8, Cat::Ll),
+    (0x1D9, Cat::Lu),
+    (0x1DA, Cat::Ll),
+    (0x1DB, Cat::Lu),
+    (0x1DC, Cat::Ll),
+    (0x1DE, Cat::Lu),
+    (0x1DF, Cat::Ll),
+    (0x1E0, Cat::Lu),
+    (0x1E1, Cat::Ll),
+    (0x1E2, Cat::Lu),
+    (0x1E3, Cat::Ll),
+    (0x1E4, Cat::Lu),
+    (0x1E5, Cat::Ll),
+    (0x1E6, Cat::Lu),
+    (0x1E7, Cat::Ll),
+    (0x1E8, Cat::Lu),
+    (0x1E9, Cat::Ll),
+    (0x1EA, Cat::Lu),
+    (0x1EB, Cat::Ll),
+    (0x1EC, Cat::Lu),
+    (0x1ED, Cat::Ll),
+    (0x1EE, Cat::Lu),
+    (0x1EF, Cat::Ll),
+    (0x1F1, Cat::Lu),
+    (0x1F2, Cat::Lt),
+    (0x1F3, Cat::Ll),
+    (0x1F4, Cat::Lu),
+    (0x1F5, Cat::Ll),
+    (0x1F6, Cat::Lu),
+    (0x1F9, Cat::Ll),
+    (0x1FA, Cat::Lu),
+    (0x1FB, Cat::Ll),
+    (0x1FC, Cat::Lu),
+    (0x1FD, Cat::Ll),
+    (0x1FE, Cat::Lu),
+    (0x1FF, Cat::Ll),
+    (0x200, Cat::Lu),
+    (0x201, Cat::Ll),
+    (0x202, Cat::Lu),
+    (0x203, Cat::Ll),
+    (0x204, Cat::Lu),
+    (0x205, Cat::Ll),
+    (0x206, Cat::Lu),
+    (0x207, Cat::Ll),
+    (0x208, Cat::Lu),
+    (0x209, Cat::Ll),
+    (0x20A, Cat::Lu),
+    (0x20B, Cat::Ll),
+    (0x20C, Cat::Lu),
+    (0x20D, Cat::Ll),
+    (0x20E, Cat::Lu),
+    (0x20F, Cat::Ll),
+    (0x210, Cat::Lu),
+    (0x211, Cat::Ll),
+    (0x212, Cat::Lu),
+    (0x213, Cat::Ll),
+    (0x214, Cat::Lu),
+    (0x215, Cat::Ll),
+    (0x216, Cat::Lu),
+    (0x217, Cat::Ll),
+    (0x218, Cat::Lu),
+    (0x219, Cat::Ll),
+    (0x21A, Cat::Lu),
+    (0x21B, Cat::Ll),
+    (0x21C, Cat::Lu),
+    (0x21D, Cat::Ll),
+    (0x21E, Cat::Lu),
+    (0x21F, Cat::Ll),
+    (0x220, Cat::Lu),
+    (0x221, Cat::Ll),
+    (0x222, Cat::Lu),
+    (0x223, Cat::Ll),
+    (0x224, Cat::Lu),
+    (0x225, Cat::Ll),
+    (0x226, Cat::Lu),
+    (0x227, Cat::Ll),
+    (0x228, Cat::Lu),
+    (0x229, Cat::Ll),
+    (0x22A, Cat::Lu),
+    (0x22B, Cat::Ll),
+    (0x22C, Cat::Lu),
+    (0x22D, Cat::Ll),
+    (0x22E, Cat::Lu),
+    (0x22F, Cat::Ll),
+    (0x230, Cat::Lu),
+    (0x231, Cat::Ll),
+    (0x232, Cat::Lu),
+    (0x233, Cat::Ll),
+    (0x23A, Cat::Lu),
+    (0x23C, Cat::Ll),
+    (0x23D, Cat::Lu),
+    (0x23F, Cat::Ll),
+    (0x241, Cat::Lu),
+    (0x242, Cat::Ll),
+    (0x243, Cat::Lu),
+    (0x247, Cat::Ll),
+    (0x248, Cat::Lu),
+    (0x249, Cat::Ll),
+    (0x24A, Cat::Lu),
+    (0x24B, Cat::Ll),
+    (0x24C, Cat::Lu),
+    (0x24D, Cat::Ll),
+    (0x24E, Cat::Lu),
+    (0x24F, Cat::Ll),
+    (0x294, Cat::Lo),
+    (0x295, Cat::Ll),
+    (0x2B0, Cat::Lm),
+    (0x2C2, Cat::Sk),
+    (0x2C6, Cat::Lm),
+    (0x2D2, Cat::Sk),
+    (0x2E0, Cat::Lm),
+    (0x2E5, Cat::Sk),
+    (0x2EC, Cat::Lm),
+    (0x2ED, Cat::Sk),
+    (0x2EE, Cat::Lm),
+    (0x2EF, Cat::Sk),
+    (0x300, Cat::Mn),
+    (0x370, Cat::Lu),
+    (0x371, Cat::Ll),
+    (0x372, Cat::Lu),
+    (0x373, Cat::Ll),
+    (0x374, Cat::Lm),
+    (0x375, Cat::Sk),
+    (0x376, Cat::Lu),
+    (0x377, Cat::Ll),
+    (0x378, Cat::Cn),
+    (0x37A, Cat::Lm),
+    (0x37B, Cat::Ll),
+    (0x37E, Cat::Po),
+    (0x37F, Cat::Lu),
+    (0x380, Cat::Cn),
+    (0x384, Cat::Sk),
+    (0x386, Cat::Lu),
+    (0x387, Cat::Po),
+    (0x388, Cat::Lu),
+    (0x38B, Cat::Cn),
+    (0x38C, Cat::Lu),
+    (0x38D, Cat::Cn),
+    (0x38E, Cat::Lu),
+    (0x390, Cat::Ll),
+    (0x391, Cat::Lu),
+    (0x3A2, Cat::Cn),
+    (0x3A3, Cat::Lu),
+    (0x3AC, Cat::Ll),
+    (0x3CF, Cat::Lu),
+    (0x3D0, Cat::Ll),
+    (0x3D2, Cat::Lu),
+    (0x3D5, Cat::Ll),
+    (0x3D8, Cat::Lu),
+    (0x3D9, Cat::Ll),
+    (0x3DA, Cat::Lu),
+    (0x3DB, Cat::Ll),
+    (0x3DC, Cat::Lu),
+    (0x3DD, Cat::Ll),
+    (0x3DE, Cat::Lu),
+    (0x3DF, Cat::Ll),
+    (0x3E0, Cat::Lu),
+    (0x3E1, Cat::Ll),
+    (0x3E2, Cat::Lu),
+    (0x3E3, Cat::Ll),
+    (0x3E4, Cat::Lu),
+    (0x3E5, Cat::Ll),
+    (0x3E6, Cat::Lu),
+    (0x3E7, Cat::Ll),
+    (0x3E8, Cat::Lu),
+    (0x3E9, Cat::Ll),
+    (0x3EA, Cat::Lu),
+    (0x3EB, Cat::Ll),
+    (0x3EC, Cat::Lu),
+    (0x3ED, Cat::Ll),
+    (0x3EE, Cat::Lu),
+    (0x3EF, Cat::Ll),
+    (0x3F4, Cat::Lu),
+    (0x3F5, Cat::Ll),
+    (0x3F6, Cat::Sm),
+    (0x3F7, Cat::Lu),
+    (0x3F8, Cat::Ll),
+    (0x3F9, Cat::Lu),
+    (0x3FB, Cat::Ll),
+    (0x3FD, Cat::Lu),
+    (0x430, Cat::Ll),
+    (0x460, Cat::Lu),
+    (0x461, Cat::Ll),
+    (0x462, Cat::Lu),
+    (0x463, Cat::Ll),
+    (0x464, Cat::Lu),
+    (0x465, Cat::Ll),
+    (0x466, Cat::Lu),
+    (0x467, Cat::Ll),
+    (0x468, Cat::Lu),
+    (0x469, Cat::Ll),
+    (0x46A, Cat::Lu),
+    (0x46B, Cat::Ll),
+    (0x46C, Cat::Lu),
+    (0x46D, Cat::Ll),
+    (0x46E, Cat::Lu),
+    (0x46F, Cat::Ll),
+    (0x470, Cat::Lu),
+    (0x471, Cat::Ll),
+    (0x472, Cat::Lu),
+    (0x473, Cat::Ll),
+    (0x474, Cat::Lu),
+    (0x475, Cat::Ll),
+    (0x476, Cat::Lu),
+    (0x477, Cat::Ll),
+    (0x478, Cat::Lu),
+    (0x479, Cat::Ll),
+    (0x47A, Cat::Lu),
+    (0x47B, Cat::Ll),
+    (0x47C, Cat::Lu),
+    (0x47D, Cat::Ll),
+    (0x47E, Cat::Lu),
+    (0x47F, Cat::Ll),
+    (0x480, Cat::Lu),
+    (0x481, Cat::Ll),
+    (0x482, Cat::So),
+    (0x483, Cat::Mn),
+    (0x488, Cat::Me),
+    (0x48A, Cat::Lu),
+    (0x48B, Cat::Ll),
+    (0x48C, Cat::Lu),
+    (0x48D, Cat::Ll),
+    (0x48E, Cat::Lu),
+    (0x48F, Cat::Ll),
+    (0x490, Cat::Lu),
+    (0x491, Cat::Ll),
+    (0x492, Cat::Lu),
+    (0x493, Cat::Ll),
+    (0x494, Cat::Lu),
+    (0x495, Cat::Ll),
+    (0x496, Cat::Lu),
+    (0x497, Cat::Ll),
+    (0x498, Cat::Lu),
+    (0x499, Cat::Ll),
+    (0x49A, Cat::Lu),
+    (0x49B, Cat::Ll),
+    (0x49C, Cat::Lu),
+    (0x49D, Cat::Ll),
+    (0x49E, Cat::Lu),
+    (0x49F, Cat::Ll),
+    (0x4A0, Cat::Lu),
+    (0x4A1, Cat::Ll),
+    (0x4A2, Cat::Lu),
+    (0x4A3, Cat::Ll),
+    (0x4A4, Cat::Lu),
+    (0x4A5, Cat::Ll),
+    (0x4A6, Cat::Lu),
+    (0x4A7, Cat::Ll),
+    (0x4A8, Cat::Lu),
+    (0x4A9, Cat::Ll),
+    (0x4AA, Cat::Lu),
+    (0x4AB, Cat::Ll),
+    (0x4AC, Cat::Lu),
+    (0x4AD, Cat::Ll),
+    (0x4AE, Cat::Lu),
+    (0x4AF, Cat::Ll),
+    (0x4B0, Cat::Lu),
+    (0x4B1, Cat::Ll),
+    (0x4B2, Cat::Lu),
+    (0x4B3, Cat::Ll),
+    (0x4B4, Cat::Lu),
+    (0x4B5, Cat::Ll),
+    (0x4B6, Cat::Lu),
+    (0x4B7, Cat::Ll),
+    (0x4B8, Cat::Lu),
+    (0x4B9, Cat::Ll),
+    (0x4BA, Cat::Lu),
+    (0x4BB, Cat::Ll),
+    (0x4BC, Cat::Lu),
+    (0x4BD, Cat::Ll),
+    (0x4BE, Cat::Lu),
+    (0x4BF, Cat::Ll),
+    (0x4C0, Cat::Lu),
+    (0x4C2, Cat::Ll),
+    (0x4C3, Cat::Lu),
+    (0x4C4, Cat::Ll),
+    (0x4C5, Cat::Lu),
+    (0x4C6, Cat::Ll),
+    (0x4C7, Cat::Lu),
+    (0x4C8, Cat::Ll),
+    (0x4C9, Cat::Lu),
+    (0x4CA, Cat::Ll),
+    (0x4CB, Cat::Lu),
+    (0x4CC, Cat::Ll),
+    (0x4CD, Cat::Lu),
+    (0x4CE, Cat::Ll),
+    (0x4D0, Cat::Lu),
+    (0x4D1, Cat::Ll),
+    (0x4D2, Cat::Lu),
+    (0x4D3, Cat::Ll),
+    (0x4D4, Cat::Lu),
+    (0x4D5, Cat::Ll),
+    (0x4D6, Cat::Lu),
+    (0x4D7, Cat::Ll),
+    (0x4D8, Cat::Lu),
+    (0x4D9, Cat::Ll),
+    (0x4DA, Cat::Lu),
+    (0x4DB, Cat::Ll),
+    (0x4DC, Cat::Lu),
+    (0x4DD, Cat::Ll),
+    (0x4DE, Cat::Lu),
+    (0x4DF, Cat::Ll),
+    (0x4E0, Cat::Lu),
+    (0x4E1, Cat::Ll),
+    (0x4E2, Cat::Lu),
+    (0x4E3, Cat::Ll),
+    (0x4E4, Cat::Lu),
+    (0x4E5, Cat::Ll),
+    (0x4E6, Cat::Lu),
+    (0x4E7, Cat::Ll),
+    (0x4E8, Cat::Lu),
+    (0x4E9, Cat::Ll),
+    (0x4EA, Cat::Lu),
+    (0x4EB, Cat::Ll),
+    (0x4EC, Cat::Lu),
+    (0x4ED, Cat::Ll),
+    (0x4EE, Cat::Lu),
+    (0x4EF, Cat::Ll),
+    (0x4F0, Cat::Lu),
+    (0x4F1, Cat::Ll),
+    (0x4F2, Cat::Lu),
+    (0x4F3, Cat::Ll),
+    (0x4F4, Cat::Lu),
+    (0x4F5, Cat::Ll),
+    (0x4F6, Cat::Lu),
+    (0x4F7, Cat::Ll),
+    (0x4F8, Cat::Lu),
+    (0x4F9, Cat::Ll),
+    (0x4FA, Cat::Lu),
+    (0x4FB, Cat::Ll),
+    (0x4FC, Cat::Lu),
+    (0x4FD, Cat::Ll),
+    (0x4FE, Cat::Lu),
+    (0x4FF, Cat::Ll),
+    (0x500, Cat::Lu),
+    (0x501, Cat::Ll),
+    (0x502, Cat::Lu),
+    (0x503, Cat::Ll),
+    (0x504, Cat::Lu),
+    (0x505, Cat::Ll),
+    (0x506, Cat::Lu),
+    (0x507, Cat::Ll),
+    (0x508, Cat::Lu),
+    (0x509, Cat::Ll),
+    (0x50A, Cat::Lu),
+    (0x50B, Cat::Ll),
+    (0x50C, Cat::Lu),
+    (0x50D, Cat::Ll),
+    (0x50E, Cat::Lu),
+    (0x50F, Cat::Ll),
+    (0x510, Cat::Lu),
+    (0x511, Cat::Ll),
+    (0x512, Cat::Lu),
+    (0x513, Cat::Ll),
+    (0x514, Cat::Lu),
+    (0x515, Cat::Ll),
+    (0x516, Cat::Lu),
+    (0x517, Cat::Ll),
+    (0x518, Cat::Lu),
+    (0x519, Cat::Ll),
+    (0x51A, Cat::Lu),
+    (0x51B, Cat::Ll),
+    (0x51C, Cat::Lu),
+    (0x51D, Cat::Ll),
+    (0x51E, Cat::Lu),
+    (0x51F, Cat::Ll),
+    (0x520, Cat::Lu),
+    (0x521, Cat::Ll),
+    (0x522, Cat::Lu),
+    (0x523, Cat::Ll),
+    (0x524, Cat::Lu),
+    (0x525, Cat::Ll),
+    (0x526, Cat::Lu),
+    (0x527, Cat::Ll),
+    (0x528, Cat::Lu),
+    (0x529, Cat::Ll),
+    (0x52A, Cat::Lu),
+    (0x52B, Cat::Ll),
+    (0x52C, Cat::Lu),
+    (0x52D, Cat::Ll),
+    (0x52E, Cat::Lu),
+    (0x52F, Cat::Ll),
+    (0x530, Cat::Cn),
+    (0x531, Cat::Lu),
+    (0x557, Cat::Cn),
+    (0x559, Cat::Lm),
+    (0x55A, Cat::Po),
+    (0x560, Cat::Ll),
+    (0x589, Cat::Po),
+    (0x58A, Cat::Pd),
+    (0x58B, Cat::Cn),
+    (0x58D, Cat::So),
+    (0x58F, Cat::Sc),
+    (0x590, Cat::Cn),
+    (0x591, Cat::Mn),
+    (0x5BE, Cat::Pd),
+    (0x5BF, Cat::Mn),
+    (0x5C0, Cat::Po),
+    (0x5C1, Cat::Mn),
+    (0x5C3, Cat::Po),
+    (0x5C4, Cat::Mn),
+    (0x5C6, Cat::Po),
+    (0x5C7, Cat::Mn),
+    (0x5C8, Cat::Cn),
+    (0x5D0, Cat::Lo),
+    (0x5EB, Cat::Cn),
+    (0x5EF, Cat::Lo),
+    (0x5F3, Cat::Po),
+    (0x5F5, Cat::Cn),
+    (0x600, Cat::Cf),
+    (0x606, Cat::Sm),
+    (0x609, Cat::Po),
+    (0x60B, Cat::Sc),
+    (0x60C, Cat::Po),
+    (0x60E, Cat::So),
+    (0x610, Cat::Mn),
+    (0x61B, Cat::Po),
+    (0x61C, Cat::Cf),
+    (0x61D, Cat::Po),
+    (0x620, Cat::Lo),
+    (0x640, Cat::Lm),
+    (0x641, Cat::Lo),
+    (0x64B, Cat::Mn),
+    (0x660, Cat::Nd),
+    (0x66A, Cat::Po),
+    (0x66E, Cat::Lo),
+    (0x670, Cat::Mn),
+    (0x671, Cat::Lo),
+    (0x6D4, Cat::Po),
+    (0x6D5, Cat::Lo),
+    (0x6D6, Cat::Mn),
+    (0x6DD, Cat::Cf),
+    (0x6DE, Cat::So),
+    (0x6DF, Cat::Mn),
+    (0x6E5, Cat::Lm),
+    (0x6E7, Cat::Mn),
+    (0x6E9, Cat::So),
+    (0x6EA, Cat::Mn),
+    (0x6EE, Cat::Lo),
+    (0x6F0, Cat::Nd),
+    (0x6FA, Cat::Lo),
+    (0x6FD, Cat::So),
+    (0x6FF, Cat::Lo),
+    (0x700, Cat::Po),
+    (0x70E, Cat::Cn),
+    (0x70F, Cat::Cf),
+    (0x710, Cat::Lo),
+    (0x711, Cat::Mn),
+    (0x712, Cat::Lo),
+    (0x730, Cat::Mn),
+    (0x74B, Cat::Cn),
+    (0x74D, Cat::Lo),
+    (0x7A6, Cat::Mn),
+    (0x7B1, Cat::Lo),
+    (0x7B2, Cat::Cn),
+    (0x7C0, Cat::Nd),
+    (0x7CA, Cat::Lo),
+    (0x7EB, Cat::Mn),
+    (0x7F4, Cat::Lm),
+    (0x7F6, Cat::So),
+    (0x7F7, Cat::Po),
+    (0x7FA, Cat::Lm),
+    (0x7FB, Cat::Cn),
+    (0x7FD, Cat::Mn),
+    (0x7FE, Cat::Sc),
+    (0x800, Cat::Lo),
+    (0x816, Cat::Mn),
+    (0x81A, Cat::Lm),
+    (0x81B, Cat::Mn),
+    (0x824, Cat::Lm),
+    (0x825, Cat::Mn),
+    (0x828, Cat::Lm),
+    (0x829, Cat::Mn),
+    (0x82E, Cat::Cn),
+    (0x830, Cat::Po),
+    (0x83F, Cat::Cn),
+    (0x840, Cat::Lo),
+    (0x859, Cat::Mn),
+    (0x85C, Cat::Cn),
+    (0x85E, Cat::Po),
+    (0x85F, Cat::Cn),
+    (0x860, Cat::Lo),
+    (0x86B, Cat::Cn),
+    (0x870, Cat::Lo),
+    (0x888, Cat::Sk),
+    (0x889, Cat::Lo),
+    (0x88F, Cat::Cn),
+    (0x890, Cat::Cf),
+    (0x892, Cat::Cn),
+    (0x898, Cat::Mn),
+    (0x8A0, Cat::Lo),
+    (0x8C9, Cat::Lm),
+    (0x8CA, Cat::Mn),
+    (0x8E2, Cat::Cf),
+    (0x8E3, Cat::Mn),
+    (0x903, Cat::Mc),
+    (0x904, Cat::Lo),
+    (0x93A, Cat::Mn),
+    (0x93B, Cat::Mc),
+    (0x93C, Cat::Mn),
+    (0x93D, Cat::Lo),
+    (0x93E, Cat::Mc),
+    (0x941, Cat::Mn),
+    (0x949, Cat::Mc),
+    (0x94D, Cat::Mn),
+    (0x94E, Cat::Mc),
+    (0x950, Cat::Lo),
+    (0x951, Cat::Mn),
+    (0x958, Cat::Lo),
+    (0x962, Cat::Mn),
+    (0x964, Cat::Po),
+    (0x966, Cat::Nd),
+    (0x970, Cat::Po),
+    (0x971, Cat::Lm),
+    (0x972, Cat::Lo),
+    (0x981, Cat::Mn),
+    (0x982, Cat::Mc),
+    (0x984, Cat::Cn),
+    (0x985, Cat::Lo),
+    (0x98D, Cat::Cn),
+    (0x98F, Cat::Lo),
+    (0x991, Cat::Cn),
+    (0x993, Cat::Lo),
+    (0x9A9, Cat::Cn),
+    (0x9AA, Cat::Lo),
+    (0x9B1, Cat::Cn),
+    (0x9B2, Cat::Lo),
+    (0x9B3, Cat::Cn),
+    (0x9B6, Cat::Lo),
+    (0x9BA, Cat::Cn),
+    (0x9BC, Cat::Mn),
+    (0x9BD, Cat::Lo),
+    (0x9BE, Cat::Mc),
+    (0x9C1, Cat::Mn),
+    (0x9C5, Cat::Cn),
+    (0x9C7, Cat::Mc),
+    (0x9C9, Cat::Cn),
+    (0x9CB, Cat::Mc),
+    (0x9CD, Cat::Mn),
+    (0x9CE, Cat::Lo),
+    (0x9CF, Cat::Cn),
+    (0x9D7, Cat::Mc),
+    (0x9D8, Cat::Cn),
+    (0x9DC, Cat::Lo),
+    (0x9DE, Cat::Cn),
+    (0x9DF, Cat::Lo),
+    (0x9E2, Cat::Mn),
+    (0x9E4, Cat::Cn),
+    (0x9E6, Cat::Nd),
+    (0x9F0, Cat::Lo),
+    (0x9F2, Cat::Sc),
+    (0x9F4, Cat::No),
+    (0x9FA, Cat::So),
+    (0x9FB, Cat::Sc),
+    (0x9FC, Cat::Lo),
+    (0x9FD, Cat::Po),
+    (0x9FE, Cat::Mn),
+    (0x9FF, Cat::Cn),
+    (0xA01, Cat::Mn),
+    (0xA03, Cat::Mc),
+    (0xA04, Cat::Cn),
+    (0xA05, Cat::Lo),
+    (0xA0B, Cat::Cn),
+    (0xA0F, Cat::Lo),
+    (0xA11, Cat::Cn),
+    (0xA13, Cat::Lo),
+    (0xA29, Cat::Cn),
+    (0xA2A, Cat::Lo),
+    (0xA31, Cat::Cn),
+    (0xA32, Cat::Lo),
+    (0xA34, Cat::Cn),
+    (0xA35, Cat::Lo),
+    (0xA37, Cat::Cn),
+    (0xA38, Cat::Lo),
+    (0xA3A, Cat::Cn),
+    (0xA3C, Cat::Mn),
+    (0xA3D, Cat::Cn),
+    (0xA3E, Cat::Mc),
+    (0xA41, Cat::Mn),
+    (0xA43, Cat::Cn),
+    (0xA47, Cat::Mn),
+    (0xA49, Cat::Cn),
+    (0xA4B, Cat::Mn),
+    (0xA4E, Cat::Cn),
+    (0xA51, Cat::Mn),
+    (0xA52, Cat::Cn),
+    (0xA59, Cat::Lo),
+    (0xA5D, Cat::Cn),
+    (0xA5E, Cat::Lo),
+    (0xA5F, Cat::Cn),
+    (0xA66, Cat::Nd),
+    (0xA70, Cat::Mn),
+    (0xA72, Cat::Lo),
+    (0xA75, Cat::Mn),
+    (0xA76, Cat::Po),
+    (0xA77, Cat::Cn),
+    (0xA81, Cat::Mn),
+    (0xA83, Cat::Mc),
+    (0xA84, Cat::Cn),
+    (0xA85, Cat::Lo),
+    (0xA8E, Cat::Cn),
+    (0xA8F, Cat::Lo),
+    (0xA92, Cat::Cn),
+    (0xA93, Cat::Lo),
+    (0xAA9, Cat::Cn),
+    (0xAAA, Cat::Lo),
+    (0xAB1, Cat::Cn),
+    (0xAB2, Cat::Lo),
+    (0xAB4, Cat::Cn),
+    (0xAB5, Cat::Lo),
+    (0xABA, Cat::Cn),
+    (0xABC, Cat::Mn),
+    (0xABD, Cat::Lo),
+    (0xABE, Cat::Mc),
+    (0xAC1, Cat::Mn),
+    (0xAC6, Cat::Cn),
+    (0xAC7, Cat::Mn),
+    (0xAC9, Cat::Mc),
+    (0xACA, Cat::Cn),
+    (0xACB, Cat::Mc),
+    (0xACD, Cat::Mn),
+    (0xACE, Cat::Cn),
+    (0xAD0, Cat::Lo),
+    (0xAD1, Cat::Cn),
+    (0xAE0, Cat::Lo),
+    (0xAE2, Cat::Mn),
+    (0xAE4, Cat::Cn),
+    (0xAE6, Cat::Nd),
+    (0xAF0, Cat::Po),
+    (0xAF1, Cat::Sc),
+    (0xAF2, Cat::Cn),
+    (0xAF9, Cat::Lo),
+    (0xAFA, Cat::Mn),
+    (0xB00, Cat::Cn),
+    (0xB01, Cat::Mn),
+    (0xB02, Cat::Mc),
+    (0xB04, Cat::Cn),
+    (0xB05, Cat::Lo),
+    (0xB0D, Cat::Cn),
+    (0xB0F, Cat::Lo),
+    (0xB11, Cat::Cn),
+    (0xB13, Cat::Lo),
+    (0xB29, Cat::Cn),
+    (0xB2A, Cat::Lo),
+    (0xB31, Cat::Cn),
+    (0xB32, Cat::Lo),
+    (0xB34, Cat::Cn),
+    (0xB35, Cat::Lo),
+    (0xB3A, Cat::Cn),
+    (0xB3C, Cat::Mn),
+    (0xB3D, Cat::Lo),
+    (0xB3E, Cat::Mc),
+    (0xB3F, Cat::Mn),
+    (0xB40, Cat::Mc),
+    (0xB41, Cat::Mn),
+    (0xB45, Cat::Cn),
+    (0xB47, Cat::Mc),
+    (0xB49, Cat::Cn),
+    (0xB4B, Cat::Mc),
+    (0xB4D, Cat::Mn),
+    (0xB4E, Cat::Cn),
+    (0xB55, Cat::Mn),
+    (0xB57, Cat::Mc),
+    (0xB58, Cat::Cn),
+    (0xB5C, Cat::Lo),
+    (0xB5E, Cat::Cn),
+    (0xB5F, Cat::Lo),
+    (0xB62, Cat::Mn),
+    (0xB64, Cat::Cn),
+    (0xB66, Cat::Nd),
+    (0xB70, Cat::So),
+    (0xB71, Cat::Lo),
+    (0xB72, Cat::No),
+    (0xB78, Cat::Cn),
+    (0xB82, Cat::Mn),
+    (0xB83, Cat::Lo),
+    (0xB84, Cat::Cn),
+    (0xB85, Cat::Lo),
+    (0xB8B, Cat::Cn),
+    (0xB8E, Cat::Lo),
+    (0xB91, Cat::Cn),
+    (0xB92, Cat::Lo),
+    (0xB96, Cat::Cn),
+    (0xB99, Cat::Lo),
+    (0xB9B, Cat::Cn),
+    (0xB9C, Cat::Lo),
+    (0xB9D, Cat::Cn),
+    (0xB9E, Cat::Lo),
+    (0xBA0, Cat::Cn),
+    (0xBA3, Cat::Lo),
+    (0xBA5, Cat::Cn),
+    (0xBA8, Cat::Lo),
+    (0xBAB, Cat::Cn),
+    (0xBAE, Cat::Lo),
+    (0xBBA, Cat::Cn),
+    (0xBBE, Cat::Mc),
+    (0xBC0, Cat::Mn),
+    (0xBC1, Cat::Mc),
+    (0xBC3, Cat::Cn),
+    (0xBC6, Cat::Mc),
+    (0xBC9, Cat::Cn),
+    (0xBCA, Cat::Mc),
+    (0xBCD, Cat::Mn),
+    (0xBCE, Cat::Cn),
+    (0xBD0, Cat::Lo),
+    (0xBD1, Cat::Cn),
+    (0xBD7, Cat::Mc),
+    (0xBD8, Cat::Cn),
+    (0xBE6, Cat::Nd),
+    (0xBF0, Cat::No),
+    (0xBF3, Cat::So),
+    (0xBF9, Cat::Sc),
+    (0xBFA, Cat::So),
+    (0xBFB, Cat::Cn),
+    (0xC00, Cat::Mn),
+    (0xC01, Cat::Mc),
+    (0xC04, Cat::Mn),
+    (0xC05, Cat::Lo),
+    (0xC0D, Cat::Cn),
+    (0xC0E, Cat::Lo),
+    (0xC11, Cat::Cn),
+    (0xC12, Cat::Lo),
+    (0xC29, Cat::Cn),
+    (0xC2A, Cat::Lo),
+    (0xC3A, Cat::Cn),
+    (0xC3C, Cat::Mn),
+    (0xC3D, Cat::Lo),
+    (0xC3E, Cat::Mn),
+    (0xC41, Cat::Mc),
+    (0xC45, Cat::Cn),
+    (0xC46, Cat::Mn),
+    (0xC49, Cat::Cn),
+    (0xC4A, Cat::Mn),
+    (0xC4E, Cat::Cn),
+    (0xC55, Cat::Mn),
+    (0xC57, Cat::Cn),
+    (0xC58, Cat::Lo),
+    (0xC5B, Cat::Cn),
+    (0xC5D, Cat::Lo),
+    (0xC5E, Cat::Cn),
+    (0xC60, Cat::Lo),
+    (0xC62, Cat::Mn),
+    (0xC64, Cat::Cn),
+    (0xC66, Cat::Nd),
+    (0xC70, Cat::Cn),
+    (0xC77, Cat::Po),
+    (0xC78, Cat::No),
+    (0xC7F, Cat::So),
+    (0xC80, Cat::Lo),
+    (0xC81, Cat::Mn),
+    (0xC82, Cat::Mc),
+    (0xC84, Cat::Po),
+    (0xC85, Cat::Lo),
+    (0xC8D, Cat::Cn),
+    (0xC8E, Cat::Lo),
+    (0xC91, Cat::Cn),
+    (0xC92, Cat::Lo),
+    (0xCA9, Cat::Cn),
+    (0xCAA, Cat::Lo),
+    (0xCB4, Cat::Cn),
+    (0xCB5, Cat::Lo),
+    (0xCBA, Cat::Cn),
+    (0xCBC, Cat::Mn),
+    (0xCBD, Cat::Lo),
+    (0xCBE, Cat::Mc),
+    (0xCBF, Cat::Mn),
+    (0xCC0, Cat::Mc),
+    (0xCC5, Cat::Cn),
+    (0xCC6, Cat::Mn),
+    (0xCC7, Cat::Mc),
+    (0xCC9, Cat::Cn),
+    (0xCCA, Cat::Mc),
+    (0xCCC, Cat::Mn),
+    (0xCCE, Cat::Cn),
+    (0xCD5, Cat::Mc),
+    (0xCD7, Cat::Cn),
+    (0xCDD, Cat::Lo),
+    (0xCDF, Cat::Cn),
+    (0xCE0, Cat::Lo),
+    (0xCE2, Cat::Mn),
+    (0xCE4, Cat::Cn),
+    (0xCE6, Cat::Nd),
+    (0xCF0, Cat::Cn),
+    (0xCF1, Cat::Lo),
+    (0xCF3, Cat::Cn),
+    (0xD00, Cat::Mn),
+    (0xD02, Cat::Mc),
+    (0xD04, Cat::Lo),
+    (0xD0D, Cat::Cn),
+    (0xD0E, Cat::Lo),
+    (0xD11, Cat::Cn),
+    (0xD12, Cat::Lo),
+    (0xD3B, Cat::Mn),
+    (0xD3D, Cat::Lo),
+    (0xD3E, Cat::Mc),
+    (0xD41, Cat::Mn),
+    (0xD45, Cat::Cn),
+    (0xD46, Cat::Mc),
+    (0xD49, Cat::Cn),
+    (0xD4A, Cat::Mc),
+    (0xD4D, Cat::Mn),
+    (0xD4E, Cat::Lo),
+    (0xD4F, Cat::So),
+    (0xD50, Cat::Cn),
+    (0xD54, Cat::Lo),
+    (0xD57, Cat::Mc),
+    (0xD58, Cat::No),
+    (0xD5F, Cat::Lo),
+    (0xD62, Cat::Mn),
+    (0xD64, Cat::Cn),
+    (0xD66, Cat::Nd),
+    (0xD70, Cat::No),
+    (0xD79, Cat::So),
+    (0xD7A, Cat::Lo),
+    (0xD80, Cat::Cn),
+    (0xD81, Cat::Mn),
+    (0xD82, Cat::Mc),
+    (0xD84, Cat::Cn),
+    (0xD85, Cat::Lo),
+    (0xD97, Cat::Cn),
+    (0xD9A, Cat::Lo),
+    (0xDB2, Cat::Cn),
+    (0xDB3, Cat::Lo),
+    (0xDBC, Cat::Cn),
+    (0xDBD, Cat::Lo),
+    (0xDBE, Cat::Cn),
+    (0xDC0, Cat::Lo),
+    (0xDC7, Cat::Cn),
+    (0xDCA, Cat::Mn),
+    (0xDCB, Cat::Cn),
+    (0xDCF, Cat::Mc),
+    (0xDD2, Cat::Mn),
+    (0xDD5, Cat::Cn),
+    (0xDD6, Cat::Mn),
+    (0xDD7, Cat::Cn),
+    (0xDD8, Cat::Mc),
+    (0xDE0, Cat::Cn),
+    (0xDE6, Cat::Nd),
+    (0xDF0, Cat::Cn),
+    (0xDF2, Cat::Mc),
+    (0xDF4, Cat::Po),
+    (0xDF5, Cat::Cn),
+    (0xE01, Cat::Lo),
+    (0xE31, Cat::Mn),
+    (0xE32, Cat::Lo),
+    (0xE34, Cat::Mn),
+    (0xE3B, Cat::Cn),
+    (0xE3F, Cat::Sc),
+    (0xE40, Cat::Lo),
+    (0xE46, Cat::Lm),
+    (0xE47, Cat::Mn),
+    (0xE4F, Cat::Po),
+    (0xE50, Cat::Nd),
+    (0xE5A, Cat::Po),
+    (0xE5C, Cat::Cn),
+    (0xE81, Cat::Lo),
+    (0xE83, Cat::Cn),
+    (0xE84, Cat::Lo),
+    (0xE85, Cat::Cn),
+    (0xE86, Cat::Lo),
+    (0xE8B, Cat::Cn),
+    (0xE8C, Cat::Lo),
+    (0xEA4, Cat::Cn),
+    (0xEA5, Cat::Lo),
+    (0xEA6, Cat::Cn),
+    (0xEA7, Cat::Lo),
+    (0xEB1, Cat::Mn),
+    (0xEB2, Cat::Lo),
+    (0xEB4, Cat::Mn),
+    (0xEBD, Cat::Lo),
+    (0xEBE, Cat::Cn),
+    (0xEC0, Cat::Lo),
+    (0xEC5, Cat::Cn),
+    (0xEC6, Cat::Lm),
+    (0xEC7, Cat::Cn),
+    (0xEC8, Cat::Mn),
+    (0xECE, Cat::Cn),
+    (0xED0, Cat::Nd),
+    (0xEDA, Cat::Cn),
+    (0xEDC, Cat::Lo),
+    (0xEE0, Cat::Cn),
+    (0xF00, Cat::Lo),
+    (0xF01, Cat::So),
+    (0xF04, Cat::Po),
+    (0xF13, Cat::So),
+    (0xF14, Cat::Po),
+    (0xF15, Cat::So),
+    (0xF18, Cat::Mn),
+    (0xF1A, Cat::So),
+    (0xF20, Cat::Nd),
+    (0xF2A, Cat::No),
+    (0xF34, Cat::So),
+    (0xF35, Cat::Mn),
+    (0xF36, Cat::So),
+    (0xF37, Cat::Mn),
+    (0xF38, Cat::So),
+    (0xF39, Cat::Mn),
+    (0xF3A, Cat::Ps),
+    (0xF3B, Cat::Pe),
+    (0xF3C, Cat::Ps),
+    (0xF3D, Cat::Pe),
+    (0xF3E, Cat::Mc),
+    (0xF40, Cat::Lo),
+    (0xF48, Cat::Cn),
+    (0xF49, Cat::Lo),
+    (0xF6D, Cat::Cn),
+    (0xF71, Cat::Mn),
+    (0xF7F, Cat::Mc),
+    (0xF80, Cat::Mn),
+    (0xF85, Cat::Po),
+    (0xF86, Cat::Mn),
+    (0xF88, Cat::Lo),
+    (0xF8D, Cat::Mn),
+    (0xF98, Cat::Cn),
+    (0xF99, Cat::Mn),
+    (0xFBD, Cat::Cn),
+    (0xFBE, Cat::So),
+    (0xFC6, Cat::Mn),
+    (0xFC7, Cat::So),
+    (0xFCD, Cat::Cn),
+    (0xFCE, Cat::So),
+    (0xFD0, Cat::Po),
+    (0xFD5, Cat::So),
+    (0xFD9, Cat::Po),
+    (0xFDB, Cat::Cn),
+    (0x1000, Cat::Lo),
+    (0x102B, Cat::Mc),
+    (0x102D, Cat::Mn),
+    (0x1031, Cat::Mc),
+    (0x1032, Cat::Mn),
+    (0x1038, Cat::Mc),
+    (0x1039, Cat::Mn),
+    (0x103B, Cat::Mc),
+    (0x103D, Cat::Mn),
+    (0x103F, Cat::Lo),
+    (0x1040, Cat::Nd),
+    (0x104A, Cat::Po),
+    (0x1050, Cat::Lo),
+    (0x1056, Cat::Mc),
+    (0x1058, Cat::Mn),
+    (0x105A, Cat::Lo),
+    (0x105E, Cat::Mn),
+    (0x1061, Cat::Lo),
+    (0x1062, Cat::Mc),
+    (0x1065, Cat::Lo),
+    (0x1067, Cat::Mc),
+    (0x106E, Cat::Lo),
+    (0x1071, Cat::Mn),
+    (0x1075, Cat::Lo),
+    (0x1082, Cat::Mn),
+    (0x1083, Cat::Mc),
+    (0x1085, Cat::Mn),
+    (0x1087, Cat::Mc),
+    (0x108D, Cat::Mn),
+    (0x108E, Cat::Lo),
+    (0x108F, Cat::Mc),
+    (0x1090, Cat::Nd),
+    (0x109A, Cat::Mc),
+    (0x109D, Cat::Mn),
+    (0x109E, Cat::So),
+    (0x10A0, Cat::Lu),
+    (0x10C6, Cat::Cn),
+    (0x10C7, Cat::Lu),
+    (0x10C8, Cat::Cn),
+    (0x10CD, Cat::Lu),
+    (0x10CE, Cat::Cn),
+    (0x10D0, Cat::Ll),
+    (0x10FB, Cat::Po),
+    (0x10FC, Cat::Lm),
+    (0x10FD, Cat::Ll),
+    (0x1100, Cat::Lo),
+    (0x1249, Cat::Cn),
+    (0x124A, Cat::Lo),
+    (0x124E, Cat::Cn),
+    (0x1250, Cat::Lo),
+    (0x1257, Cat::Cn),
+    (0x1258, Cat::Lo),
+    (0x1259, Cat::Cn),
+    (0x125A, Cat::Lo),
+    (0x125E, Cat::Cn),
+    (0x1260, Cat::Lo),
+    (0x1289, Cat::Cn),
+    (0x128A, Cat::Lo),
+    (0x128E, Cat::Cn),
+    (0x1290, Cat::Lo),
+    (0x12B1, Cat::Cn),
+    (0x12B2, Cat::Lo),
+    (0x12B6, Cat::Cn),
+    (0x12B8, Cat::Lo),
+    (0x12BF, Cat::Cn),
+    (0x12C0, Cat::Lo),
+    (0x12C1, Cat::Cn),
+    (0x12C2, Cat::Lo),
+    (0x12C6, Cat::Cn),
+    (0x12C8, Cat::Lo),
+    (0x12D7, Cat::Cn),
+    (0x12D8, Cat::Lo),
+    (0x1311, Cat::Cn),
+    (0x1312, Cat::Lo),
+    (0x1316, Cat::Cn),
+    (0x1318, Cat::Lo),
+    (0x135B, Cat::Cn),
+    (0x135D, Cat::Mn),
+    (0x1360, Cat::Po),
+    (0x1369, Cat::No),
+    (0x137D, Cat::Cn),
+    (0x1380, Cat::Lo),
+    (0x1390, Cat::So),
+    (0x139A, Cat::Cn),
+    (0x13A0, Cat::Lu),
+    (0x13F6, Cat::Cn),
+    (0x13F8, Cat::Ll),
+    (0x13FE, Cat::Cn),
+    (0x1400, Cat::Pd),
+    (0x1401, Cat::Lo),
+    (0x166D, Cat::So),
+    (0x166E, Cat::Po),
+    (0x166F, Cat::Lo),
+    (0x1680, Cat::Zs),
+    (0x1681, Cat::Lo),
+    (0x169B, Cat::Ps),
+    (0x169C, Cat::Pe),
+    (0x169D, Cat::Cn),
+    (0x16A0, Cat::Lo),
+    (0x16EB, Cat::Po),
+    (0x16EE, Cat::Nl),
+    (0x16F1, Cat::Lo),
+    (0x16F9, Cat::Cn),
+    (0x1700, Cat::Lo),
+    (0x1712, Cat::Mn),
+    (0x1715, Cat::Mc),
+    (0x1716, Cat::Cn),
+    (0x171F, Cat::Lo),
+    (0x1732, Cat::Mn),
+    (0x1734, Cat::Mc),
+    (0x1735, Cat::Po),
+    (0x1737, Cat::Cn),
+    (0x1740, Cat::Lo),
+    (0x1752, Cat::Mn),
+    (0x1754, Cat::Cn),
+    (0x1760, Cat::Lo),
+    (0x176D, Cat::Cn),
+    (0x176E, Cat::Lo),
+    (0x1771, Cat::Cn),
+    (0x1772, Cat::Mn),
+    (0x1774, Cat::Cn),
+    (0x1780, Cat::Lo),
+    (0x17B4, Cat::Mn),
+    (0x17B6, Cat::Mc),
+    (0x17B7, Cat::Mn),
+    (0x17BE, Cat::Mc),
+    (0x17C6, Cat::Mn),
+    (0x17C7, Cat::Mc),
+    (0x17C9, Cat::Mn),
+    (0x17D4, Cat::Po),
+    (0x17D7, Cat::Lm),
+    (0x17D8, Cat::Po),
+    (0x17DB, Cat::Sc),
+    (0x17DC, Cat::Lo),
+    (0x17DD, Cat::Mn),
+    (0x17DE, Cat::Cn),
+    (0x17E0, Cat::Nd),
+    (0x17EA, Cat::Cn),
+    (0x17F0, Cat::No),
+    (0x17FA, Cat::Cn),
+    (0x1800, Cat::Po),
+    (0x1806, Cat::Pd),
+    (0x1807, Cat::Po),
+    (0x180B, Cat::Mn),
+    (0x180E, Cat::Cf),
+    (0x180F, Cat::Mn),
+    (0x1810, Cat::Nd),
+    (0x181A, Cat::Cn),
+    (0x1820, Cat::Lo),
+    (0x1843, Cat::Lm),
+    (0x1844, Cat::Lo),
+    (0x1879, Cat::Cn),
+    (0x1880, Cat::Lo),
+    (0x1885, Cat::Mn),
+    (0x1887, Cat::Lo),
+    (0x18A9, Cat::Mn),
+    (0x18AA, Cat::Lo),
+    (0x18AB, Cat::Cn),
+    (0x18B0, Cat::Lo),
+    (0x18F6, Cat::Cn),
+    (0x1900, Cat::Lo),
+    (0x191F, Cat::Cn),
+    (0x1920, Cat::Mn),
+    (0x1923, Cat::Mc),
+    (0x1927, Cat::Mn),
+    (0x1929, Cat::Mc),
+    (0x192C, Cat::Cn),
+    (0x1930, Cat::Mc),
+    (0x1932, Cat::Mn),
+    (0x1933, Cat::Mc),
+    (0x1939, Cat::Mn),
+    (0x193C, Cat::Cn),
+    (0x1940, Cat::So),
+    (0x1941, Cat::Cn),
+    (0x1944, Cat::Po),
+    (0x1946, Cat::Nd),
+    (0x1950, Cat::Lo),
+    (0x196E, Cat::Cn),
+    (0x1970, Cat::Lo),
+    (0x1975, Cat::Cn),
+    (0x1980, Cat::Lo),
+    (0x19AC, Cat::Cn),
+    (0x19B0, Cat::Lo),
+    (0x19CA, Cat::Cn),
+    (0x19D0, Cat::Nd),
+    (0x19DA, Cat::No),
+    (0x19DB, Cat::Cn),
+    (0x19DE, Cat::So),
+    (0x1A00, Cat::Lo),
+    (0x1A17, Cat::Mn),
+    (0x1A19, Cat::Mc),
+    (0x1A1B, Cat::Mn),
+    (0x1A1C, Cat::Cn),
+    (0x1A1E, Cat::Po),
+    (0x1A20, Cat::Lo),
+    (0x1A55, Cat::Mc),
+    (0x1A56, Cat::Mn),
+    (0x1A57, Cat::Mc),
+    (0x1A58, Cat::Mn),
+    (0x1A5F, Cat::Cn),
+    (0x1A60, Cat::Mn),
+    (0x1A61, Cat::Mc),
+    (0x1A62, Cat::Mn),
+    (0x1A63, Cat::Mc),
+    (0x1A65, Cat::Mn),
+    (0x1A6D, Cat::Mc),
+    (0x1A73, Cat::Mn),
+    (0x1A7D, Cat::Cn),
+    (0x1A7F, Cat::Mn),
+    (0x1A80, Cat::Nd),
+    (0x1A8A, Cat::Cn),
+    (0x1A90, Cat::Nd),
+    (0x1A9A, Cat::Cn),
+    (0x1AA0, Cat::Po),
+    (0x1AA7, Cat::Lm),
+    (0x1AA8, Cat::Po),
+    (0x1AAE, Cat::Cn),
+    (0x1AB0, Cat::Mn),
+    (0x1ABE, Cat::Me),
+    (0x1ABF, Cat::Mn),
+    (0x1ACF, Cat::Cn),
+    (0x1B00, Cat::Mn),
+    (0x1B04, Cat::Mc),
+    (0x1B05, Cat::Lo),
+    (0x1B34, Cat::Mn),
+    (0x1B35, Cat::Mc),
+    (0x1B36, Cat::Mn),
+    (0x1B3B, Cat::Mc),
+    (0x1B3C, Cat::Mn),
+    (0x1B3D, Cat::Mc),
+    (0x1B42, Cat::Mn),
+    (0x1B43, Cat::Mc),
+    (0x1B45, Cat::Lo),
+    (0x1B4D, Cat::Cn),
+    (0x1B50, Cat::Nd),
+    (0x1B5A, Cat::Po),
+    (0x1B61, Cat::So),
+    (0x1B6B, Cat::Mn),
+    (0x1B74, Cat::So),
+    (0x1B7D, Cat::Po),
+    (0x1B7F, Cat::Cn),
+    (0x1B80, Cat::Mn),
+    (0x1B82, Cat::Mc),
+    (0x1B83, Cat::Lo),
+    (0x1BA1, Cat::Mc),
+    (0x1BA2, Cat::Mn),
+    (0x1BA6, Cat::Mc),
+    (0x1BA8, Cat::Mn),
+    (0x1BAA, Cat::Mc),
+    (0x1BAB, Cat::Mn),
+    (0x1BAE, Cat::Lo),
+    (0x1BB0, Cat::Nd),
+    (0x1BBA, Cat::Lo),
+    (0x1BE6, Cat::Mn),
+    (0x1BE7, Cat::Mc),
+    (0x1BE8, Cat::Mn),
+    (0x1BEA, Cat::Mc),
+    (0x1BED, Cat::Mn),
+    (0x1BEE, Cat::Mc),
+    (0x1BEF, Cat::Mn),
+    (0x1BF2, Cat::Mc),
+    (0x1BF4, Cat::Cn),
+    (0x1BFC, Cat::Po),
+    (0x1C00, Cat::Lo),
+    (0x1C24, Cat::Mc),
+    (0x1C2C, Cat::Mn),
+    (0x1C34, Cat::Mc),
+    (0x1C36, Cat::Mn),
+    (0x1C38, Cat::Cn),
+    (0x1C3B, Cat::Po),
+    (0x1C40, Cat::Nd),
+    (0x1C4A, Cat::Cn),
+    (0x1C4D, Cat::Lo),
+    (0x1C50, Cat::Nd),
+    (0x1C5A, Cat::Lo),
+    (0x1C78, Cat::Lm),
+    (0x1C7E, Cat::Po),
+    (0x1C80, Cat::Ll),
+    (0x1C89, Cat::Cn),
+    (0x1C90, Cat::Lu),
+    (0x1CBB, Cat::Cn),
+    (0x1CBD, Cat::Lu),
+    (0x1CC0, Cat::Po),
+    (0x1CC8, Cat::Cn),
+    (0x1CD0, Cat::Mn),
+    (0x1CD3, Cat::Po),
+    (0x1CD4, Cat::Mn),
+    (0x1CE1, Cat::Mc),
+    (0x1CE2, Cat::Mn),
+    (0x1CE9, Cat::Lo),
+    (0x1CED, Cat::Mn),
+    (0x1CEE, Cat::Lo),
+    (0x1CF4, Cat::Mn),
+    (0x1CF5, Cat::Lo),
+    (0x1CF7, Cat::Mc),
+    (0x1CF8, Cat::Mn),
+    (0x1CFA, Cat::Lo),
+    (0x1CFB, Cat::Cn),
+    (0x1D00, Cat::Ll),
+    (0x1D2C, Cat::Lm),
+    (0x1D6B, Cat::Ll),
+    (0x1D78, Cat::Lm),
+    (0x1D79, Cat::Ll),
+    (0x1D9B, Cat::Lm),
+    (0x1DC0, Cat::Mn),
+    (0x1E00, Cat::Lu),
+    (0x1E01, Cat::Ll),
+    (0x1E02, Cat::Lu),
+    (0x1E03, Cat::Ll),
+    (0x1E04, Cat::Lu),
+    (0x1E05, Cat::Ll),
+    (0x1E06, Cat::Lu),
+    (0x1E07, Cat::Ll),
+    (0x1E08, Cat::Lu),
+    (0x1E09, Cat::Ll),
+    (0x1E0A, Cat::Lu),
+    (0x1E0B, Cat::Ll),
+    (0x1E0C, Cat::Lu),
+    (0x1E0D, Cat::Ll),
+    (0x1E0E, Cat::Lu),
+    (0x1E0F, Cat::Ll),
+    (0x1E10, Cat::Lu),
+    (0x1E11, Cat::Ll),
+    (0x1E12, Cat::Lu),
+    (0x1E13, Cat::Ll),
+    (0x1E14, Cat::Lu),
+    (0x1E15, Cat::Ll),
+    (0x1E16, Cat::Lu),
+    (0x1E17, Cat::Ll),
+    (0x1E18, Cat::Lu),
+    (0x1E19, Cat::Ll),
+    (0x1E1A, Cat::Lu),
+    (0x1E1B, Cat::Ll),
+    (0x1E1C, Cat::Lu),
+    (0x1E1D, Cat::Ll),
+    (0x1E1E, Cat::Lu),
+    (0x1E1F, Cat::Ll),
+    (0x1E20, Cat::Lu),
+    (0x1E21, Cat::Ll),
+    (0x1E22, Cat::Lu),
+    (0x1E23, Cat::Ll),
+    (0x1E24, Cat::Lu),
+    (0x1E25, Cat::Ll),
+    (0x1E26, Cat::Lu),
+    (0x1E27, Cat::Ll),
+    (0x1E28, Cat::Lu),
+    (0x1E29, Cat::Ll),
+    (0x1E2A, Cat::Lu),
+    (0x1E2B, Cat::Ll),
+    (0x1E2C, Cat::Lu),
+    (0x1E2D, Cat::Ll),
+    (0x1E2E, Cat::Lu),
+    (0x1E2F, Cat::Ll),
+    (0x1E30, Cat::Lu),
+    (0x1E31, Cat::Ll),
+    (0x1E32, Cat::Lu),
+    (0x1E33, Cat::Ll),
+    (0x1E34, Cat::Lu),
+    (0x1E35, Cat::Ll),
+    (0x1E36, Cat::Lu),
+    (0x1E37, Cat::Ll),
+    (0x1E38, Cat::Lu),
+    (0x1E39, Cat::Ll),
+    (0x1E3A, Cat::Lu),
+    (0x1E3B, Cat::Ll),
+    (0x1E3C, Cat::Lu),
+    (0x1E3D, Cat::Ll),
+    (0x1E3E, Cat::Lu),
+    (0x1E3F, Cat::Ll),
+    (0x1E40, Cat::Lu),
+    (0x1E41, Cat::Ll),
+    (0x1E42, Cat::Lu),
+    (0x1E43, Cat::Ll),
+    (0x1E44, Cat::Lu),
+    (0x1E45, Cat::Ll),
+    (0x1E46, Cat::Lu),
+    (0x1E47, Cat::Ll),
+    (0x1E48, Cat::Lu),
+    (0x1E49, Cat::Ll),
+    (0x1E4A, Cat::Lu),
+    (0x1E4B, Cat::Ll),
+    (0x1E4C, Cat::Lu),
+    (0x1E4D, Cat::Ll),
+    (0x1E4E, Cat::Lu),
+    (0x1E4F, Cat::Ll),
+    (0x1E50, Cat::Lu),
+    (0x1E51, Cat::Ll),
+    (0x1E52, Cat::Lu),
+    (0x1E53, Cat::Ll),
+    (0x1E54, Cat::Lu),
+    (0x1E55, Cat::Ll),
+    (0x1E56, Cat::Lu),
+    (0x1E57, Cat::Ll),
+    (0x1E58, Cat::Lu),
+    (0x1E59, Cat::Ll),
+    (0x1E5A, Cat::Lu),
+    (0x1E5B, Cat::Ll),
+    (0x1E5C, Cat::Lu),
+    (0x1E5D, Cat::Ll),
+    (0x1E5E, Cat::Lu),
+    (0x1E5F, Cat::Ll),
+    (0x1E60, Cat::Lu),
+    (0x1E61, Cat::Ll),
+    (0x1E62, Cat::Lu),
+    (0x1E63, Cat::Ll),
+    (0x1E64, Cat::Lu),
+    (0x1E65, Cat::Ll),
+    (0x1E66, Cat::Lu),
+    (0x1E67, Cat::Ll),
+    (0x1E68, Cat::Lu),
+    (0x1E69, Cat::Ll),
+    (0x1E6A, Cat::Lu),
+    (0x1E6B, Cat::Ll),
+    (0x1E6C, Cat::Lu),
+    (0x1E6D, Cat::Ll),
+    (0x1E6E, Cat::Lu),
+    (0x1E6F, Cat::Ll),
+    (0x1E70, Cat::Lu),
+    (0x1E71, Cat::Ll),
+    (0x1E72, Cat::Lu),
+    (0x1E73, Cat::Ll),
+    (0x1E74, Cat::Lu),
+    (0x1E75, Cat::Ll),
+    (0x1E76, Cat::Lu),
+    (0x1E77, Cat::Ll),
+    (0x1E78, Cat::Lu),
+    (0x1E79, Cat::Ll),
+    (0x1E7A, Cat::Lu),
+    (0x1E7B, Cat::Ll),
+    (0x1E7C, Cat::Lu),
+    (0x1E7D, Cat::Ll),
+    (0x1E7E, Cat::Lu),
+    (0x1E7F, Cat::Ll),
+    (0x1E80, Cat::Lu),
+    (0x1E81, Cat::Ll),
+    (0x1E82, Cat::Lu),
+    (0x1E83, Cat::Ll),
+    (0x1E84, Cat::Lu),
+    (0x1E85, Cat::Ll),
+    (0x1E86, Cat::Lu),
+    (0x1E87, Cat::Ll),
+    (0x1E88, Cat::Lu),
+    (0x1E89, Cat::Ll),
+    (0x1E8A, Cat::Lu),
+    (0x1E8B, Cat::Ll),
+    (0x1E8C, Cat::Lu),
+    (0x1E8D, Cat::Ll),
+    (0x1E8E, Cat::Lu),
+    (0x1E8F, Cat::Ll),
+    (0x1E90, Cat::Lu),
+    (0x1E91, Cat::Ll),
+    (0x1E92, Cat::Lu),
+    (0x1E93, Cat::Ll),
+    (0x1E94, Cat::Lu),
+    (0x1E95, Cat::Ll),
+    (0x1E9E, Cat::Lu),
+    (0x1E9F, Cat::Ll),
+    (0x1EA0, Cat::Lu),
+    (0x1EA1, Cat::Ll),
+    (0x1EA2, Cat::Lu),
+    (0x1EA3, Cat::Ll),
+    (0x1EA4, Cat::Lu),
+    (0x1EA5, Cat::Ll),
+    (0x1EA6, Cat::Lu),
+    (0x1EA7, Cat::Ll),
+    (0x1EA8, Cat::Lu),
+    (0x1EA9, Cat::Ll),
+    (0x1EAA, Cat::Lu),
+    (0x1EAB, Cat::Ll),
+    (0x1EAC, Cat::Lu),
+    (0x1EAD, Cat::Ll),
+    (0x1EAE, Cat::Lu),
+    (0x1EAF, Cat::Ll),
+    (0x1EB0, Cat::Lu),
+    (0x1EB1, Cat::Ll),
+    (0x1EB2, Cat::Lu),
+    (0x1EB3, Cat::Ll),
+    (0x1EB4, Cat::Lu),
+    (0x1EB5, Cat::Ll),
+    (0x1EB6, Cat::Lu),
+    (0x1EB7, Cat::Ll),
+    (0x1EB8, Cat::Lu),
+    (0x1EB9, Cat::Ll),
+    (0x1EBA, Cat::Lu),
+    (0x1EBB, Cat::Ll),
+    (0x1EBC, Cat::Lu),
+    (0x1EBD, Cat::Ll),
+    (0x1EBE, Cat::Lu),
+    (0x1EBF, Cat::Ll),
+    (0x1EC0, Cat::Lu),
+    (0x1EC1, Cat::Ll),
+    (0x1EC2, Cat::Lu),
+    (0x1EC3, Cat::Ll),
+    (0x1EC4, Cat::Lu),
+    (0x1EC5, Cat::Ll),
+    (0x1EC6, Cat::Lu),
+    (0x1EC7, Cat::Ll),
+    (0x1EC8, Cat::Lu),
+    (0x1EC9, Cat::Ll),
+    (0x1ECA, Cat::Lu),
+    (0x1ECB, Cat::Ll),
+    (0x1ECC, Cat::Lu),
+    (0x1ECD, Cat::Ll),
+    (0x1ECE, Cat::Lu),
+    (0x1ECF, Cat::Ll),
+    (0x1ED0, Cat::Lu),
+    (0x1ED1, Cat::Ll),
+    (0x1ED2, Cat::Lu),
+    (0x1ED3, Cat::Ll),
+    (0x1ED4, Cat::Lu),
+    (0x1ED5, Cat::Ll),
+    (0x1ED6, Cat::Lu),
+    (0x1ED7, Cat::Ll),
+    (0x1ED8, Cat::Lu),
+    (0x1ED9, Cat::Ll),
+    (0x1EDA, Cat::Lu),
+    (0x1EDB, Cat::Ll),
+    (0x1EDC, Cat::Lu),
+    (0x1EDD, Cat::Ll),
+    (0x1EDE, Cat::Lu),
+    (0x1EDF, Cat::Ll),
+    (0x1EE0, Cat::Lu),
+    (0x1EE1, Cat::Ll),
+    (0x1EE2, Cat::Lu),
+    (0x1EE3, Cat::Ll),
+    (0x1EE4, Cat::Lu),
+    (0x1EE5, Cat::Ll),
+    (0x1EE6, Cat::Lu),
+    (0x1EE7, Cat::Ll),
+    (0x1EE8, Cat::Lu),
+    (0x1EE9, Cat::Ll),
+    (0x1EEA, Cat::Lu),
+    (0x1EEB, Cat::Ll),
+    (0x1EEC, Cat::Lu),
+    (0x1EED, Cat::Ll),
+    (0x1EEE, Cat::Lu),
+    (0x1EEF, Cat::Ll),
+    (0x1EF0, Cat::Lu),
+    (0x1EF1, Cat::Ll),
+    (0x1EF2, Cat::Lu),
+    (0x1EF3, Cat::Ll),
+    (0x1EF4, Cat::Lu),
+    (0x1EF5, Cat::Ll),
+    (0x1EF6, Cat::Lu),
+    (0x1EF7, Cat::Ll),
+    (0x1EF8, Cat::Lu),
+    (0x1EF9, Cat::Ll),
+    (0x1EFA, Cat::Lu),
+    (0x1EFB, Cat::Ll),
+    (0x1EFC, Cat::Lu),
+    (0x1EFD, Cat::Ll),
+    (0x1EFE, Cat::Lu),
+    (0x1EFF, Cat::Ll),
+    (0x1F08, Cat::Lu),
+    (0x1F10, Cat::Ll),
+    (0x1F16, Cat::Cn),
+    (0x1F18, Cat::Lu),
+    (0x1F1E, Cat::Cn),
+    (0x1F20, Cat::Ll),
+    (0x1F28, Cat::Lu),
+    (0x1F30, Cat::Ll),
+    (0x1F38, Cat::Lu),
+    (0x1F40, Cat::Ll),
+    (0x1F46, Cat::Cn),
+    (0x1F48, Cat::Lu),
+    (0x1F4E, Cat::Cn),
+    (0x1F50, Cat::Ll),
+    (0x1F58, Cat::Cn),
+    (0x1F59, Cat::Lu),
+    (0x1F5A, Cat::Cn),
+    (0x1F5B, Cat::Lu),
+    (0x1F5C, Cat::Cn),
+    (0x1F5D, Cat::Lu),
+    (0x1F5E, Cat::Cn),
+    (0x1F5F, Cat::Lu),
+    (0x1F60, Cat::Ll),
+    (0x1F68, Cat::Lu),
+    (0x1F70, Cat::Ll),
+    (0x1F7E, Cat::Cn),
+    (0x1F80, Cat::Ll),
+    (0x1F88, Cat::Lt),
+    (0x1F90, Cat::Ll),
+    (0x1F98, Cat::Lt),
+    (0x1FA0, Cat::Ll),
+    (0x1FA8, Cat::Lt),
+    (0x1FB0, Cat::Ll),
+    (0x1FB5, Cat::Cn),
+    (0x1FB6, Cat::Ll),
+    (0x1FB8, Cat::Lu),
+    (0x1FBC, Cat::Lt),
+    (0x1FBD, Cat::Sk),
+    (0x1FBE, Cat::Ll),
+    (0x1FBF, Cat::Sk),
+    (0x1FC2, Cat::Ll),
+    (0x1FC5, Cat::Cn),
+    (0x1FC6, Cat::Ll),
+    (0x1FC8, Cat::Lu),
+    (0x1FCC, Cat::Lt),
+    (0x1FCD, Cat::Sk),
+    (0x1FD0, Cat::Ll),
+    (0x1FD4, Cat::Cn),
+    (0x1FD6, Cat::Ll),
+    (0x1FD8, Cat::Lu),
+    (0x1FDC, Cat::Cn),
+    (0x1FDD, Cat::Sk),
+    (0x1FE0, Cat::Ll),
+    (0x1FE8, Cat::Lu),
+    (0x1FED, Cat::Sk),
+    (0x1FF0, Cat::Cn),
+    (0x1FF2, Cat::Ll),
+    (0x1FF5, Cat::Cn),
+    (0x1FF6, Cat::Ll),
+    (0x1FF8, Cat::Lu),
+    (0x1FFC, Cat::Lt),
+    (0x1FFD, Cat::Sk),
+    (0x1FFF, Cat::Cn),
+    (0x2000, Cat::Zs),
+    (0x200B, Cat::Cf),
+    (0x2010, Cat::Pd),
+    (0x2016, Cat::Po),
+    (0x2018, Cat::Pi),
+    (0x2019, Cat::Pf),
+    (0x201A, Cat::Ps),
+    (0x201B, Cat::Pi),
+    (0x201D, Cat::Pf),
+    (0x201E, Cat::Ps),
+    (0x201F, Cat::Pi),
+    (0x2020, Cat::Po),
+    (0x2028, Cat::Zl),
+    (0x2029, Cat::Zp),
+    (0x202A, Cat::Cf),
+    (0x202F, Cat::Zs),
+    (0x2030, Cat::Po),
+    (0x2039, Cat::Pi),
+    (0x203A, Cat::Pf),
+    (0x203B, Cat::Po),
+    (0x203F, Cat::Pc),
+    (0x2041, Cat::Po),
+    (0x2044, Cat::Sm),
+    (0x2045, Cat::Ps),
+    (0x2046, Cat::Pe),
+    (0x2047, Cat::Po),
+    (0x2052, Cat::Sm),
+    (0x2053, Cat::Po),
+    (0x2054, Cat::Pc),
+    (0x2055, Cat::Po),
+    (0x205F, Cat::Zs),
+    (0x2060, Cat::Cf),
+    (0x2065, Cat::Cn),
+    (0x2066, Cat::Cf),
+    (0x2070, Cat::No),
+    (0x2071, Cat::Lm),
+    (0x2072, Cat::Cn),
+    (0x2074, Cat::No),
+    (0x207A, Cat::Sm),
+    (0x207D, Cat::Ps),
+    (0x207E, Cat::Pe),
+    (0x207F, Cat::Lm),
+    (0x2080, Cat::No),
+    (0x208A, Cat::Sm),
+    (0x208D, Cat::Ps),
+    (0x208E, Cat::Pe),
+    (0x208F, Cat::Cn),
+    (0x2090, Cat::Lm),
+    (0x209D, Cat::Cn),
+    (0x20A0, Cat::Sc),
+    (0x20C1, Cat::Cn),
+    (0x20D0, Cat::Mn),
+    (0x20DD, Cat::Me),
+    (0x20E1, Cat::Mn),
+    (0x20E2, Cat::Me),
+    (0x20E5, Cat::Mn),
+    (0x20F1, Cat::Cn),
+    (0x2100, Cat::So),
+    (0x2102, Cat::Lu),
+    (0x2103, Cat::So),
+    (0x2107, Cat::Lu),
+    (0x2108, Cat::So),
+    (0x210A, Cat::Ll),
+    (0x210B, Cat::Lu),
+    (0x210E, Cat::Ll),
+    (0x2110, Cat::Lu),
+    (0x2113, Cat::Ll),
+    (0x2114, Cat::So),
+    (0x2115, Cat::Lu),
+    (0x2116, Cat::So),
+    (0x2118, Cat::Sm),
+    (0x2119, Cat::Lu),
+    (0x211E, Cat::So),
+    (0x2124, Cat::Lu),
+    (0x2125, Cat::So),
+    (0x2126, Cat::Lu),
+    (0x2127, Cat::So),
+    (0x2128, Cat::Lu),
+    (0x2129, Cat::So),
+    (0x212A, Cat::Lu),
+    (0x212E, Cat::So),
+    (0x212F, Cat::Ll),
+    (0x2130, Cat::Lu),
+    (0x2134, Cat::Ll),
+    (0x2135, Cat::Lo),
+    (0x2139, Cat::Ll),
+    (0x213A, Cat::So),
+    (0x213C, Cat::Ll),
+    (0x213E, Cat::Lu),
+    (0x2140, Cat::Sm),
+    (0x2145, Cat::Lu),
+    (0x2146, Cat::Ll),
+    (0x214A, Cat::So),
+    (0x214B, Cat::Sm),
+    (0x214C, Cat::So),
+    (0x214E, Cat::Ll),
+    (0x214F, Cat::So),
+    (0x2150, Cat::No),
+    (0x2160, Cat::Nl),
+    (0x2183, Cat::Lu),
+    (0x2184, Cat::Ll),
+    (0x2185, Cat::Nl),
+    (0x2189, Cat::No),
+    (0x218A, Cat::So),
+    (0x218C, Cat::Cn),
+    (0x2190, Cat::Sm),
+    (0x2195, Cat::So),
+    (0x219A, Cat::Sm),
+    (0x219C, Cat::So),
+    (0x21A0, Cat::Sm),
+    (0x21A1, Cat::So),
+    (0x21A3, Cat::Sm),
+    (0x21A4, Cat::So),
+    (0x21A6, Cat::Sm),
+    (0x21A7, Cat::So),
+    (0x21AE, Cat::Sm),
+    (0x21AF, Cat::So),
+    (0x21CE, Cat::Sm),
+    (0x21D0, Cat::So),
+    (0x21D2, Cat::Sm),
+    (0x21D3, Cat::So),
+    (0x21D4, Cat::Sm),
+    (0x21D5, Cat::So),
+    (0x21F4, Cat::Sm),
+    (0x2300, Cat::So),
+    (0x2308, Cat::Ps),
+    (0x2309, Cat::Pe),
+    (0x230A, Cat::Ps),
+    (0x230B, Cat::Pe),
+    (0x230C, Cat::So),
+    (0x2320, Cat::Sm),
+    (0x2322, Cat::So),
+    (0x2329, Cat::Ps),
+    (0x232A, Cat::Pe),
+    (0x232B, Cat::So),
+    (0x237C, Cat::Sm),
+    (0x237D, Cat::So),
+    (0x239B, Cat::Sm),
+    (0x23B4, Cat::So),
+    (0x23DC, Cat::Sm),
+    (0x23E2, Cat::So),
+    (0x2427, Cat::Cn),
+    (0x2440, Cat::So),
+    (0x244B, Cat::Cn),
+    (0x2460, Cat::No),
+    (0x249C, Cat::So),
+    (0x24EA, Cat::No),
+    (0x2500, Cat::So),
+    (0x25B7, Cat::Sm),
+    (0x25B8, Cat::So),
+    (0x25C1, Cat::Sm),
+    (0x25C2, Cat::So),
+    (0x25F8, Cat::Sm),
+    (0x2600, Cat::So),
+    (0x266F, Cat::Sm),
+    (0x2670, Cat::So),
+    (0x2768, Cat::Ps),
+    (0x2769, Cat::Pe),
+    (0x276A, Cat::Ps),
+    (0x276B, Cat::Pe),
+    (0x276C, Cat::Ps),
+    (0x276D, Cat::Pe),
+    (0x276E, Cat::Ps),
+    (0x276F, Cat::Pe),
+    (0x2770, Cat::Ps),
+    (0x2771, Cat::Pe),
+    (0x2772, Cat::Ps),
+    (0x2773, Cat::Pe),
+    (0x2774, Cat::Ps),
+    (0x2775, Cat::Pe),
+    (0x2776, Cat::No),
+    (0x2794, Cat::So),
+    (0x27C0, Cat::Sm),
+    (0x27C5, Cat::Ps),
+    (0x27C6, Cat::Pe),
+    (0x27C7, Cat::Sm),
+    (0x27E6, Cat::Ps),
+    (0x27E7, Cat::Pe),
+    (0x27E8, Cat::Ps),
+    (0x27E9, Cat::Pe),
+    (0x27EA, Cat::Ps),
+    (0x27EB, Cat::Pe),
+    (0x27EC, Cat::Ps),
+    (0x27ED, Cat::Pe),
+    (0x27EE, Cat::Ps),
+    (0x27EF, Cat::Pe),
+    (0x27F0, Cat::Sm),
+    (0x2800, Cat::So),
+    (0x2900, Cat::Sm),
+    (0x2983, Cat::Ps),
+    (0x2984, Cat::Pe),
+    (0x2985, Cat::Ps),
+    (0x2986, Cat::Pe),
+    (0x2987, Cat::Ps),
+    (0x2988, Cat::Pe),
+    (0x2989, Cat::Ps),
+    (0x298A, Cat::Pe),
+    (0x298B, Cat::Ps),
+    (0x298C, Cat::Pe),
+    (0x298D, Cat::Ps),
+    (0x298E, Cat::Pe),
+    (0x298F, Cat::Ps),
+    (0x2990, Cat::Pe),
+    (0x2991, Cat::Ps),
+    (0x2992, Cat::Pe),
+    (0x2993, Cat::Ps),
+    (0x2994, Cat::Pe),
+    (0x2995, Cat::Ps),
+    (0x2996, Cat::Pe),
+    (0x2997, Cat::Ps),
+    (0x2998, Cat::Pe),
+    (0x2999, Cat::Sm),
+    (0x29D8, Cat::Ps),
+    (0x29D9, Cat::Pe),
+    (0x29DA, Cat::Ps),
+    (0x29DB, Cat::Pe),
+    (0x29DC, Cat::Sm),
+    (0x29FC, Cat::Ps),
+    (0x29FD, Cat::Pe),
+    (0x29FE, Cat::Sm),
+    (0x2B00, Cat::So),
+    (0x2B30, Cat::Sm),
+    (0x2B45, Cat::So),
+    (0x2B47, Cat::Sm),
+    (0x2B4D, Cat::So),
+    (0x2B74, Cat::Cn),
+    (0x2B76, Cat::So),
+    (0x2B96, Cat::Cn),
+    (0x2B97, Cat::So),
+    (0x2C00, Cat::Lu),
+    (0x2C30, Cat::Ll),
+    (0x2C60, Cat::Lu),
+    (0x2C61, Cat::Ll),
+    (0x2C62, Cat::Lu),
+    (0x2C65, Cat::Ll),
+    (0x2C67, Cat::Lu),
+    (0x2C68, Cat::Ll),
+    (0x2C69, Cat::Lu),
+    (0x2C6A, Cat::Ll),
+    (0x2C6B, Cat::Lu),
+    (0x2C6C, Cat::Ll),
+    (0x2C6D, Cat::Lu),
+    (0x2C71, Cat::Ll),
+    (0x2C72, Cat::Lu),
+    (0x2C73, Cat::Ll),
+    (0x2C75, Cat::Lu),
+    (0x2C76, Cat::Ll),
+    (0x2C7C, Cat::Lm),
+    (0x2C7E, Cat::Lu),
+    (0x2C81, Cat::Ll),
+    (0x2C82, Cat::Lu),
+    (0x2C83, Cat::Ll),
+    (0x2C84, Cat::Lu),
+    (0x2C85, Cat::Ll),
+    (0x2C86, Cat::Lu),
+    (0x2C87, Cat::Ll),
+    (0x2C88, Cat::Lu),
+    (0x2C89, Cat::Ll),
+    (0x2C8A, Cat::Lu),
+    (0x2C8B, Cat::Ll),
+    (0x2C8C, Cat::Lu),
+    (0x2C8D, Cat::Ll),
+    (0x2C8E, Cat::Lu),
+    (0x2C8F, Cat::Ll),
+    (0x2C90, Cat::Lu),
+    (0x2C91, Cat::Ll),
+    (0x2C92, Cat::Lu),
+    (0x2C93, Cat::Ll),
+    (0x2C94, Cat::Lu),
+    (0x2C95, Cat::Ll),
+    (0x2C96, Cat::Lu),
+    (0x2C97, Cat::Ll),
+    (0x2C98, Cat::Lu),
+    (0x2C99, Cat::Ll),
+    (0x2C9A, Cat::Lu),
+    (0x2C9B, Cat::Ll),
+    (0x2C9C, Cat::Lu),
+    (0x2C9D, Cat::Ll),
+    (0x2C9E, Cat::Lu),
+    (0x2C9F, Cat::Ll),
+    (0x2CA0, Cat::Lu),
+    (0x2CA1, Cat::Ll),
+    (0x2CA2, Cat::Lu),
+    (0x2CA3, Cat::Ll),
+    (0x2CA4, Cat::Lu),
+    (0x2CA5, Cat::Ll),
+    (0x2CA6, Cat::Lu),
+    (0x2CA7, Cat::Ll),
+    (0x2CA8, Cat::Lu),
+    (0x2CA9, Cat::Ll),
+    (0x2CAA, Cat::Lu),
+    (0x2CAB, Cat::Ll),
+    (0x2CAC, Cat::Lu),
+    (0x2CAD, Cat::Ll),
+    (0x2CAE, Cat::Lu),
+    (0x2CAF, Cat::Ll),
+    (0x2CB0, Cat::Lu),
+    (0x2CB1, Cat::Ll),
+    (0x2CB2, Cat::Lu),
+    (0x2CB3, Cat::Ll),
+    (0x2CB4, Cat::Lu),
+    (0x2CB5, Cat::Ll),
+    (0x2CB6, Cat::Lu),
+    (0x2CB7, Cat::Ll),
+    (0x2CB8, Cat::Lu),
+    (0x2CB9, Cat::Ll),
+    (0x2CBA, Cat::Lu),
+    (0x2CBB, Cat::Ll),
+    (0x2CBC, Cat::Lu),
+    (0x2CBD, Cat::Ll),
+    (0x2CBE, Cat::Lu),
+    (0x2CBF, Cat::Ll),
+    (0x2CC0, Cat::Lu),
+    (0x2CC1, Cat::Ll),
+    (0x2CC2, Cat::Lu),
+    (0x2CC3, Cat::Ll),
+    (0x2CC4, Cat::Lu),
+    (0x2CC5, Cat::Ll),
+    (0x2CC6, Cat::Lu),
+    (0x2CC7, Cat::Ll),
+    (0x2CC8, Cat::Lu),
+    (0x2CC9, Cat::Ll),
+    (0x2CCA, Cat::Lu),
+    (0x2CCB, Cat::Ll),
+    (0x2CCC, Cat::Lu),
+    (0x2CCD, Cat::Ll),
+    (0x2CCE, Cat::Lu),
+    (0x2CCF, Cat::Ll),
+    (0x2CD0, Cat::Lu),
+    (0x2CD1, Cat::Ll),
+    (0x2CD2, Cat::Lu),
+    (0x2CD3, Cat::Ll),
+    (0x2CD4, Cat::Lu),
+    (0x2CD5, Cat::Ll),
+    (0x2CD6, Cat::Lu),
+    (0x2CD7, Cat::Ll),
+    (0x2CD8, Cat::Lu),
+    (0x2CD9, Cat::Ll),
+    (0x2CDA, Cat::Lu),
+    (0x2CDB, Cat::Ll),
+    (0x2CDC, Cat::Lu),
+    (0x2CDD, Cat::Ll),
+    (0x2CDE, Cat::Lu),
+    (0x2CDF, Cat::Ll),
+    (0x2CE0, Cat::Lu),
+    (0x2CE1, Cat::Ll),
+    (0x2CE2, Cat::Lu),
+    (0x2CE3, Cat::Ll),
+    (0x2CE5, Cat::So),
+    (0x2CEB, Cat::Lu),
+    (0x2CEC, Cat::Ll),
+    (0x2CED, Cat::Lu),
+    (0x2CEE, Cat::Ll),
+    (0x2CEF, Cat::Mn),
+    (0x2CF2, Cat::Lu),
+    (0x2CF3, Cat::Ll),
+    (0x2CF4, Cat::Cn),
+    (0x2CF9, Cat::Po),
+    (0x2CFD, Cat::No),
+    (0x2CFE, Cat::Po),
+    (0x2D00, Cat::Ll),
+    (0x2D26, Cat::Cn),
+    (0x2D27, Cat::Ll),
+    (0x2D28, Cat::Cn),
+    (0x2D2D, Cat::Ll),
+    (0x2D2E, Cat::Cn),
+    (0x2D30, Cat::Lo),
+    (0x2D68, Cat::Cn),
+    (0x2D6F, Cat::Lm),
+    (0x2D70, Cat::Po),
+    (0x2D71, Cat::Cn),
+    (0x2D7F, Cat::Mn),
+    (0x2D80, Cat::Lo),
+    (0x2D97, Cat::Cn),
+    (0x2DA0, Cat::Lo),
+    (0x2DA7, Cat::Cn),
+    (0x2DA8, Cat::Lo),
+    (0x2DAF, Cat::Cn),
+    (0x2DB0, Cat::Lo),
+    (0x2DB7, Cat::Cn),
+    (0x2DB8, Cat::Lo),
+    (0x2DBF, Cat::Cn),
+    (0x2DC0, Cat::Lo),
+    (0x2DC7, Cat::Cn),
+    (0x2DC8, Cat::Lo),
+    (0x2DCF, Cat::Cn),
+    (0x2DD0, Cat::Lo),
+    (0x2DD7, Cat::Cn),
+    (0x2DD8, Cat::Lo),
+    (0x2DDF, Cat::Cn),
+    (0x2DE0, Cat::Mn),
+    (0x2E00, Cat::Po),
+    (0x2E02, Cat::Pi),
+    (0x2E03, Cat::Pf),
+    (0x2E04, Cat::Pi),
+    (0x2E05, Cat::Pf),
+    (0x2E06, Cat::Po),
+    (0x2E09, Cat::Pi),
+    (0x2E0A, Cat::Pf),
+    (0x2E0B, Cat::Po),
+    (0x2E0C, Cat::Pi),
+    (0x2E0D, Cat::Pf),
+    (0x2E0E, Cat::Po),
+    (0x2E17, Cat::Pd),
+    (0x2E18, Cat::Po),
+    (0x2E1A, Cat::Pd),
+    (0x2E1B, Cat::Po),
+    (0x2E1C, Cat::Pi),
+    (0x2E1D, Cat::Pf),
+    (0x2E1E, Cat::Po),
+    (0x2E20, Cat::Pi),
+    (0x2E21, Cat::Pf),
+    (0x2E22, Cat::Ps),
+    (0x2E23, Cat::Pe),
+    (0x2E24, Cat::Ps),
+    (0x2E25, Cat::Pe),
+    (0x2E26, Cat::Ps),
+    (0x2E27, Cat::Pe),
+    (0x2E28, Cat::Ps),
+    (0x2E29, Cat::Pe),
+    (0x2E2A, Cat::Po),
+    (0x2E2F, Cat::Lm),
+    (0x2E30, Cat::Po),
+    (0x2E3A, Cat::Pd),
+    (0x2E3C, Cat::Po),
+    (0x2E40, Cat::Pd),
+    (0x2E41, Cat::Po),
+    (0x2E42, Cat::Ps),
+    (0x2E43, Cat::Po),
+    (0x2E50, Cat::So),
+    (0x2E52, Cat::Po),
+    (0x2E55, Cat::Ps),
+    (0x2E56, Cat::Pe),
+    (0x2E57, Cat::Ps),
+    (0x2E58, Cat::Pe),
+    (0x2E59, Cat::Ps),
+    (0x2E5A, Cat::Pe),
+    (0x2E5B, Cat::Ps),
+    (0x2E5C, Cat::Pe),
+    (0x2E5D, Cat::Pd),
+    (0x2E5E, Cat::Cn),
+    (0x2E80, Cat::So),
+    (0x2E9A, Cat::Cn),
+    (0x2E9B, Cat::So),
+    (0x2EF4, Cat::Cn),
+    (0x2F00, Cat::So),
+    (0x2FD6, Cat::Cn),
+    (0x2FF0, Cat::So),
+    (0x2FFC, Cat::Cn),
+    (0x3000, Cat::Zs),
+    (0x3001, Cat::Po),
+    (0x3004, Cat::So),
+    (0x3005, Cat::Lm),
+    (0x3006, Cat::Lo),
+    (0x3007, Cat::Nl),
+    (0x3008, Cat::Ps),
+    (0x3009, Cat::Pe),
+    (0x300A, Cat::Ps),
+    (0x300B, Cat::Pe),
+    (0x300C, Cat::Ps),
+    (0x300D, Cat::Pe),
+    (0x300E, Cat::Ps),
+    (0x300F, Cat::Pe),
+    (0x3010, Cat::Ps),
+    (0x3011, Cat::Pe),
+    (0x3012, Cat::So),
+    (0x3014, Cat::Ps),
+    (0x3015, Cat::Pe),
+    (0x3016, Cat::Ps),
+    (0x3017, Cat::Pe),
+    (0x3018, Cat::Ps),
+    (0x3019, Cat::Pe),
+    (0x301A, Cat::Ps),
+    (0x301B, Cat::Pe),
+    (0x301C, Cat::Pd),
+    (0x301D, Cat::Ps),
+    (0x301E, Cat::Pe),
+    (0x3020, Cat::So),
+    (0x3021, Cat::Nl),
+    (0x302A, Cat::Mn),
+    (0x302E, Cat::Mc),
+    (0x3030, Cat::Pd),
+    (0x3031, Cat::Lm),
+    (0x3036, Cat::So),
+    (0x3038, Cat::Nl),
+    (0x303B, Cat::Lm),
+    (0x303C, Cat::Lo),
+    (0x303D, Cat::Po),
+    (0x303E, Cat::So),
+    (0x3040, Cat::Cn),
+    (0x3041, Cat::Lo),
+    (0x3097, Cat::Cn),
+    (0x3099, Cat::Mn),
+    (0x309B, Cat::Sk),
+    (0x309D, Cat::Lm),
+    (0x309F, Cat::Lo),
+    (0x30A0, Cat::Pd),
+    (0x30A1, Cat::Lo),
+    (0x30FB, Cat::Po),
+    (0x30FC, Cat::Lm),
+    (0x30FF, Cat::Lo),
+    (0x3100, Cat::Cn),
+    (0x3105, Cat::Lo),
+    (0x3130, Cat::Cn),
+    (0x3131, Cat::Lo),
+    (0x318F, Cat::Cn),
+    (0x3190, Cat::So),
+    (0x3192, Cat::No),
+    (0x3196, Cat::So),
+    (0x31A0, Cat::Lo),
+    (0x31C0, Cat::So),
+    (0x31E4, Cat::Cn),
+    (0x31F0, Cat::Lo),
+    (0x3200, Cat::So),
+    (0x321F, Cat::Cn),
+    (0x3220, Cat::No),
+    (0x322A, Cat::So),
+    (0x3248, Cat::No),
+    (0x3250, Cat::So),
+    (0x3251, Cat::No),
+    (0x3260, Cat::So),
+    (0x3280, Cat::No),
+    (0x328A, Cat::So),
+    (0x32B1, Cat::No),
+    (0x32C0, Cat::So),
+    (0x3400, Cat::Lo),
+    (0x4DC0, Cat::So),
+    (0x4E00, Cat::Lo),
+    (0xA015, Cat::Lm),
+    (0xA016, Cat::Lo),
+    (0xA48D, Cat::Cn),
+    (0xA490, Cat::So),
+    (0xA4C7, Cat::Cn),
+    (0xA4D0, Cat::Lo),
+    (0xA4F8, Cat::Lm),
+    (0xA4FE, Cat::Po),
+    (0xA500, Cat::Lo),
+    (0xA60C, Cat::Lm),
+    (0xA60D, Cat::Po),
+    (0xA610, Cat::Lo),
+    (0xA620, Cat::Nd),
+    (0xA62A, Cat::Lo),
+    (0xA62C, Cat::Cn),
+    (0xA640, Cat::Lu),
+    (0xA641, Cat::Ll),
+    (0xA642, Cat::Lu),
+    (0xA643, Cat::Ll),
+    (0xA644, Cat::Lu),
+    (0xA645, Cat::Ll),
+    (0xA646, Cat::Lu),
+    (0xA647, Cat::Ll),
+    (0xA648, Cat::Lu),
+    (0xA649, Cat::Ll),
+    (0xA64A, Cat::Lu),
+    (0xA64B, Cat::Ll),
+    (0xA64C, Cat::Lu),
+    (0xA64D, Cat::Ll),
+    (0xA64E, Cat::Lu),
+    (0xA64F, Cat::Ll),
+    (0xA650, Cat::Lu),
+    (0xA651, Cat::Ll),
+    (0xA652, Cat::Lu),
+    (0xA653, Cat::Ll),
+    (0xA654, Cat::Lu),
+    (0xA655, Cat::Ll),
+    (0xA656, Cat::Lu),
+    (0xA657, Cat::Ll),
+    (0xA658, Cat::Lu),
+    (0xA659, Cat::Ll),
+    (0xA65A, Cat::Lu),
+    (0xA65B, Cat::Ll),
+    (0xA65C, Cat::Lu),
+    (0xA65D, Cat::Ll),
+    (0xA65E, Cat::Lu),
+    (0xA65F, Cat::Ll),
+    (0xA660, Cat::Lu),
+    (0xA661, Cat::Ll),
+    (0xA662, Cat::Lu),
+    (0xA663, Cat::Ll),
+    (0xA664, Cat::Lu),
+    (0xA665, Cat::Ll),
+    (0xA666, Cat::Lu),
+    (0xA667, Cat::Ll),
+    (0xA668, Cat::Lu),
+    (0xA669, Cat::Ll),
+    (0xA66A, Cat::Lu),
+    (0xA66B, Cat::Ll),
+    (0xA66C, Cat::Lu),
+    (0xA66D, Cat::Ll),
+    (0xA66E, Cat::Lo),
+    (0xA66F, Cat::Mn),
+    (0xA670, Cat::Me),
+    (0xA673, Cat::Po),
+    (0xA674, Cat::Mn),
+    (0xA67E, Cat::Po),
+    (0xA67F, Cat::Lm),
+    (0xA680, Cat::Lu),
+    (0xA681, Cat::Ll),
+    (0xA682, Cat::Lu),
+    (0xA683, Cat::Ll),
+    (0xA684, Cat::Lu),
+    (0xA685, Cat::Ll),
+    (0xA686, Cat::Lu),
+    (0xA687, Cat::Ll),
+    (0xA688, Cat::Lu),
+    (0xA689, Cat::Ll),
+    (0xA68A, Cat::Lu),
+    (0xA68B, Cat::Ll),
+    (0xA68C, Cat::Lu),
+    (0xA68D, Cat::Ll),
+    (0xA68E, Cat::Lu),
+    (0xA68F, Cat::Ll),
+    (0xA690, Cat::Lu),
+    (0xA691, Cat::Ll),
+    (0xA692, Cat::Lu),
+    (0xA693, Cat::Ll),
+    (0xA694, Cat::Lu),
+    (0xA695, Cat::Ll),
+    (0xA696, Cat::Lu),
+    (0xA697, Cat::Ll),
+    (0xA698, Cat::Lu),
+    (0xA699, Cat::Ll),
+    (0xA69A, Cat::Lu),
+    (0xA69B, Cat::Ll),
+    (0xA69C, Cat::Lm),
+    (0xA69E, Cat::Mn),
+    (0xA6A0, Cat::Lo),
+    (0xA6E6, Cat::Nl),
+    (0xA6F0, Cat::Mn),
+    (0xA6F2, Cat::Po),
+    (0xA6F8, Cat::Cn),
+    (0xA700, Cat::Sk),
+    (0xA717, Cat::Lm),
+    (0xA720, Cat::Sk),
+    (0xA722, Cat::Lu),
+    (0xA723, Cat::Ll),
+    (0xA724, Cat::Lu),
+    (0xA725, Cat::Ll),
+    (0xA726, Cat::Lu),
+    (0xA727, Cat::Ll),
+    (0xA728, Cat::Lu),
+    (0xA729, Cat::Ll),
+    (0xA72A, Cat::Lu),
+    (0xA72B, Cat::Ll),
+    (0xA72C, Cat::Lu),
+    (0xA72D, Cat::Ll),
+    (0xA72E, Cat::Lu),
+    (0xA72F, Cat::Ll),
+    (0xA732, Cat::Lu),
+    (0xA733, Cat::Ll),
+    (0xA734, Cat::Lu),
+    (0xA735, Cat::Ll),
+    (0xA736, Cat::Lu),
+    (0xA737, Cat::Ll),
+    (0xA738, Cat::Lu),
+    (0xA739, Cat::Ll),
+    (0xA73A, Cat::Lu),
+    (0xA73B, Cat::Ll),
+    (0xA73C, Cat::Lu),
+    (0xA73D, Cat::Ll),
+    (0xA73E, Cat::Lu),
+    (0xA73F, Cat::Ll),
+    (0xA740, Cat::Lu),
+    (0xA741, Cat::Ll),
+    (0xA742, Cat::Lu),
+    (0xA743, Cat::Ll),
+    (0xA744, Cat::Lu),
+    (0xA745, Cat::Ll),
+    (0xA746, Cat::Lu),
+    (0xA747, Cat::Ll),
+    (0xA748, Cat::Lu),
+    (0xA749, Cat::Ll),
+    (0xA74A, Cat::Lu),
+    (0xA74B, Cat::Ll),
+    (0xA74C, Cat::Lu),
+    (0xA74D, Cat::Ll),
+    (0xA74E, Cat::Lu),
+    (0xA74F, Cat::Ll),
+    (0xA750, Cat::Lu),
+    (0xA751, Cat::Ll),
+    (0xA752, Cat::Lu),
+    (0xA753, Cat::Ll),
+    (0xA754, Cat::Lu),
+    (0xA755, Cat::Ll),
+    (0xA756, Cat::Lu),
+    (0xA757, Cat::Ll),
+    (0xA758, Cat::Lu),
+    (0xA759, Cat::Ll),
+    (0xA75A, Cat::Lu),
+    (0xA75B, Cat::Ll),
+    (0xA75C, Cat::Lu),
+    (0xA75D, Cat::Ll),
+    (0xA75E, Cat::Lu),
+    (0xA75F, Cat::Ll),
+    (0xA760, Cat::Lu),
+    (0xA761, Cat::Ll),
+    (0xA762, Cat::Lu),
+    (0xA763, Cat::Ll),
+    (0xA764, Cat::Lu),
+    (0xA765, Cat::Ll),
+    (0xA766, Cat::Lu),
+    (0xA767, Cat::Ll),
+    (0xA768, Cat::Lu),
+    (0xA769, Cat::Ll),
+    (0xA76A, Cat::Lu),
+    (0xA76B, Cat::Ll),
+    (0xA76C, Cat::Lu),
+    (0xA76D, Cat::Ll),
+    (0xA76E, Cat::Lu),
+    (0xA76F, Cat::Ll),
+    (0xA770, Cat::Lm),
+    (0xA771, Cat::Ll),
+    (0xA779, Cat::Lu),
+    (0xA77A, Cat::Ll),
+    (0xA77B, Cat::Lu),
+    (0xA77C, Cat::Ll),
+    (0xA77D, Cat::Lu),
+    (0xA77F, Cat::Ll),
+    (0xA780, Cat::Lu),
+    (0xA781, Cat::Ll),
+    (0xA782, Cat::Lu),
+    (0xA783, Cat::Ll),
+    (0xA784, Cat::Lu),
+    (0xA785, Cat::Ll),
+    (0xA786, Cat::Lu),
+    (0xA787, Cat::Ll),
+    (0xA788, Cat::Lm),
+    (0xA789, Cat::Sk),
+    (0xA78B, Cat::Lu),
+    (0xA78C, Cat::Ll),
+    (0xA78D, Cat::Lu),
+    (0xA78E, Cat::Ll),
+    (0xA78F, Cat::Lo),
+    (0xA790, Cat::Lu),
+    (0xA791, Cat::Ll),
+    (0xA792, Cat::Lu),
+    (0xA793, Cat::Ll),
+    (0xA796, Cat::Lu),
+    (0xA797, Cat::Ll),
+    (0xA798, Cat::Lu),
+    (0xA799, Cat::Ll),
+    (0xA79A, Cat::Lu),
+    (0xA79B, Cat::Ll),
+    (0xA79C, Cat::Lu),
+    (0xA79D, Cat::Ll),
+    (0xA79E, Cat::Lu),
+    (0xA79F, Cat::Ll),
+    (0xA7A0, Cat::Lu),
+    (0xA7A1, Cat::Ll),
+    (0xA7A2, Cat::Lu),
+    (0xA7A3, Cat::Ll),
+    (0xA7A4, Cat::Lu),
+    (0xA7A5, Cat::Ll),
+    (0xA7A6, Cat::Lu),
+    (0xA7A7, Cat::Ll),
+    (0xA7A8, Cat::Lu),
+    (0xA7A9, Cat::Ll),
+    (0xA7AA, Cat::Lu),
+    (0xA7AF, Cat::Ll),
+    (0xA7B0, Cat::Lu),
+    (0xA7B5, Cat::Ll),
+    (0xA7B6, Cat::Lu),
+    (0xA7B7, Cat::Ll),
+    (0xA7B8, Cat::Lu),
+    (0xA7B9, Cat::Ll),
+    (0xA7BA, Cat::Lu),
+    (0xA7BB, Cat::Ll),
+    (0xA7BC, Cat::Lu),
+    (0xA7BD, Cat::Ll),
+    (0xA7BE, Cat::Lu),
+    (0xA7BF, Cat::Ll),
+    (0xA7C0, Cat::Lu),
+    (0xA7C1, Cat::Ll),
+    (0xA7C2, Cat::Lu),
+    (0xA7C3, Cat::Ll),
+    (0xA7C4, Cat::Lu),
+    (0xA7C8, Cat::Ll),
+    (0xA7C9, Cat::Lu),
+    (0xA7CA, Cat::Ll),
+    (0xA7CB, Cat::Cn),
+    (0xA7D0, Cat::Lu),
+    (0xA7D1, Cat::Ll),
+    (0xA7D2, Cat::Cn),
+    (0xA7D3, Cat::Ll),
+    (0xA7D4, Cat::Cn),
+    (0xA7D5, Cat::Ll),
+    (0xA7D6, Cat::Lu),
+    (0xA7D7, Cat::Ll),
+    (0xA7D8, Cat::Lu),
+    (0xA7D9, Cat::Ll),
+    (0xA7DA, Cat::Cn),
+    (0xA7F2, Cat::Lm),
+    (0xA7F5, Cat::Lu),
+    (0xA7F6, Cat::Ll),
+    (0xA7F7, Cat::Lo),
+    (0xA7F8, Cat::Lm),
+    (0xA7FA, Cat::Ll),
+    (0xA7FB, Cat::Lo),
+    (0xA802, Cat::Mn),
+    (0xA803, Cat::Lo),
+    (0xA806, Cat::Mn),
+    (0xA807, Cat::Lo),
+    (0xA80B, Cat::Mn),
+    (0xA80C, Cat::Lo),
+    (0xA823, Cat::Mc),
+    (0xA825, Cat::Mn),
+    (0xA827, Cat::Mc),
+    (0xA828, Cat::So),
+    (0xA82C, Cat::Mn),
+    (0xA82D, Cat::Cn),
+    (0xA830, Cat::No),
+    (0xA836, Cat::So),
+    (0xA838, Cat::Sc),
+    (0xA839, Cat::So),
+    (0xA83A, Cat::Cn),
+    (0xA840, Cat::Lo),
+    (0xA874, Cat::Po),
+    (0xA878, Cat::Cn),
+    (0xA880, Cat::Mc),
+    (0xA882, Cat::Lo),
+    (0xA8B4, Cat::Mc),
+    (0xA8C4, Cat::Mn),
+    (0xA8C6, Cat::Cn),
+    (0xA8CE, Cat::Po),
+    (0xA8D0, Cat::Nd),
+    (0xA8DA, Cat::Cn),
+    (0xA8E0, Cat::Mn),
+    (0xA8F2, Cat::Lo),
+    (0xA8F8, Cat::Po),
+    (0xA8FB, Cat::Lo),
+    (0xA8FC, Cat::Po),
+    (0xA8FD, Cat::Lo),
+    (0xA8FF, Cat::Mn),
+    (0xA900, Cat::Nd),
+    (0xA90A, Cat::Lo),
+    (0xA926, Cat::Mn),
+    (0xA92E, Cat::Po),
+    (0xA930, Cat::Lo),
+    (0xA947, Cat::Mn),
+    (0xA952, Cat::Mc),
+    (0xA954, Cat::Cn),
+    (0xA95F, Cat::Po),
+    (0xA960, Cat::Lo),
+    (0xA97D, Cat::Cn),
+    (0xA980, Cat::Mn),
+    (0xA983, Cat::Mc),
+    (0xA984, Cat::Lo),
+    (0xA9B3, Cat::Mn),
+    (0xA9B4, Cat::Mc),
+    (0xA9B6, Cat::Mn),
+    (0xA9BA, Cat::Mc),
+    (0xA9BC, Cat::Mn),
+    (0xA9BE, Cat::Mc),
+    (0xA9C1, Cat::Po),
+    (0xA9CE, Cat::Cn),
+    (0xA9CF, Cat::Lm),
+    (0xA9D0, Cat::Nd),
+    (0xA9DA, Cat::Cn),
+    (0xA9DE, Cat::Po),
+    (0xA9E0, Cat::Lo),
+    (0xA9E5, Cat::Mn),
+    (0xA9E6, Cat::Lm),
+    (0xA9E7, Cat::Lo),
+    (0xA9F0, Cat::Nd),
+    (0xA9FA, Cat::Lo),
+    (0xA9FF, Cat::Cn),
+    (0xAA00, Cat::Lo),
+    (0xAA29, Cat::Mn),
+    (0xAA2F, Cat::Mc),
+    (0xAA31, Cat::Mn),
+    (0xAA33, Cat::Mc),
+    (0xAA35, Cat::Mn),
+    (0xAA37, Cat::Cn),
+    (0xAA40, Cat::Lo),
+    (0xAA43, Cat::Mn),
+    (0xAA44, Cat::Lo),
+    (0xAA4C, Cat::Mn),
+    (0xAA4D, Cat::Mc),
+    (0xAA4E, Cat::Cn),
+    (0xAA50, Cat::Nd),
+    (0xAA5A, Cat::Cn),
+    (0xAA5C, Cat::Po),
+    (0xAA60, Cat::Lo),
+    (0xAA70, Cat::Lm),
+    (0xAA71, Cat::Lo),
+    (0xAA77, Cat::So),
+    (0xAA7A, Cat::Lo),
+    (0xAA7B, Cat::Mc),
+    (0xAA7C, Cat::Mn),
+    (0xAA7D, Cat::Mc),
+    (0xAA7E, Cat::Lo),
+    (0xAAB0, Cat::Mn),
+    (0xAAB1, Cat::Lo),
+    (0xAAB2, Cat::Mn),
+    (0xAAB5, Cat::Lo),
+    (0xAAB7, Cat::Mn),
+    (0xAAB9, Cat::Lo),
+    (0xAABE, Cat::Mn),
+    (0xAAC0, Cat::Lo),
+    (0xAAC1, Cat::Mn),
+    (0xAAC2, Cat::Lo),
+    (0xAAC3, Cat::Cn),
+    (0xAADB, Cat::Lo),
+    (0xAADD, Cat::Lm),
+    (0xAADE, Cat::Po),
+    (0xAAE0, Cat::Lo),
+    (0xAAEB, Cat::Mc),
+    (0xAAEC, Cat::Mn),
+    (0xAAEE, Cat::Mc),
+    (0xAAF0, Cat::Po),
+    (0xAAF2, Cat::Lo),
+    (0xAAF3, Cat::Lm),
+    (0xAAF5, Cat::Mc),
+    (0xAAF6, Cat::Mn),
+    (0xAAF7, Cat::Cn),
+    (0xAB01, Cat::Lo),
+    (0xAB07, Cat::Cn),
+    (0xAB09, Cat::Lo),
+    (0xAB0F, Cat::Cn),
+    (0xAB11, Cat::Lo),
+    (0xAB17, Cat::Cn),
+    (0xAB20, Cat::Lo),
+    (0xAB27, Cat::Cn),
+    (0xAB28, Cat::Lo),
+    (0xAB2F, Cat::Cn),
+    (0xAB30, Cat::Ll),
+    (0xAB5B, Cat::Sk),
+    (0xAB5C, Cat::Lm),
+    (0xAB60, Cat::Ll),
+    (0xAB69, Cat::Lm),
+    (0xAB6A, Cat::Sk),
+    (0xAB6C, Cat::Cn),
+    (0xAB70, Cat::Ll),
+    (0xABC0, Cat::Lo),
+    (0xABE3, Cat::Mc),
+    (0xABE5, Cat::Mn),
+    (0xABE6, Cat::Mc),
+    (0xABE8, Cat::Mn),
+    (0xABE9, Cat::Mc),
+    (0xABEB, Cat::Po),
+    (0xABEC, Cat::Mc),
+    (0xABED, Cat::Mn),
+    (0xABEE, Cat::Cn),
+    (0xABF0, Cat::Nd),
+    (0xABFA, Cat::Cn),
+    (0xAC00, Cat::Lo),
+    (0xD7A4, Cat::Cn),
+    (0xD7B0, Cat::Lo),
+    (0xD7C7, Cat::Cn),
+    (0xD7CB, Cat::Lo),
+    (0xD7FC, Cat::Cn),
+    (0xD800, Cat::Cs),
+    (0xE000, Cat::Co),
+    (0xF900, Cat::Lo),
+    (0xFA6E, Cat::Cn),
+    (0xFA70, Cat::Lo),
+    (0xFADA, Cat::Cn),
+    (0xFB00, Cat::Ll),
+    (0xFB07, Cat::Cn),
+    (0xFB13, Cat::Ll),
+    (0xFB18, Cat::Cn),
+    (0xFB1D, Cat::Lo),
+    (0xFB1E, Cat::Mn),
+    (0xFB1F, Cat::Lo),
+    (0xFB29, Cat::Sm),
+    (0xFB2A, Cat::Lo),
+    (0xFB37, Cat::Cn),
+    (0xFB38, Cat::Lo),
+    (0xFB3D, Cat::Cn),
+    (0xFB3E, Cat::Lo),
+    (0xFB3F, Cat::Cn),
+    (0xFB40, Cat::Lo),
+    (0xFB42, Cat::Cn),
+    (0xFB43, Cat::Lo),
+    (0xFB45, Cat::Cn),
+    (0xFB46, Cat::Lo),
+    (0xFBB2, Cat::Sk),
+    (0xFBC3, Cat::Cn),
+    (0xFBD3, Cat::Lo),
+    (0xFD3E, Cat::Pe),
+    (0xFD3F, Cat::Ps),
+    (0xFD40, Cat::So),
+    (0xFD50, Cat::Lo),
+    (0xFD90, Cat::Cn),
+    (0xFD92, Cat::Lo),
+    (0xFDC8, Cat::Cn),
+    (0xFDCF, Cat::So),
+    (0xFDD0, Cat::Cn),
+    (0xFDF0, Cat::Lo),
+    (0xFDFC, Cat::Sc),
+    (0xFDFD, Cat::So),
+    (0xFE00, Cat::Mn),
+    (0xFE10, Cat::Po),
+    (0xFE17, Cat::Ps),
+    (0xFE18, Cat::Pe),
+    (0xFE19, Cat::Po),
+    (0xFE1A, Cat::Cn),
+    (0xFE20, Cat::Mn),
+    (0xFE30, Cat::Po),
+    (0xFE31, Cat::Pd),
+    (0xFE33, Cat::Pc),
+    (0xFE35, Cat::Ps),
+    (0xFE36, Cat::Pe),
+    (0xFE37, Cat::Ps),
+    (0xFE38, Cat::Pe),
+    (0xFE39, Cat::Ps),
+    (0xFE3A, Cat::Pe),
+    (0xFE3B, Cat::Ps),
+    (0xFE3C, Cat::Pe),
+    (0xFE3D, Cat::Ps),
+    (0xFE3E, Cat::Pe),
+    (0xFE3F, Cat::Ps),
+    (0xFE40, Cat::Pe),
+    (0xFE41, Cat::Ps),
+    (0xFE42, Cat::Pe),
+    (0xFE43, Cat::Ps),
+    (0xFE44, Cat::Pe),
+    (0xFE45, Cat::Po),
+    (0xFE47, Cat::Ps),
+    (0xFE48, Cat::Pe),
+    (0xFE49, Cat::Po),
+    (0xFE4D, Cat::Pc),
+    (0xFE50, Cat::Po),
+    (0xFE53, Cat::Cn),
+    (0xFE54, Cat::Po),
+    (0xFE58, Cat::Pd),
+    (0xFE59, Cat::Ps),
+    (0xFE5A, Cat::Pe),
+    (0xFE5B, Cat::Ps),
+    (0xFE5C, Cat::Pe),
+    (0xFE5D, Cat::Ps),
+    (0xFE5E, Cat::Pe),
+    (0xFE5F, Cat::Po),
+    (0xFE62, Cat::Sm),
+    (0xFE63, Cat::Pd),
+    (0xFE64, Cat::Sm),
+    (0xFE67, Cat::Cn),
+    (0xFE68, Cat::Po),
+    (0xFE69, Cat::Sc),
+    (0xFE6A, Cat::Po),
+    (0xFE6C, Cat::Cn),
+    (0xFE70, Cat::Lo),
+    (0xFE75, Cat::Cn),
+    (0xFE76, Cat::Lo),
+    (0xFEFD, Cat::Cn),
+    (0xFEFF, Cat::Cf),
+    (0xFF00, Cat::Cn),
+    (0xFF01, Cat::Po),
+    (0xFF04, Cat::Sc),
+    (0xFF05, Cat::Po),
+    (0xFF08, Cat::Ps),
+    (0xFF09, Cat::Pe),
+    (0xFF0A, Cat::Po),
+    (0xFF0B, Cat::Sm),
+    (0xFF0C, Cat::Po),
+    (0xFF0D, Cat::Pd),
+    (0xFF0E, Cat::Po),
+    (0xFF10, Cat::Nd),
+    (0xFF1A, Cat::Po),
+    (0xFF1C, Cat::Sm),
+    (0xFF1F, Cat::Po),
+    (0xFF21, Cat::Lu),
+    (0xFF3B, Cat::Ps),
+    (0xFF3C, Cat::Po),
+    (0xFF3D, Cat::Pe),
+    (0xFF3E, Cat::Sk),
+    (0xFF3F, Cat::Pc),
+    (0xFF40, Cat::Sk),
+    (0xFF41, Cat::Ll),
+    (0xFF5B, Cat::Ps),
+    (0xFF5C, Cat::Sm),
+    (0xFF5D, Cat::Pe),
+    (0xFF5E, Cat::Sm),
+    (0xFF5F, Cat::Ps),
+    (0xFF60, Cat::Pe),
+    (0xFF61, Cat::Po),
+    (0xFF62, Cat::Ps),
+    (0xFF63, Cat::Pe),
+    (0xFF64, Cat::Po),
+    (0xFF66, Cat::Lo),
+    (0xFF70, Cat::Lm),
+    (0xFF71, Cat::Lo),
+    (0xFF9E, Cat::Lm),
+    (0xFFA0, Cat::Lo),
+    (0xFFBF, Cat::Cn),
+    (0xFFC2, Cat::Lo),
+    (0xFFC8, Cat::Cn),
+    (0xFFCA, Cat::Lo),
+    (0xFFD0, Cat::Cn),
+    (0xFFD2, Cat::Lo),
+    (0xFFD8, Cat::Cn),
+    (0xFFDA, Cat::Lo),
+    (0xFFDD, Cat::Cn),
+    (0xFFE0, Cat::Sc),
+    (0xFFE2, Cat::Sm),
+    (0xFFE3, Cat::Sk),
+    (0xFFE4, Cat::So),
+    (0xFFE5, Cat::Sc),
+    (0xFFE7, Cat::Cn),
+    (0xFFE8, Cat::So),
+    (0xFFE9, Cat::Sm),
+    (0xFFED, Cat::So),
+    (0xFFEF, Cat::Cn),
+    (0xFFF9, Cat::Cf),
+    (0xFFFC, Cat::So),
+    (0xFFFE, Cat::Cn),
+    (0x10000, Cat::Lo),
+    (0x1000C, Cat::Cn),
+    (0x1000D, Cat::Lo),
+    (0x10027, Cat::Cn),
+    (0x10028, Cat::Lo),
+    (0x1003B, Cat::Cn),
+    (0x1003C, Cat::Lo),
+    (0x1003E, Cat::Cn),
+    (0x1003F, Cat::Lo),
+    (0x1004E, Cat::Cn),
+    (0x10050, Cat::Lo),
+    (0x1005E, Cat::Cn),
+    (0x10080, Cat::Lo),
+    (0x100FB, Cat::Cn),
+    (0x10100, Cat::Po),
+    (0x10103, Cat::Cn),
+    (0x10107, Cat::No),
+    (0x10134, Cat::Cn),
+    (0x10137, Cat::So),
+    (0x10140, Cat::Nl),
+    (0x10175, Cat::No),
+    (0x10179, Cat::So),
+    (0x1018A, Cat::No),
+    (0x1018C, Cat::So),
+    (0x1018F, Cat::Cn),
+    (0x10190, Cat::So),
+    (0x1019D, Cat::Cn),
+    (0x101A0, Cat::So),
+    (0x101A1, Cat::Cn),
+    (0x101D0, Cat::So),
+    (0x101FD, Cat::Mn),
+    (0x101FE, Cat::Cn),
+    (0x10280, Cat::Lo),
+    (0x1029D, Cat::Cn),
+    (0x102A0, Cat::Lo),
+    (0x102D1, Cat::Cn),
+    (0x102E0, Cat::Mn),
+    (0x102E1, Cat::No),
+    (0x102FC, Cat::Cn),
+    (0x10300, Cat::Lo),
+    (0x10320, Cat::No),
+    (0x10324, Cat::Cn),
+    (0x1032D, Cat::Lo),
+    (0x10341, Cat::Nl),
+    (0x10342, Cat::Lo),
+    (0x1034A, Cat::Nl),
+    (0x1034B, Cat::Cn),
+    (0x10350, Cat::Lo),
+    (0x10376, Cat::Mn),
+    (0x1037B, Cat::Cn),
+    (0x10380, Cat::Lo),
+    (0x1039E, Cat::Cn),
+    (0x1039F, Cat::Po),
+    (0x103A0, Cat::Lo),
+    (0x103C4, Cat::Cn),
+    (0x103C8, Cat::Lo),
+    (0x103D0, Cat::Po),
+    (0x103D1, Cat::Nl),
+    (0x103D6, Cat::Cn),
+    (0x10400, Cat::Lu),
+    (0x10428, Cat::Ll),
+    (0x10450, Cat::Lo),
+    (0x1049E, Cat::Cn),
+    (0x104A0, Cat::Nd),
+    (0x104AA, Cat::Cn),
+    (0x104B0, Cat::Lu),
+    (0x104D4, Cat::Cn),
+    (0x104D8, Cat::Ll),
+    (0x104FC, Cat::Cn),
+    (0x10500, Cat::Lo),
+    (0x10528, Cat::Cn),
+    (0x10530, Cat::Lo),
+    (0x10564, Cat::Cn),
+    (0x1056F, Cat::Po),
+    (0x10570, Cat::Lu),
+    (0x1057B, Cat::Cn),
+    (0x1057C, Cat::Lu),
+    (0x1058B, Cat::Cn),
+    (0x1058C, Cat::Lu),
+    (0x10593, Cat::Cn),
+    (0x10594, Cat::Lu),
+    (0x10596, Cat::Cn),
+    (0x10597, Cat::Ll),
+    (0x105A2, Cat::Cn),
+    (0x105A3, Cat::Ll),
+    (0x105B2, Cat::Cn),
+    (0x105B3, Cat::Ll),
+    (0x105BA, Cat::Cn),
+    (0x105BB, Cat::Ll),
+    (0x105BD, Cat::Cn),
+    (0x10600, Cat::Lo),
+    (0x10737, Cat::Cn),
+    (0x10740, Cat::Lo),
+    (0x10756, Cat::Cn),
+    (0x10760, Cat::Lo),
+    (0x10768, Cat::Cn),
+    (0x10780, Cat::Lm),
+    (0x10786, Cat::Cn),
+    (0x10787, Cat::Lm),
+    (0x107B1, Cat::Cn),
+    (0x107B2, Cat::Lm),
+    (0x107BB, Cat::Cn),
+    (0x10800, Cat::Lo),
+    (0x10806, Cat::Cn),
+    (0x10808, Cat::Lo),
+    (0x10809, Cat::Cn),
+    (0x1080A, Cat::Lo),
+    (0x10836, Cat::Cn),
+    (0x10837, Cat::Lo),
+    (0x10839, Cat::Cn),
+    (0x1083C, Cat::Lo),
+    (0x1083D, Cat::Cn),
+    (0x1083F, Cat::Lo),
+    (0x10856, Cat::Cn),
+    (0x10857, Cat::Po),
+    (0x10858, Cat::No),
+    (0x10860, Cat::Lo),
+    (0x10877, Cat::So),
+    (0x10879, Cat::No),
+    (0x10880, Cat::Lo),
+    (0x1089F, Cat::Cn),
+    (0x108A7, Cat::No),
+    (0x108B0, Cat::Cn),
+    (0x108E0, Cat::Lo),
+    (0x108F3, Cat::Cn),
+    (0x108F4, Cat::Lo),
+    (0x108F6, Cat::Cn),
+    (0x108FB, Cat::No),
+    (0x10900, Cat::Lo),
+    (0x10916, Cat::No),
+    (0x1091C, Cat::Cn),
+    (0x1091F, Cat::Po),
+    (0x10920, Cat::Lo),
+    (0x1093A, Cat::Cn),
+    (0x1093F, Cat::Po),
+    (0x10940, Cat::Cn),
+    (0x10980, Cat::Lo),
+    (0x109B8, Cat::Cn),
+    (0x109BC, Cat::No),
+    (0x109BE, Cat::Lo),
+    (0x109C0, Cat::No),
+    (0x109D0, Cat::Cn),
+    (0x109D2, Cat::No),
+    (0x10A00, Cat::Lo),
+    (0x10A01, Cat::Mn),
+    (0x10A04, Cat::Cn),
+    (0x10A05, Cat::Mn),
+    (0x10A07, Cat::Cn),
+    (0x10A0C, Cat::Mn),
+    (0x10A10, Cat::Lo),
+    (0x10A14, Cat::Cn),
+    (0x10A15, Cat::Lo),
+    (0x10A18, Cat::Cn),
+    (0x10A19, Cat::Lo),
+    (0x10A36, Cat::Cn),
+    (0x10A38, Cat::Mn),
+    (0x10A3B, Cat::Cn),
+    (0x10A3F, Cat::Mn),
+    (0x10A40, Cat::No),
+    (0x10A49, Cat::Cn),
+    (0x10A50, Cat::Po),
+    (0x10A59, Cat::Cn),
+    (0x10A60, Cat::Lo),
+    (0x10A7D, Cat::No),
+    (0x10A7F, Cat::Po),
+    (0x10A80, Cat::Lo),
+    (0x10A9D, Cat::No),
+    (0x10AA0, Cat::Cn),
+    (0x10AC0, Cat::Lo),
+    (0x10AC8, Cat::So),
+    (0x10AC9, Cat::Lo),
+    (0x10AE5, Cat::Mn),
+    (0x10AE7, Cat::Cn),
+    (0x10AEB, Cat::No),
+    (0x10AF0, Cat::Po),
+    (0x10AF7, Cat::Cn),
+    (0x10B00, Cat::Lo),
+    (0x10B36, Cat::Cn),
+    (0x10B39, Cat::Po),
+    (0x10B40, Cat::Lo),
+    (0x10B56, Cat::Cn),
+    (0x10B58, Cat::No),
+    (0x10B60, Cat::Lo),
+    (0x10B73, Cat::Cn),
+    (0x10B78, Cat::No),
+    (0x10B80, Cat::Lo),
+    (0x10B92, Cat::Cn),
+    (0x10B99, Cat::Po),
+    (0x10B9D, Cat::Cn),
+    (0x10BA9, Cat::No),
+    (0x10BB0, Cat::Cn),
+    (0x10C00, Cat::Lo),
+    (0x10C49, Cat::Cn),
+    (0x10C80, Cat::Lu),
+    (0x10CB3, Cat::Cn),
+    (0x10CC0, Cat::Ll),
+    (0x10CF3, Cat::Cn),
+    (0x10CFA, Cat::No),
+    (0x10D00, Cat::Lo),
+    (0x10D24, Cat::Mn),
+    (0x10D28, Cat::Cn),
+    (0x10D30, Cat::Nd),
+    (0x10D3A, Cat::Cn),
+    (0x10E60, Cat::No),
+    (0x10E7F, Cat::Cn),
+    (0x10E80, Cat::Lo),
+    (0x10EAA, Cat::Cn),
+    (0x10EAB, Cat::Mn),
+    (0x10EAD, Cat::Pd),
+    (0x10EAE, Cat::Cn),
+    (0x10EB0, Cat::Lo),
+    (0x10EB2, Cat::Cn),
+    (0x10F00, Cat::Lo),
+    (0x10F1D, Cat::No),
+    (0x10F27, Cat::Lo),
+    (0x10F28, Cat::Cn),
+    (0x10F30, Cat::Lo),
+    (0x10F46, Cat::Mn),
+    (0x10F51, Cat::No),
+    (0x10F55, Cat::Po),
+    (0x10F5A, Cat::Cn),
+    (0x10F70, Cat::Lo),
+    (0x10F82, Cat::Mn),
+    (0x10F86, Cat::Po),
+    (0x10F8A, Cat::Cn),
+    (0x10FB0, Cat::Lo),
+    (0x10FC5, Cat::No),
+    (0x10FCC, Cat::Cn),
+    (0x10FE0, Cat::Lo),
+    (0x10FF7, Cat::Cn),
+    (0x11000, Cat::Mc),
+    (0x11001, Cat::Mn),
+    (0x11002, Cat::Mc),
+    (0x11003, Cat::Lo),
+    (0x11038, Cat::Mn),
+    (0x11047, Cat::Po),
+    (0x1104E, Cat::Cn),
+    (0x11052, Cat::No),
+    (0x11066, Cat::Nd),
+    (0x11070, Cat::Mn),
+    (0x11071, Cat::Lo),
+    (0x11073, Cat::Mn),
+    (0x11075, Cat::Lo),
+    (0x11076, Cat::Cn),
+    (0x1107F, Cat::Mn),
+    (0x11082, Cat::Mc),
+    (0x11083, Cat::Lo),
+    (0x110B0, Cat::Mc),
+    (0x110B3, Cat::Mn),
+    (0x110B7, Cat::Mc),
+    (0x110B9, Cat::Mn),
+    (0x110BB, Cat::Po),
+    (0x110BD, Cat::Cf),
+    (0x110BE, Cat::Po),
+    (0x110C2, Cat::Mn),
+    (0x110C3, Cat::Cn),
+    (0x110CD, Cat::Cf),
+    (0x110CE, Cat::Cn),
+    (0x110D0, Cat::Lo),
+    (0x110E9, Cat::Cn),
+    (0x110F0, Cat::Nd),
+    (0x110FA, Cat::Cn),
+    (0x11100, Cat::Mn),
+    (0x11103, Cat::Lo),
+    (0x11127, Cat::Mn),
+    (0x1112C, Cat::Mc),
+    (0x1112D, Cat::Mn),
+    (0x11135, Cat::Cn),
+    (0x11136, Cat::Nd),
+    (0x11140, Cat::Po),
+    (0x11144, Cat::Lo),
+    (0x11145, Cat::Mc),
+    (0x11147, Cat::Lo),
+    (0x11148, Cat::Cn),
+    (0x11150, Cat::Lo),
+    (0x11173, Cat::Mn),
+    (0x11174, Cat::Po),
+    (0x11176, Cat::Lo),
+    (0x11177, Cat::Cn),
+    (0x11180, Cat::Mn),
+    (0x11182, Cat::Mc),
+    (0x11183, Cat::Lo),
+    (0x111B3, Cat::Mc),
+    (0x111B6, Cat::Mn),
+    (0x111BF, Cat::Mc),
+    (0x111C1, Cat::Lo),
+    (0x111C5, Cat::Po),
+    (0x111C9, Cat::Mn),
+    (0x111CD, Cat::Po),
+    (0x111CE, Cat::Mc),
+    (0x111CF, Cat::Mn),
+    (0x111D0, Cat::Nd),
+    (0x111DA, Cat::Lo),
+    (0x111DB, Cat::Po),
+    (0x111DC, Cat::Lo),
+    (0x111DD, Cat::Po),
+    (0x111E0, Cat::Cn),
+    (0x111E1, Cat::No),
+    (0x111F5, Cat::Cn),
+    (0x11200, Cat::Lo),
+    (0x11212, Cat::Cn),
+    (0x11213, Cat::Lo),
+    (0x1122C, Cat::Mc),
+    (0x1122F, Cat::Mn),
+    (0x11232, Cat::Mc),
+    (0x11234, Cat::Mn),
+    (0x11235, Cat::Mc),
+    (0x11236, Cat::Mn),
+    (0x11238, Cat::Po),
+    (0x1123E, Cat::Mn),
+    (0x1123F, Cat::Cn),
+    (0x11280, Cat::Lo),
+    (0x11287, Cat::Cn),
+    (0x11288, Cat::Lo),
+    (0x11289, Cat::Cn),
+    (0x1128A, Cat::Lo),
+    (0x1128E, Cat::Cn),
+    (0x1128F, Cat::Lo),
+    (0x1129E, Cat::Cn),
+    (0x1129F, Cat::Lo),
+    (0x112A9, Cat::Po),
+    (0x112AA, Cat::Cn),
+    (0x112B0, Cat::Lo),
+    (0x112DF, Cat::Mn),
+    (0x112E0, Cat::Mc),
+    (0x112E3, Cat::Mn),
+    (0x112EB, Cat::Cn),
+    (0x112F0, Cat::Nd),
+    (0x112FA, Cat::Cn),
+    (0x11300, Cat::Mn),
+    (0x11302, Cat::Mc),
+    (0x11304, Cat::Cn),
+    (0x11305, Cat::Lo),
+    (0x1130D, Cat::Cn),
+    (0x1130F, Cat::Lo),
+    (0x11311, Cat::Cn),
+    (0x11313, Cat::Lo),
+    (0x11329, Cat::Cn),
+    (0x1132A, Cat::Lo),
+    (0x11331, Cat::Cn),
+    (0x11332, Cat::Lo),
+    (0x11334, Cat::Cn),
+    (0x11335, Cat::Lo),
+    (0x1133A, Cat::Cn),
+    (0x1133B, Cat::Mn),
+    (0x1133D, Cat::Lo),
+    (0x1133E, Cat::Mc),
+    (0x11340, Cat::Mn),
+    (0x11341, Cat::Mc),
+    (0x11345, Cat::Cn),
+    (0x11347, Cat::Mc),
+    (0x11349, Cat::Cn),
+    (0x1134B, Cat::Mc),
+    (0x1134E, Cat::Cn),
+    (0x11350, Cat::Lo),
+    (0x11351, Cat::Cn),
+    (0x11357, Cat::Mc),
+    (0x11358, Cat::Cn),
+    (0x1135D, Cat::Lo),
+    (0x11362, Cat::Mc),
+    (0x11364, Cat::Cn),
+    (0x11366, Cat::Mn),
+    (0x1136D, Cat::Cn),
+    (0x11370, Cat::Mn),
+    (0x11375, Cat::Cn),
+    (0x11400, Cat::Lo),
+    (0x11435, Cat::Mc),
+    (0x11438, Cat::Mn),
+    (0x11440, Cat::Mc),
+    (0x11442, Cat::Mn),
+    (0x11445, Cat::Mc),
+    (0x11446, Cat::Mn),
+    (0x11447, Cat::Lo),
+    (0x1144B, Cat::Po),
+    (0x11450, Cat::Nd),
+    (0x1145A, Cat::Po),
+    (0x1145C, Cat::Cn),
+    (0x1145D, Cat::Po),
+    (0x1145E, Cat::Mn),
+    (0x1145F, Cat::Lo),
+    (0x11462, Cat::Cn),
+    (0x11480, Cat::Lo),
+    (0x114B0, Cat::Mc),
+    (0x114B3, Cat::Mn),
+    (0x114B9, Cat::Mc),
+    (0x114BA, Cat::Mn),
+    (0x114BB, Cat::Mc),
+    (0x114BF, Cat::Mn),
+    (0x114C1, Cat::Mc),
+    (0x114C2, Cat::Mn),
+    (0x114C4, Cat::Lo),
+    (0x114C6, Cat::Po),
+    (0x114C7, Cat::Lo),
+    (0x114C8, Cat::Cn),
+    (0x114D0, Cat::Nd),
+    (0x114DA, Cat::Cn),
+    (0x11580, Cat::Lo),
+    (0x115AF, Cat::Mc),
+    (0x115B2, Cat::Mn),
+    (0x115B6, Cat::Cn),
+    (0x115B8, Cat::Mc),
+    (0x115BC, Cat::Mn),
+    (0x115BE, Cat::Mc),
+    (0x115BF, Cat::Mn),
+    (0x115C1, Cat::Po),
+    (0x115D8, Cat::Lo),
+    (0x115DC, Cat::Mn),
+    (0x115DE, Cat::Cn),
+    (0x11600, Cat::Lo),
+    (0x11630, Cat::Mc),
+    (0x11633, Cat::Mn),
+    (0x1163B, Cat::Mc),
+    (0x1163D, Cat::Mn),
+    (0x1163E, Cat::Mc),
+    (0x1163F, Cat::Mn),
+    (0x11641, Cat::Po),
+    (0x11644, Cat::Lo),
+    (0x11645, Cat::Cn),
+    (0x11650, Cat::Nd),
+    (0x1165A, Cat::Cn),
+    (0x11660, Cat::Po),
+    (0x1166D, Cat::Cn),
+    (0x11680, Cat::Lo),
+    (0x116AB, Cat::Mn),
+    (0x116AC, Cat::Mc),
+    (0x116AD, Cat::Mn),
+    (0x116AE, Cat::Mc),
+    (0x116B0, Cat::Mn),
+    (0x116B6, Cat::Mc),
+    (0x116B7, Cat::Mn),
+    (0x116B8, Cat::Lo),
+    (0x116B9, Cat::Po),
+    (0x116BA, Cat::Cn),
+    (0x116C0, Cat::Nd),
+    (0x116CA, Cat::Cn),
+    (0x11700, Cat::Lo),
+    (0x1171B, Cat::Cn),
+    (0x1171D, Cat::Mn),
+    (0x11720, Cat::Mc),
+    (0x11722, Cat::Mn),
+    (0x11726, Cat::Mc),
+    (0x11727, Cat::Mn),
+    (0x1172C, Cat::Cn),
+    (0x11730, Cat::Nd),
+    (0x1173A, Cat::No),
+    (0x1173C, Cat::Po),
+    (0x1173F, Cat::So),
+    (0x11740, Cat::Lo),
+    (0x11747, Cat::Cn),
+    (0x11800, Cat::Lo),
+    (0x1182C, Cat::Mc),
+    (0x1182F, Cat::Mn),
+    (0x11838, Cat::Mc),
+    (0x11839, Cat::Mn),
+    (0x1183B, Cat::Po),
+    (0x1183C, Cat::Cn),
+    (0x118A0, Cat::Lu),
+    (0x118C0, Cat::Ll),
+    (0x118E0, Cat::Nd),
+    (0x118EA, Cat::No),
+    (0x118F3, Cat::Cn),
+    (0x118FF, Cat::Lo),
+    (0x11907, Cat::Cn),
+    (0x11909, Cat::Lo),
+    (0x1190A, Cat::Cn),
+    (0x1190C, Cat::Lo),
+    (0x11914, Cat::Cn),
+    (0x11915, Cat::Lo),
+    (0x11917, Cat::Cn),
+    (0x11918, Cat::Lo),
+    (0x11930, Cat::Mc),
+    (0x11936, Cat::Cn),
+    (0x11937, Cat::Mc),
+    (0x11939, Cat::Cn),
+    (0x1193B, Cat::Mn),
+    (0x1193D, Cat::Mc),
+    (0x1193E, Cat::Mn),
+    (0x1193F, Cat::Lo),
+    (0x11940, Cat::Mc),
+    (0x11941, Cat::Lo),
+    (0x11942, Cat::Mc),
+    (0x11943, Cat::Mn),
+    (0x11944, Cat::Po),
+    (0x11947, Cat::Cn),
+    (0x11950, Cat::Nd),
+    (0x1195A, Cat::Cn),
+    (0x119A0, Cat::Lo),
+    (0x119A8, Cat::Cn),
+    (0x119AA, Cat::Lo),
+    (0x119D1, Cat::Mc),
+    (0x119D4, Cat::Mn),
+    (0x119D8, Cat::Cn),
+    (0x119DA, Cat::Mn),
+    (0x119DC, Cat::Mc),
+    (0x119E0, Cat::Mn),
+    (0x119E1, Cat::Lo),
+    (0x119E2, Cat::Po),
+    (0x119E3, Cat::Lo),
+    (0x119E4, Cat::Mc),
+    (0x119E5, Cat::Cn),
+    (0x11A00, Cat::Lo),
+    (0x11A01, Cat::Mn),
+    (0x11A0B, Cat::Lo),
+    (0x11A33, Cat::Mn),
+    (0x11A39, Cat::Mc),
+    (0x11A3A, Cat::Lo),
+    (0x11A3B, Cat::Mn),
+    (0x11A3F, Cat::Po),
+    (0x11A47, Cat::Mn),
+    (0x11A48, Cat::Cn),
+    (0x11A50, Cat::Lo),
+    (0x11A51, Cat::Mn),
+    (0x11A57, Cat::Mc),
+    (0x11A59, Cat::Mn),
+    (0x11A5C, Cat::Lo),
+    (0x11A8A, Cat::Mn),
+    (0x11A97, Cat::Mc),
+    (0x11A98, Cat::Mn),
+    (0x11A9A, Cat::Po),
+    (0x11A9D, Cat::Lo),
+    (0x11A9E, Cat::Po),
+    (0x11AA3, Cat::Cn),
+    (0x11AB0, Cat::Lo),
+    (0x11AF9, Cat::Cn),
+    (0x11C00, Cat::Lo),
+    (0x11C09, Cat::Cn),
+    (0x11C0A, Cat::Lo),
+    (0x11C2F, Cat::Mc),
+    (0x11C30, Cat::Mn),
+    (0x11C37, Cat::Cn),
+    (0x11C38, Cat::Mn),
+    (0x11C3E, Cat::Mc),
+    (0x11C3F, Cat::Mn),
+    (0x11C40, Cat::Lo),
+    (0x11C41, Cat::Po),
+    (0x11C46, Cat::Cn),
+    (0x11C50, Cat::Nd),
+    (0x11C5A, Cat::No),
+    (0x11C6D, Cat::Cn),
+    (0x11C70, Cat::Po),
+    (0x11C72, Cat::Lo),
+    (0x11C90, Cat::Cn),
+    (0x11C92, Cat::Mn),
+    (0x11CA8, Cat::Cn),
+    (0x11CA9, Cat::Mc),
+    (0x11CAA, Cat::Mn),
+    (0x11CB1, Cat::Mc),
+    (0x11CB2, Cat::Mn),
+    (0x11CB4, Cat::Mc),
+    (0x11CB5, Cat::Mn),
+    (0x11CB7, Cat::Cn),
+    (0x11D00, Cat::Lo),
+    (0x11D07, Cat::Cn),
+    (0x11D08, Cat::Lo),
+    (0x11D0A, Cat::Cn),
+    (0x11D0B, Cat::Lo),
+    (0x11D31, Cat::Mn),
+    (0x11D37, Cat::Cn),
+    (0x11D3A, Cat::Mn),
+    (0x11D3B, Cat::Cn),
+    (0x11D3C, Cat::Mn),
+    (0x11D3E, Cat::Cn),
+    (0x11D3F, Cat::Mn),
+    (0x11D46, Cat::Lo),
+    (0x11D47, Cat::Mn),
+    (0x11D48, Cat::Cn),
+    (0x11D50, Cat::Nd),
+    (0x11D5A, Cat::Cn),
+    (0x11D60, Cat::Lo),
+    (0x11D66, Cat::Cn),
+    (0x11D67, Cat::Lo),
+    (0x11D69, Cat::Cn),
+    (0x11D6A, Cat::Lo),
+    (0x11D8A, Cat::Mc),
+    (0x11D8F, Cat::Cn),
+    (0x11D90, Cat::Mn),
+    (0x11D92, Cat::Cn),
+    (0x11D93, Cat::Mc),
+    (0x11D95, Cat::Mn),
+    (0x11D96, Cat::Mc),
+    (0x11D97, Cat::Mn),
+    (0x11D98, Cat::Lo),
+    (0x11D99, Cat::Cn),
+    (0x11DA0, Cat::Nd),
+    (0x11DAA, Cat::Cn),
+    (0x11EE0, Cat::Lo),
+    (0x11EF3, Cat::Mn),
+    (0x11EF5, Cat::Mc),
+    (0x11EF7, Cat::Po),
+    (0x11EF9, Cat::Cn),
+    (0x11FB0, Cat::Lo),
+    (0x11FB1, Cat::Cn),
+    (0x11FC0, Cat::No),
+    (0x11FD5, Cat::So),
+    (0x11FDD, Cat::Sc),
+    (0x11FE1, Cat::So),
+    (0x11FF2, Cat::Cn),
+    (0x11FFF, Cat::Po),
+    (0x12000, Cat::Lo),
+    (0x1239A, Cat::Cn),
+    (0x12400, Cat::Nl),
+    (0x1246F, Cat::Cn),
+    (0x12470, Cat::Po),
+    (0x12475, Cat::Cn),
+    (0x12480, Cat::Lo),
+    (0x12544, Cat::Cn),
+    (0x12F90, Cat::Lo),
+    (0x12FF1, Cat::Po),
+    (0x12FF3, Cat::Cn),
+    (0x13000, Cat::Lo),
+    (0x1342F, Cat::Cn),
+    (0x13430, Cat::Cf),
+    (0x13439, Cat::Cn),
+    (0x14400, Cat::Lo),
+    (0x14647, Cat::Cn),
+    (0x16800, Cat::Lo),
+    (0x16A39, Cat::Cn),
+    (0x16A40, Cat::Lo),
+    (0x16A5F, Cat::Cn),
+    (0x16A60, Cat::Nd),
+    (0x16A6A, Cat::Cn),
+    (0x16A6E, Cat::Po),
+    (0x16A70, Cat::Lo),
+    (0x16ABF, Cat::Cn),
+    (0x16AC0, Cat::Nd),
+    (0x16ACA, Cat::Cn),
+    (0x16AD0, Cat::Lo),
+    (0x16AEE, Cat::Cn),
+    (0x16AF0, Cat::Mn),
+    (0x16AF5, Cat::Po),
+    (0x16AF6, Cat::Cn),
+    (0x16B00, Cat::Lo),
+    (0x16B30, Cat::Mn),
+    (0x16B37, Cat::Po),
+    (0x16B3C, Cat::So),
+    (0x16B40, Cat::Lm),
+    (0x16B44, Cat::Po),
+    (0x16B45, Cat::So),
+    (0x16B46, Cat::Cn),
+    (0x16B50, Cat::Nd),
+    (0x16B5A, Cat::Cn),
+    (0x16B5B, Cat::No),
+    (0x16B62, Cat::Cn),
+    (0x16B63, Cat::Lo),
+    (0x16B78, Cat::Cn),
+    (0x16B7D, Cat::Lo),
+    (0x16B90, Cat::Cn),
+    (0x16E40, Cat::Lu),
+    (0x16E60, Cat::Ll),
+    (0x16E80, Cat::No),
+    (0x16E97, Cat::Po),
+    (0x16E9B, Cat::Cn),
+    (0x16F00, Cat::Lo),
+    (0x16F4B, Cat::Cn),
+    (0x16F4F, Cat::Mn),
+    (0x16F50, Cat::Lo),
+    (0x16F51, Cat::Mc),
+    (0x16F88, Cat::Cn),
+    (0x16F8F, Cat::Mn),
+    (0x16F93, Cat::Lm),
+    (0x16FA0, Cat::Cn),
+    (0x16FE0, Cat::Lm),
+    (0x16FE2, Cat::Po),
+    (0x16FE3, Cat::Lm),
+    (0x16FE4, Cat::Mn),
+    (0x16FE5, Cat::Cn),
+    (0x16FF0, Cat::Mc),
+    (0x16FF2, Cat::Cn),
+    (0x17000, Cat::Lo),
+    (0x187F8, Cat::Cn),
+    (0x18800, Cat::Lo),
+    (0x18CD6, Cat::Cn),
+    (0x18D00, Cat::Lo),
+    (0x18D09, Cat::Cn),
+    (0x1AFF0, Cat::Lm),
+    (0x1AFF4, Cat::Cn),
+    (0x1AFF5, Cat::Lm),
+    (0x1AFFC, Cat::Cn),
+    (0x1AFFD, Cat::Lm),
+    (0x1AFFF, Cat::Cn),
+    (0x1B000, Cat::Lo),
+    (0x1B123, Cat::Cn),
+    (0x1B150, Cat::Lo),
+    (0x1B153, Cat::Cn),
+    (0x1B164, Cat::Lo),
+    (0x1B168, Cat::Cn),
+    (0x1B170, Cat::Lo),
+    (0x1B2FC, Cat::Cn),
+    (0x1BC00, Cat::Lo),
+    (0x1BC6B, Cat::Cn),
+    (0x1BC70, Cat::Lo),
+    (0x1BC7D, Cat::Cn),
+    (0x1BC80, Cat::Lo),
+    (0x1BC89, Cat::Cn),
+    (0x1BC90, Cat::Lo),
+    (0x1BC9A, Cat::Cn),
+    (0x1BC9C, Cat::So),
+    (0x1BC9D, Cat::Mn),
+    (0x1BC9F, Cat::Po),
+    (0x1BCA0, Cat::Cf),
+    (0x1BCA4, Cat::Cn),
+    (0x1CF00, Cat::Mn),
+    (0x1CF2E, Cat::Cn),
+    (0x1CF30, Cat::Mn),
+    (0x1CF47, Cat::Cn),
+    (0x1CF50, Cat::So),
+    (0x1CFC4, Cat::Cn),
+    (0x1D000, Cat::So),
+    (0x1D0F6, Cat::Cn),
+    (0x1D100, Cat::So),
+    (0x1D127, Cat::Cn),
+    (0x1D129, Cat::So),
+    (0x1D165, Cat::Mc),
+    (0x1D167, Cat::Mn),
+    (0x1D16A, Cat::So),
+    (0x1D16D, Cat::Mc),
+    (0x1D173, Cat::Cf),
+    (0x1D17B, Cat::Mn),
+    (0x1D183, Cat::So),
+    (0x1D185, Cat::Mn),
+    (0x1D18C, Cat::So),
+    (0x1D1AA, Cat::Mn),
+    (0x1D1AE, Cat::So),
+    (0x1D1EB, Cat::Cn),
+    (0x1D200, Cat::So),
+    (0x1D242, Cat::Mn),
+    (0x1D245, Cat::So),
+    (0x1D246, Cat::Cn),
+    (0x1D2E0, Cat::No),
+    (0x1D2F4, Cat::Cn),
+    (0x1D300, Cat::So),
+    (0x1D357, Cat::Cn),
+    (0x1D360, Cat::No),
+    (0x1D379, Cat::Cn),
+    (0x1D400, Cat::Lu),
+    (0x1D41A, Cat::Ll),
+    (0x1D434, Cat::Lu),
+    (0x1D44E, Cat::Ll),
+    (0x1D455, Cat::Cn),
+    (0x1D456, Cat::Ll),
+    (0x1D468, Cat::Lu),
+    (0x1D482, Cat::Ll),
+    (0x1D49C, Cat::Lu),
+    (0x1D49D, Cat::Cn),
+    (0x1D49E, Cat::Lu),
+    (0x1D4A0, Cat::Cn),
+    (0x1D4A2, Cat::Lu),
+    (0x1D4A3, Cat::Cn),
+    (0x1D4A5, Cat::Lu),
+    (0x1D4A7, Cat::Cn),
+    (0x1D4A9, Cat::Lu),
+    (0x1D4AD, Cat::Cn),
+    (0x1D4AE, Cat::Lu),
+    (0x1D4B6, Cat::Ll),
+    (0x1D4BA, Cat::Cn),
+    (0x1D4BB, Cat::Ll),
+    (0x1D4BC, Cat::Cn),
+    (0x1D4BD, Cat::Ll),
+    (0x1D4C4, Cat::Cn),
+    (0x1D4C5, Cat::Ll),
+    (0x1D4D0, Cat::Lu),
+    (0x1D4EA, Cat::Ll),
+    (0x1D504, Cat::Lu),
+    (0x1D506, Cat::Cn),
+    (0x1D507, Cat::Lu),
+    (0x1D50B, Cat::Cn),
+    (0x1D50D, Cat::Lu),
+    (0x1D515, Cat::Cn),
+    (0x1D516, Cat::Lu),
+    (0x1D51D, Cat::Cn),
+    (0x1D51E, Cat::Ll),
+    (0x1D538, Cat::Lu),
+    (0x1D53A, Cat::Cn),
+    (0x1D53B, Cat::Lu),
+    (0x1D53F, Cat::Cn),
+    (0x1D540, Cat::Lu),
+    (0x1D545, Cat::Cn),
+    (0x1D546, Cat::Lu),
+    (0x1D547, Cat::Cn),
+    (0x1D54A, Cat::Lu),
+    (0x1D551, Cat::Cn),
+    (0x1D552, Cat::Ll),
+    (0x1D56C, Cat::Lu),
+    (0x1D586, Cat::Ll),
+    (0x1D5A0, Cat::Lu),
+    (0x1D5BA, Cat::Ll),
+    (0x1D5D4, Cat::Lu),
+    (0x1D5EE, Cat::Ll),
+    (0x1D608, Cat::Lu),
+    (0x1D622, Cat::Ll),
+    (0x1D63C, Cat::Lu),
+    (0x1D656, Cat::Ll),
+    (0x1D670, Cat::Lu),
+    (0x1D68A, Cat::Ll),
+    (0x1D6A6, Cat::Cn),
+    (0x1D6A8, Cat::Lu),
+    (0x1D6C1, Cat::Sm),
+    (0x1D6C2, Cat::Ll),
+    (0x1D6DB, Cat::Sm),
+    (0x1D6DC, Cat::Ll),
+    (0x1D6E2, Cat::Lu),
+    (0x1D6FB, Cat::Sm),
+    (0x1D6FC, Cat::Ll),
+    (0x1D715, Cat::Sm),
+    (0x1D716, Cat::Ll),
+    (0x1D71C, Cat::Lu),
+    (0x1D735, Cat::Sm),
+    (0x1D736, Cat::Ll),
+    (0x1D74F, Cat::Sm),
+    (0x1D750, Cat::Ll),
+    (0x1D756, Cat::Lu),
+    (0x1D76F, Cat::Sm),
+    (0x1D770, Cat::Ll),
+    (0x1D789, Cat::Sm),
+    (0x1D78A, Cat::Ll),
+    (0x1D790, Cat::Lu),
+    (0x1D7A9, Cat::Sm),
+    (0x1D7AA, Cat::Ll),
+    (0x1D7C3, Cat::Sm),
+    (0x1D7C4, Cat::Ll),
+    (0x1D7CA, Cat::Lu),
+    (0x1D7CB, Cat::Ll),
+    (0x1D7CC, Cat::Cn),
+    (0x1D7CE, Cat::Nd),
+    (0x1D800, Cat::So),
+    (0x1DA00, Cat::Mn),
+    (0x1DA37, Cat::So),
+    (0x1DA3B, Cat::Mn),
+    (0x1DA6D, Cat::So),
+    (0x1DA75, Cat::Mn),
+    (0x1DA76, Cat::So),
+    (0x1DA84, Cat::Mn),
+    (0x1DA85, Cat::So),
+    (0x1DA87, Cat::Po),
+    (0x1DA8C, Cat::Cn),
+    (0x1DA9B, Cat::Mn),
+    (0x1DAA0, Cat::Cn),
+    (0x1DAA1, Cat::Mn),
+    (0x1DAB0, Cat::Cn),
+    (0x1DF00, Cat::Ll),
+    (0x1DF0A, Cat::Lo),
+    (0x1DF0B, Cat::Ll),
+    (0x1DF1F, Cat::Cn),
+    (0x1E000, Cat::Mn),
+    (0x1E007, Cat::Cn),
+    (0x1E008, Cat::Mn),
+    (0x1E019, Cat::Cn),
+    (0x1E01B, Cat::Mn),
+    (0x1E022, Cat::Cn),
+    (0x1E023, Cat::Mn),
+    (0x1E025, Cat::Cn),
+    (0x1E026, Cat::Mn),
+    (0x1E02B, Cat::Cn),
+    (0x1E100, Cat::Lo),
+    (0x1E12D, Cat::Cn),
+    (0x1E130, Cat::Mn),
+    (0x1E137, Cat::Lm),
+    (0x1E13E, Cat::Cn),
+    (0x1E140, Cat::Nd),
+    (0x1E14A, Cat::Cn),
+    (0x1E14E, Cat::Lo),
+    (0x1E14F, Cat::So),
+    (0x1E150, Cat::Cn),
+    (0x1E290, Cat::Lo),
+    (0x1E2AE, Cat::Mn),
+    (0x1E2AF, Cat::Cn),
+    (0x1E2C0, Cat::Lo),
+    (0x1E2EC, Cat::Mn),
+    (0x1E2F0, Cat::Nd),
+    (0x1E2FA, Cat::Cn),
+    (0x1E2FF, Cat::Sc),
+    (0x1E300, Cat::Cn),
+    (0x1E7E0, Cat::Lo),
+    (0x1E7E7, Cat::Cn),
+    (0x1E7E8, Cat::Lo),
+    (0x1E7EC, Cat::Cn),
+    (0x1E7ED, Cat::Lo),
+    (0x1E7EF, Cat::Cn),
+    (0x1E7F0, Cat::Lo),
+    (0x1E7FF, Cat::Cn),
+    (0x1E800, Cat::Lo),
+    (0x1E8C5, Cat::Cn),
+    (0x1E8C7, Cat::No),
+    (0x1E8D0, Cat::Mn),
+    (0x1E8D7, Cat::Cn),
+    (0x1E900, Cat::Lu),
+    (0x1E922, Cat::Ll),
+    (0x1E944, Cat::Mn),
+    (0x1E94B, Cat::Lm),
+    (0x1E94C, Cat::Cn),
+    (0x1E950, Cat::Nd),
+    (0x1E95A, Cat::Cn),
+    (0x1E95E, Cat::Po),
+    (0x1E960, Cat::Cn),
+    (0x1EC71, Cat::No),
+    (0x1ECAC, Cat::So),
+    (0x1ECAD, Cat::No),
+    (0x1ECB0, Cat::Sc),
+    (0x1ECB1, Cat::No),
+    (0x1ECB5, Cat::Cn),
+    (0x1ED01, Cat::No),
+    (0x1ED2E, Cat::So),
+    (0x1ED2F, Cat::No),
+    (0x1ED3E, Cat::Cn),
+    (0x1EE00, Cat::Lo),
+    (0x1EE04, Cat::Cn),
+    (0x1EE05, Cat::Lo),
+    (0x1EE20, Cat::Cn),
+    (0x1EE21, Cat::Lo),
+    (0x1EE23, Cat::Cn),
+    (0x1EE24, Cat::Lo),
+    (0x1EE25, Cat::Cn),
+    (0x1EE27, Cat::Lo),
+    (0x1EE28, Cat::Cn),
+    (0x1EE29, Cat::Lo),
+    (0x1EE33, Cat::Cn),
+    (0x1EE34, Cat::Lo),
+    (0x1EE38, Cat::Cn),
+    (0x1EE39, Cat::Lo),
+    (0x1EE3A, Cat::Cn),
+    (0x1EE3B, Cat::Lo),
+    (0x1EE3C, Cat::Cn),
+    (0x1EE42, Cat::Lo),
+    (0x1EE43, Cat::Cn),
+    (0x1EE47, Cat::Lo),
+    (0x1EE48, Cat::Cn),
+    (0x1EE49, Cat::Lo),
+    (0x1EE4A, Cat::Cn),
+    (0x1EE4B, Cat::Lo),
+    (0x1EE4C, Cat::Cn),
+    (0x1EE4D, Cat::Lo),
+    (0x1EE50, Cat::Cn),
+    (0x1EE51, Cat::Lo),
+    (0x1EE53, Cat::Cn),
+    (0x1EE54, Cat::Lo),
+    (0x1EE55, Cat::Cn),
+    (0x1EE57, Cat::Lo),
+    (0x1EE58, Cat::Cn),
+    (0x1EE59, Cat::Lo),
+    (0x1EE5A, Cat::Cn),
+    (0x1EE5B, Cat::Lo),
+    (0x1EE5C, Cat::Cn),
+    (0x1EE5D, Cat::Lo),
+    (0x1EE5E, Cat::Cn),
+    (0x1EE5F, Cat::Lo),
+    (0x1EE60, Cat::Cn),
+    (0x1EE61, Cat::Lo),
+    (0x1EE63, Cat::Cn),
+    (0x1EE64, Cat::Lo),
+    (0x1EE65, Cat::Cn),
+    (0x1EE67, Cat::Lo),
+    (0x1EE6B, Cat::Cn),
+    (0x1EE6C, Cat::Lo),
+    (0x1EE73, Cat::Cn),
+    (0x1EE74, Cat::Lo),
+    (0x1EE78, Cat::Cn),
+    (0x1EE79, Cat::Lo),
+    (0x1EE7D, Cat::Cn),
+    (0x1EE7E, Cat::Lo),
+    (0x1EE7F, Cat::Cn),
+    (0x1EE80, Cat::Lo),
+    (0x1EE8A, Cat::Cn),
+    (0x1EE8B, Cat::Lo),
+    (0x1EE9C, Cat::Cn),
+    (0x1EEA1, Cat::Lo),
+    (0x1EEA4, Cat::Cn),
+    (0x1EEA5, Cat::Lo),
+    (0x1EEAA, Cat::Cn),
+    (0x1EEAB, Cat::Lo),
+    (0x1EEBC, Cat::Cn),
+    (0x1EEF0, Cat::Sm),
+    (0x1EEF2, Cat::Cn),
+    (0x1F000, Cat::So),
+    (0x1F02C, Cat::Cn),
+    (0x1F030, Cat::So),
+    (0x1F094, Cat::Cn),
+    (0x1F0A0, Cat::So),
+    (0x1F0AF, Cat::Cn),
+    (0x1F0B1, Cat::So),
+    (0x1F0C0, Cat::Cn),
+    (0x1F0C1, Cat::So),
+    (0x1F0D0, Cat::Cn),
+    (0x1F0D1, Cat::So),
+    (0x1F0F6, Cat::Cn),
+    (0x1F100, Cat::No),
+    (0x1F10D, Cat::So),
+    (0x1F1AE, Cat::Cn),
+    (0x1F1E6, Cat::So),
+    (0x1F203, Cat::Cn),
+    (0x1F210, Cat::So),
+    (0x1F23C, Cat::Cn),
+    (0x1F240, Cat::So),
+    (0x1F249, Cat::Cn),
+    (0x1F250, Cat::So),
+    (0x1F252, Cat::Cn),
+    (0x1F260, Cat::So),
+    (0x1F266, Cat::Cn),
+    (0x1F300, Cat::So),
+    (0x1F3FB, Cat::Sk),
+    (0x1F400, Cat::So),
+    (0x1F6D8, Cat::Cn),
+    (0x1F6DD, Cat::So),
+    (0x1F6ED, Cat::Cn),
+    (0x1F6F0, Cat::So),
+    (0x1F6FD, Cat::Cn),
+    (0x1F700, Cat::So),
+    (0x1F774, Cat::Cn),
+    (0x1F780, Cat::So),
+    (0x1F7D9, Cat::Cn),
+    (0x1F7E0, Cat::So),
+    (0x1F7EC, Cat::Cn),
+    (0x1F7F0, Cat::So),
+    (0x1F7F1, Cat::Cn),
+    (0x1F800, Cat::So),
+    (0x1F80C, Cat::Cn),
+    (0x1F810, Cat::So),
+    (0x1F848, Cat::Cn),
+    (0x1F850, Cat::So),
+    (0x1F85A, Cat::Cn),
+    (0x1F860, Cat::So),
+    (0x1F888, Cat::Cn),
+    (0x1F890, Cat::So),
+    (0x1F8AE, Cat::Cn),
+    (0x1F8B0, Cat::So),
+    (0x1F8B2, Cat::Cn),
+    (0x1F900, Cat::So),
+    (0x1FA54, Cat::Cn),
+    (0x1FA60, Cat::So),
+    (0x1FA6E, Cat::Cn),
+    (0x1FA70, Cat::So),
+    (0x1FA75, Cat::Cn),
+    (0x1FA78, Cat::So),
+    (0x1FA7D, Cat::Cn),
+    (0x1FA80, Cat::So),
+    (0x1FA87, Cat::Cn),
+    (0x1FA90, Cat::So),
+    (0x1FAAD, Cat::Cn),
+    (0x1FAB0, Cat::So),
+    (0x1FABB, Cat::Cn),
+    (0x1FAC0, Cat::So),
+    (0x1FAC6, Cat::Cn),
+    (0x1FAD0, Cat::So),
+    (0x1FADA, Cat::Cn),
+    (0x1FAE0, Cat::So),
+    (0x1FAE8, Cat::Cn),
+    (0x1FAF0, Cat::So),
+    (0x1FAF7, Cat::Cn),
+    (0x1FB00, Cat::So),
+    (0x1FB93, Cat::Cn),
+    (0x1FB94, Cat::So),
+    (0x1FBCB, Cat::Cn),
+    (0x1FBF0, Cat::Nd),
+    (0x1FBFA, Cat::Cn),
+    (0x20000, Cat::Lo),
+    (0x2A6E0, Cat::Cn),
+    (0x2A700, Cat::Lo),
+    (0x2B739, Cat::Cn),
+    (0x2B740, Cat::Lo),
+    (0x2B81E, Cat::Cn),
+    (0x2B820, Cat::Lo),
+    (0x2CEA2, Cat::Cn),
+    (0x2CEB0, Cat::Lo),
+    (0x2EBE1, Cat::Cn),
+    (0x2F800, Cat::Lo),
+    (0x2FA1E, Cat::Cn),
+    (0x30000, Cat::Lo),
+    (0x3134B, Cat::Cn),
+    (0xE0001, Cat::Cf),
+    (0xE0002, Cat::Cn),
+    (0xE0020, Cat::Cf),
+    (0xE0080, Cat::Cn),
+    (0xE0100, Cat::Mn),
+    (0xE01F0, Cat::Cn),
+    (0xF0000, Cat::Co),
+    (0xFFFFE, Cat::Cn),
+    (0x100000, Cat::Co),
+    (0x10FFFE, Cat::Cn),
+    (0x110000, Cat::Cn),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Script {
+    Adlam,
+    Ahom,
+    AnatolianHieroglyphs,
+    Arabic,
+    Armenian,
+    Avestan,
+    Balinese,
+    Bamum,
+    BassaVah,
+    Batak,
+    Bengali,
+    Bhaiksuki,
+    Bopomofo,
+    Brahmi,
+    Braille,
+    Buginese,
+    Buhid,
+    CanadianAboriginal,
+    Carian,
+    CaucasianAlbanian,
+    Chakma,
+    Cham,
+    Cherokee,
+    Chorasmian,
+    Common,
+    Coptic,
+    Cuneiform,
+    Cypriot,
+    CyproMinoan,
+    Cyrillic,
+    Deseret,
+    Devanagari,
+    DivesAkuru,
+    Dogra,
+    Duployan,
+    EgyptianHieroglyphs,
+    Elbasan,
+    Elymaic,
+    Ethiopic,
+    Georgian,
+    Glagolitic,
+    Gothic,
+    Grantha,
+    Greek,
+    Gujarati,
+    GunjalaGondi,
+    Gurmukhi,
+    Han,
+    Hangul,
+    HanifiRohingya,
+    Hanunoo,
+    Hatran,
+    Hebrew,
+    Hiragana,
+    ImperialAramaic,
+    Inherited,
+    InscriptionalPahlavi,
+    InscriptionalParthian,
+    Javanese,
+    Kaithi,
+    Kannada,
+    Katakana,
+    KayahLi,
+    Kharoshthi,
+    KhitanSmallScript,
+    Khmer,
+    Khojki,
+    Khudawadi,
+    Lao,
+    Latin,
+    Lepcha,
+    Limbu,
+    LinearA,
+    LinearB,
+    Lisu,
+    Lycian,
+    Lydian,
+    Mahajani,
+    Makasar,
+    Malayalam,
+    Mandaic,
+    Manichaean,
+    Marchen,
+    MasaramGondi,
+    Medefaidrin,
+    MeeteiMayek,
+    MendeKikakui,
+    MeroiticCursive,
+    MeroiticHieroglyphs,
+    Miao,
+    Modi,
+    Mongolian,
+    Mro,
+    Multani,
+    Myanmar,
+    Nabataean,
+    Nandinagari,
+    NewTaiLue,
+    Newa,
+    Nko,
+    Nushu,
+    NyiakengPuachueHmong,
+    Ogham,
+    OlChiki,
+    OldHungarian,
+    OldItalic,
+    OldNorthArabian,
+    OldPermic,
+    OldPersian,
+    OldSogdian,
+    OldSouthArabian,
+    OldTurkic,
+    OldUyghur,
+    Oriya,
+    Osage,
+    Osmanya,
+    PahawhHmong,
+    Palmyrene,
+    PauCinHau,
+    PhagsPa,
+    Phoenician,
+    PsalterPahlavi,
+    Rejang,
+    Runic,
+    Samaritan,
+    Saurashtra,
+    Sharada,
+    Shavian,
+    Siddham,
+    SignWriting,
+    Sinhala,
+    Sogdian,
+    SoraSompeng,
+    Soyombo,
+    Sundanese,
+    SylotiNagri,
+    Syriac,
+    Tagalog,
+    Tagbanwa,
+    TaiLe,
+    TaiTham,
+    TaiViet,
+    Takri,
+    Tamil,
+    Tangsa,
+    Tangut,
+    Telugu,
+    Thaana,
+    Thai,
+    Tibetan,
+    Tifinagh,
+    Tirhuta,
+    Toto,
+    Ugaritic,
+    Unknown,
+    Vai,
+    Vithkuqi,
+    Wancho,
+    WarangCiti,
+    Yezidi,
+    Yi,
+    ZanabazarSquare,
+}
+
+impl Script {
+    /// The name as written in the Unicode data files
+    pub fn name(self) -> &'static str {
+        match self {
+            Script::Adlam => "Adlam",
+            Script::Ahom => "Ahom",
+            Script::AnatolianHieroglyphs => "Anatolian_Hieroglyphs",
+            Script::Arabic => "Arabic",
+            Script::Armenian => "Armenian",
+            Script::Avestan => "Avestan",
+            Script::Balinese => "Balinese",
+            Script::Bamum => "Bamum",
+            Script::BassaVah => "Bassa_Vah",
+            Script::Batak => "Batak",
+            Script::Bengali => "Bengali",
+            Script::Bhaiksuki => "Bhaiksuki",
+            Script::Bopomofo => "Bopomofo",
+            Script::Brahmi => "Brahmi",
+            Script::Braille => "Braille",
+            Script::Buginese => "Buginese",
+            Script::Buhid => "Buhid",
+            Script::CanadianAboriginal => "Canadian_Aboriginal",
+            Script::Carian => "Carian",
+            Script::CaucasianAlbanian => "Caucasian_Albanian",
+            Script::Chakma => "Chakma",
+            Script::Cham => "Cham",
+            Script::Cherokee => "Cherokee",
+            Script::Chorasmian => "Chorasmian",
+            Script::Common => "Common",
+            Script::Coptic => "Coptic",
+            Script::Cuneiform => "Cuneiform",
+            Script::Cypriot => "Cypriot",
+            Script::CyproMinoan => "Cypro_Minoan",
+            Script::Cyrillic => "Cyrillic",
+            Script::Deseret => "Deseret",
+            Script::Devanagari => "Devanagari",
+            Script::DivesAkuru => "Dives_Akuru",
+            Script::Dogra => "Dogra",
+            Script::Duployan => "Duployan",
+            Script::EgyptianHieroglyphs => "Egyptian_Hieroglyphs",
+            Script::Elbasan => "Elbasan",
+            Script::Elymaic => "Elymaic",
+            Script::Ethiopic => "Ethiopic",
+            Script::Georgian => "Georgian",
+            Script::Glagolitic => "Glagolitic",
+            Script::Gothic => "Gothic",
+            Script::Grantha => "Grantha",
+            Script::Greek => "Greek",
+            Script::Gujarati => "Gujarati",
+            Script::GunjalaGondi => "Gunjala_Gondi",
+            Script::Gurmukhi => "Gurmukhi",
+            Script::Han => "Han",
+            Script::Hangul => "Hangul",
+            Script::HanifiRohingya => "Hanifi_Rohingya",
+            Script::Hanunoo => "Hanunoo",
+            Script::Hatran => "Hatran",
+            Script::Hebrew => "Hebrew",
+            Script::Hiragana => "Hiragana",
+            Script::ImperialAramaic => "Imperial_Aramaic",
+            Script::Inherited => "Inherited",
+            Script::InscriptionalPahlavi => "Inscriptional_Pahlavi",
+            Script::InscriptionalParthian => "Inscriptional_Parthian",
+            Script::Javanese => "Javanese",
+            Script::Kaithi => "Kaithi",
+            Script::Kannada => "Kannada",
+            Script::Katakana => "Katakana",
+            Script::KayahLi => "Kayah_Li",
+            Script::Kharoshthi => "Kharoshthi",
+            Script::KhitanSmallScript => "Khitan_Small_Script",
+            Script::Khmer => "Khmer",
+            Script::Khojki => "Khojki",
+            Script::Khudawadi => "Khudawadi",
+            Script::Lao => "Lao",
+            Script::Latin => "Latin",
+            Script::Lepcha => "Lepcha",
+            Script::Limbu => "Limbu",
+            Script::LinearA => "Linear_A",
+            Script::LinearB => "Linear_B",
+            Script::Lisu => "Lisu",
+            Script::Lycian => "Lycian",
+            Script::Lydian => "Lydian",
+            Script::Mahajani => "Mahajani",
+            Script::Makasar => "Makasar",
+            Script::Malayalam => "Malayalam",
+            Script::Mandaic => "Mandaic",
+            Script::Manichaean => "Manichaean",
+            Script::Marchen => "Marchen",
+            Script::MasaramGondi => "Masaram_Gondi",
+            Script::Medefaidrin => "Medefaidrin",
+            Script::MeeteiMayek => "Meetei_Mayek",
+            Script::MendeKikakui => "Mende_Kikakui",
+            Script::MeroiticCursive => "Meroitic_Cursive",
+            Script::MeroiticHieroglyphs => "Meroitic_Hieroglyphs",
+            Script::Miao => "Miao",
+            Script::Modi => "Modi",
+            Script::Mongolian => "Mongolian",
+            Script::Mro => "Mro",
+            Script::Multani => "Multani",
+            Script::Myanmar => "Myanmar",
+            Script::Nabataean => "Nabataean",
+            Script::Nandinagari => "Nandinagari",
+            Script::NewTaiLue => "New_Tai_Lue",
+            Script::Newa => "Newa",
+            Script::Nko => "Nko",
+            Script::Nushu => "Nushu",
+            Script::NyiakengPuachueHmong => "Nyiakeng_Puachue_Hmong",
+            Script::Ogham => "Ogham",
+            Script::OlChiki => "Ol_Chiki",
+            Script::OldHungarian => "Old_Hungarian",
+            Script::OldItalic => "Old_Italic",
+            Script::OldNorthArabian => "Old_North_Arabian",
+            Script::OldPermic => "Old_Permic",
+            Script::OldPersian => "Old_Persian",
+            Script::OldSogdian => "Old_Sogdian",
+            Script::OldSouthArabian => "Old_South_Arabian",
+            Script::OldTurkic => "Old_Turkic",
+            Script::OldUyghur => "Old_Uyghur",
+            Script::Oriya => "Oriya",
+            Script::Osage => "Osage",
+            Script::Osmanya => "Osmanya",
+            Script::PahawhHmong => "Pahawh_Hmong",
+            Script::Palmyrene => "Palmyrene",
+            Script::PauCinHau => "Pau_Cin_Hau",
+            Script::PhagsPa => "Phags_Pa",
+            Script::Phoenician => "Phoenician",
+            Script::PsalterPahlavi => "Psalter_Pahlavi",
+            Script::Rejang => "Rejang",
+            Script::Runic => "Runic",
+            Script::Samaritan => "Samaritan",
+            Script::Saurashtra => "Saurashtra",
+            Script::Sharada => "Sharada",
+            Script::Shavian => "Shavian",
+            Script::Siddham => "Siddham",
+            Script::SignWriting => "SignWriting",
+            Script::Sinhala => "Sinhala",
+            Script::Sogdian => "Sogdian",
+            Script::SoraSompeng => "Sora_Sompeng",
+            Script::Soyombo => "Soyombo",
+            Script::Sundanese => "Sundanese",
+            Script::SylotiNagri => "Syloti_Nagri",
+            Script::Syriac => "Syriac",
+            Script::Tagalog => "Tagalog",
+            Script::Tagbanwa => "Tagbanwa",
+            Script::TaiLe => "Tai_Le",
+            Script::TaiTham => "Tai_Tham",
+            Script::TaiViet => "Tai_Viet",
+            Script::Takri => "Takri",
+            Script::Tamil => "Tamil",
+            Script::Tangsa => "Tangsa",
+            Script::Tangut => "Tangut",
+            Script::Telugu => "Telugu",
+            Script::Thaana => "Thaana",
+            Script::Thai => "Thai",
+            Script::Tibetan => "Tibetan",
+            Script::Tifinagh => "Tifinagh",
+            Script::Tirhuta => "Tirhuta",
+            Script::Toto => "Toto",
+            Script::Ugaritic => "Ugaritic",
+            Script::Unknown => "Unknown",
+            Script::Vai => "Vai",
+            Script::Vithkuqi => "Vithkuqi",
+            Script::Wancho => "Wancho",
+            Script::WarangCiti => "Warang_Citi",
+            Script::Yezidi => "Yezidi",
+            Script::Yi => "Yi",
+            Script::ZanabazarSquare => "Zanabazar_Square",
+        }
+    }
+}
+
+pub const SCRIPTS: &[(u32, Script)] = &[
+    (0x0, Script::Common),
+    (0x41, Script::Latin),
+    (0x5B, Script::Common),
+    (0x61, Script::Latin),
+    (0x7B, Script::Common),
+    (0xAA, Script::Latin),
+    (0xAB, Script::Common),
+    (0xBA, Script::Latin),
+    (0xBB, Script::Common),
+    (0xC0, Script::Latin),
+    (0xD7, Script::Common),
+    (0xD8, Script::Latin),
+    (0xF7, Script::Common),
+    (0xF8, Script::Latin),
+    (0x2B9, Script::Common),
+    (0x2E0, Script::Latin),
+    (0x2E5, Script::Common),
+    (0x2EA, Script::Bopomofo),
+    (0x2EC, Script::Common),
+    (0x300, Script::Inherited),
+    (0x370, Script::Greek),
+    (0x374, Script::Common),
+    (0x375, Script::Greek),
+    (0x378, Script::Unknown),
+    (0x37A, Script::Greek),
+    (0x37E, Script::Common),
+    (0x37F, Script::Greek),
+    (0x380, Script::Unknown),
+    (0x384, Script::Greek),
+    (0x385, Script::Common),
+    (0x386, Script::Greek),
+    (0x387, Script::Common),
+    (0x388, Script::Greek),
+    (0x38B, Script::Unknown),
+    (0x38C, Script::Greek),
+    (0x38D, Script::Unknown),
+    (0x38E, Script::Greek),
+    (0x3A2, Script::Unknown),
+    (0x3A3, Script::Greek),
+    (0x3E2, Script::Coptic),
+    (0x3F0, Script::Greek),
+    (0x400, Script::Cyrillic),
+    (0x485, Script::Inherited),
+    (0x487, Script::Cyrillic),
+    (0x530, Script::Unknown),
+    (0x531, Script::Armenian),
+    (0x557, Script::Unknown),
+    (0x559, Script::Armenian),
+    (0x58B, Script::Unknown),
+    (0x58D, Script::Armenian),
+    (0x590, Script::Unknown),
+    (0x591, Script::Hebrew),
+    (0x5C8, Script::Unknown),
+    (0x5D0, Script::Hebrew),
+    (0x5EB, Script::Unknown),
+    (0x5EF, Script::Hebrew),
+    (0x5F5, Script::Unknown),
+    (0x600, Script::Arabic),
+    (0x605, Script::Common),
+    (0x606, Script::Arabic),
+    (0x60C, Script::Common),
+    (0x60D, Script::Arabic),
+    (0x61B, Script::Common),
+    (0x61C, Script::Arabic),
+    (0x61F, Script::Common),
+    (0x620, Script::Arabic),
+    (0x640, Script::Common),
+    (0x641, Script::Arabic),
+    (0x64B, Script::Inherited),
+    (0x656, Script::Arabic),
+    (0x670, Script::Inherited),
+    (0x671, Script::Arabic),
+    (0x6DD, Script::Common),
+    (0x6DE, Script::Arabic),
+    (0x700, Script::Syriac),
+    (0x70E, Script::Unknown),
+    (0x70F, Script::Syriac),
+    (0x74B, Script::Unknown),
+    (0x74D, Script::Syriac),
+    (0x750, Script::Arabic),
+    (0x780, Script::Thaana),
+    (0x7B2, Script::Unknown),
+    (0x7C0, Script::Nko),
+    (0x7FB, Script::Unknown),
+    (0x7FD, Script::Nko),
+    (0x800, Script::Samaritan),
+    (0x82E, Script::Unknown),
+    (0x830, Script::Samaritan),
+    (0x83F, Script::Unknown),
+    (0x840, Script::Mandaic),
+    (0x85C, Script::Unknown),
+    (0x85E, Script::Mandaic),
+    (0x85F, Script::Unknown),
+    (0x860, Script::Syriac),
+    (0x86B, Script::Unknown),
+    (0x870, Script::Arabic),
+    (0x88F, Script::Unknown),
+    (0x890, Script::Arabic),
+    (0x892, Script::Unknown),
+    (0x898, Script::Arabic),
+    (0x8E2, Script::Common),
+    (0x8E3, Script::Arabic),
+    (0x900, Script::Devanagari),
+    (0x951, Script::Inherited),
+    (0x955, Script::Devanagari),
+    (0x964, Script::Common),
+    (0x966, Script::Devanagari),
+    (0x980, Script::Bengali),
+    (0x984, Script::Unknown),
+    (0x985, Script::Bengali),
+    (0x98D, Script::Unknown),
+    (0x98F, Script::Bengali),
+    (0x991, Script::Unknown),
+    (0x993, Script::Bengali),
+    (0x9A9, Script::Unknown),
+    (0x9AA, Script::Bengali),
+    (0x9B1, Script::Unknown),
+    (0x9B2, Script::Bengali),
+    (0x9B3, Script::Unknown),
+    (0x9B6, Script::Bengali),
+    (0x9BA, Script::Unknown),
+    (0x9BC, Script::Bengali),
+    (0x9C5, Script::Unknown),
+    (0x9C7, Script::Bengali),
+    (0x9C9, Script::Unknown),
+    (0x9CB, Script::Bengali),
+    (0x9CF, Script::Unknown),
+    (0x9D7, Script::Bengali),
+    (0x9D8, Script::Unknown),
+    (0x9DC, Script::Bengali),
+    (0x9DE, Script::Unknown),
+    (0x9DF, Script::Bengali),
+    (0x9E4, Script::Unknown),
+    (0x9E6, Script::Bengali),
+    (0x9FF, Script::Unknown),
+    (0xA01, Script::Gurmukhi),
+    (0xA04, Script::Unknown),
+    (0xA05, Script::Gurmukhi),
+    (0xA0B, Script::Unknown),
+    (0xA0F, Script::Gurmukhi),
+    (0xA11, Script::Unknown),
+    (0xA13, Script::Gurmukhi),
+    (0xA29, Script::Unknown),
+    (0xA2A, Script::Gurmukhi),
+    (0xA31, Script::Unknown),
+    (0xA32, Script::Gurmukhi),
+    (0xA34, Script::Unknown),
+    (0xA35, Script::Gurmukhi),
+    (0xA37, Script::Unknown),
+    (0xA38, Script::Gurmukhi),
+    (0xA3A, Script::Unknown),
+    (0xA3C, Script::Gurmukhi),
+    (0xA3D, Script::Unknown),
+    (0xA3E, Script::Gurmukhi),
+    (0xA43, Script::Unknown),
+    (0xA47, Script::Gurmukhi),
+    (0xA49, Script::Unknown),
+    (0xA4B, Script::Gurmukhi),
+    (0xA4E, Script::Unknown),
+    (0xA51, Script::Gurmukhi),
+    (0xA52, Script::Unknown),
+    (0xA59, Script::Gurmukhi),
+    (0xA5D, Script::Unknown),
+    (0xA5E, Script::Gurmukhi),
+    (0xA5F, Script::Unknown),
+    (0xA66, Script::Gurmukhi),
+    (0xA77, Script::Unknown),
+    (0xA81, Script::Gujarati),
+    (0xA84, Script::Unknown),
+    (0xA85, Script::Gujarati),
+    (0xA8E, Script::Unknown),
+    (0xA8F, Script::Gujarati),
+    (0xA92, Script::Unknown),
+    (0xA93, Script::Gujarati),
+    (0xAA9, Script::Unknown),
+    (0xAAA, Script::Gujarati),
+    (0xAB1, Script::Unknown),
+    (0xAB2, Script::Gujarati),
+    (0xAB4, Script::Unknown),
+    (0xAB5, Script::Gujarati),
+    (0xABA, Script::Unknown),
+    (0xABC, Script::Gujarati),
+    (0xAC6, Script::Unknown),
+    (0xAC7, Script::Gujarati),
+    (0xACA, Script::Unknown),
+    (0xACB, Script::Gujarati),
+    (0xACE, Script::Unknown),
+    (0xAD0, Script::Gujarati),
+    (0xAD1, Script::Unknown),
+    (0xAE0, Script::Gujarati),
+    (0xAE4, Script::Unknown),
+    (0xAE6, Script::Gujarati),
+    (0xAF2, Script::Unknown),
+    (0xAF9, Script::Gujarati),
+    (0xB00, Script::Unknown),
+    (0xB01, Script::Oriya),
+    (0xB04, Script::Unknown),
+    (0xB05, Script::Oriya),
+    (0xB0D, Script::Unknown),
+    (0xB0F, Script::Oriya),
+    (0xB11, Script::Unknown),
+    (0xB13, Script::Oriya),
+    (0xB29, Script::Unknown),
+    (0xB2A, Script::Oriya),
+    (0xB31, Script::Unknown),
+    (0xB32, Script::Oriya),
+    (0xB34, Script::Unknown),
+    (0xB35, Script::Oriya),
+    (0xB3A, Script::Unknown),
+    (0xB3C, Script::Oriya),
+    (0xB45, Script::Unknown),
+    (0xB47, Script::Oriya),
+    (0xB49, Script::Unknown),
+    (0xB4B, Script::Oriya),
+    (0xB4E, Script::Unknown),
+    (0xB55, Script::Oriya),
+    (0xB58, Script::Unknown),
+    (0xB5C, Script::Oriya),
+    (0xB5E, Script::Unknown),
+    (0xB5F, Script::Oriya),
+    (0xB64, Script::Unknown),
+    (0xB66, Script::Oriya),
+    (0xB78, Script::Unknown),
+    (0xB82, Script::Tamil),
+    (0xB84, Script::Unknown),
+    (0xB85, Script::Tamil),
+    (0xB8B, Script::Unknown),
+    (0xB8E, Script::Tamil),
+    (0xB91, Script::Unknown),
+    (0xB92, Script::Tamil),
+    (0xB96, Script::Unknown),
+    (0xB99, Script::Tamil),
+    (0xB9B, Script::Unknown),
+    (0xB9C, Script::Tamil),
+    (0xB9D, Script::Unknown),
+    (0xB9E, Script::Tamil),
+    (0xBA0, Script::Unknown),
+    (0xBA3, Script::Tamil),
+    (0xBA5, Script::Unknown),
+    (0xBA8, Script::Tamil),
+    (0xBAB, Script::Unknown),
+    (0xBAE, Script::Tamil),
+    (0xBBA, Script::Unknown),
+    (0xBBE, Script::Tamil),
+    (0xBC3, Script::Unknown),
+    (0xBC6, Script::Tamil),
+    (0xBC9, Script::Unknown),
+    (0xBCA, Script::Tamil),
+    (0xBCE, Script::Unknown),
+    (0xBD0, Script::Tamil),
+    (0xBD1, Script::Unknown),
+    (0xBD7, Script::Tamil),
+    (0xBD8, Script::Unknown),
+    (0xBE6, Script::Tamil),
+    (0xBFB, Script::Unknown),
+    (0xC00, Script::Telugu),
+    (0xC0D, Script::Unknown),
+    (0xC0E, Script::Telugu),
+    (0xC11, Script::Unknown),
+    (0xC12, Script::Telugu),
+    (0xC29, Script::Unknown),
+    (0xC2A, Script::Telugu),
+    (0xC3A, Script::Unknown),
+    (0xC3C, Script::Telugu),
+    (0xC45, Script::Unknown),
+    (0xC46, Script::Telugu),
+    (0xC49, Script::Unknown),
+    (0xC4A, Script::Telugu),
+    (0xC4E, Script::Unknown),
+    (0xC55, Script::Telugu),
+    (0xC57, Script::Unknown),
+    (0xC58, Script::Telugu),
+    (0xC5B, Script::Unknown),
+    (0xC5D, Script::Telugu),
+    (0xC5E, Script::Unknown),
+    (0xC60, Script::Telugu),
+    (0xC64, Script::Unknown),
+    (0xC66, Script::Telugu),
+    (0xC70, Script::Unknown),
+    (0xC77, Script::Telugu),
+    (0xC80, Script::Kannada),
+    (0xC8D, Script::Unknown),
+    (0xC8E, Script::Kannada),
+    (0xC91, Script::Unknown),
+    (0xC92, Script::Kannada),
+    (0xCA9, Script::Unknown),
+    (0xCAA, Script::Kannada),
+    (0xCB4, Script::Unknown),
+    (0xCB5, Script::Kannada),
+    (0xCBA, Script::Unknown),
+    (0xCBC, Script::Kannada),
+    (0xCC5, Script::Unknown),
+    (0xCC6, Script::Kannada),
+    (0xCC9, Script::Unknown),
+    (0xCCA, Script::Kannada),
+    (0xCCE, Script::Unknown),
+    (0xCD5, Script::Kannada),
+    (0xCD7, Script::Unknown),
+    (0xCDD, Script::Kannada),
+    (0xCDF, Script::Unknown),
+    (0xCE0, Script::Kannada),
+    (0xCE4, Script::Unknown),
+    (0xCE6, Script::Kannada),
+    (0xCF0, Script::Unknown),
+    (0xCF1, Script::Kannada),
+    (0xCF3, Script::Unknown),
+    (0xD00, Script::Malayalam),
+    (0xD0D, Script::Unknown),
+    (0xD0E, Script::Malayalam),
+    (0xD11, Script::Unknown),
+    (0xD12, Script::Malayalam),
+    (0xD45, Script::Unknown),
+    (0xD46, Script::Malayalam),
+    (0xD49, Script::Unknown),
+    (0xD4A, Script::Malayalam),
+    (0xD50, Script::Unknown),
+    (0xD54, Script::Malayalam),
+    (0xD64, Script::Unknown),
+    (0xD66, Script::Malayalam),
+    (0xD80, Script::Unknown),
+    (0xD81, Script::Sinhala),
+    (0xD84, Script::Unknown),
+    (0xD85, Script::Sinhala),
+    (0xD97, Script::Unknown),
+    (0xD9A, Script::Sinhala),
+    (0xDB2, Script::Unknown),
+    (0xDB3, Script::Sinhala),
+    (0xDBC, Script::Unknown),
+    (0xDBD, Script::Sinhala),
+    (0xDBE, Script::Unknown),
+    (0xDC0, Script::Sinhala),
+    (0xDC7, Script::Unknown),
+    (0xDCA, Script::Sinhala),
+    (0xDCB, Script::Unknown),
+    (0xDCF, Script::Sinhala),
+    (0xDD5, Script::Unknown),
+    (0xDD6, Script::Sinhala),
+    (0xDD7, Script::Unknown),
+    (0xDD8, Script::Sinhala),
+    (0xDE0, Script::Unknown),
+    (0xDE6, Script::Sinhala),
+    (0xDF0, Script::Unknown),
+    (0xDF2, Script::Sinhala),
+    (0xDF5, Script::Unknown),
+    (0xE01, Script::Thai),
+    (0xE3B, Script::Unknown),
+    (0xE3F, Script::Common),
+    (0xE40, Script::Thai),
+    (0xE5C, Script::Unknown),
+    (0xE81, Script::Lao),
+    (0xE83, Script::Unknown),
+    (0xE84, Script::Lao),
+    (0xE85, Script::Unknown),
+    (0xE86, Script::Lao),
+    (0xE8B, Script::Unknown),
+    (0xE8C, Script::Lao),
+    (0xEA4, Script::Unknown),
+    (0xEA5, Script::Lao),
+    (0xEA6, Script::Unknown),
+    (0xEA7, Script::Lao),
+    (0xEBE, Script::Unknown),
+    (0xEC0, Script::Lao),
+    (0xEC5, Script::Unknown),
+    (0xEC6, Script::Lao),
+    (0xEC7, Script::Unknown),
+    (0xEC8, Script::Lao),
+    (0xECE, Script::Unknown),
+    (0xED0, Script::Lao),
+    (0xEDA, Script::Unknown),
+    (0xEDC, Script::Lao),
+    (0xEE0, Script::Unknown),
+    (0xF00, Script::Tibetan),
+    (0xF48, Script::Unknown),
+    (0xF49, Script::Tibetan),
+    (0xF6D, Script::Unknown),
+    (0xF71, Script::Tibetan),
+    (0xF98, Script::Unknown),
+    (0xF99, Script::Tibetan),
+    (0xFBD, Script::Unknown),
+    (0xFBE, Script::Tibetan),
+    (0xFCD, Script::Unknown),
+    (0xFCE, Script::Tibetan),
+    (0xFD5, Script::Common),
+    (0xFD9, Script::Tibetan),
+    (0xFDB, Script::Unknown),
+    (0x1000, Script::Myanmar),
+    (0x10A0, Script::Georgian),
+    (0x10C6, Script::Unknown),
+    (0x10C7, Script::Georgian),
+    (0x10C8, Script::Unknown),
+    (0x10CD, Script::Georgian),
+    (0x10CE, Script::Unknown),
+    (0x10D0, Script::Georgian),
+    (0x10FB, Script::Common),
+    (0x10FC, Script::Georgian),
+    (0x1100, Script::Hangul),
+    (0x1200, Script::Ethiopic),
+    (0x1249, Script::Unknown),
+    (0x124A, Script::Ethiopic),
+    (0x124E, Script::Unknown),
+    (0x1250, Script::Ethiopic),
+    (0x1257, Script::Unknown),
+    (0x1258, Script::Ethiopic),
+    (0x1259, Script::Unknown),
+    (0x125A, Script::Ethiopic),
+    (0x125E, Script::Unknown),
+    (0x1260, Script::Ethiopic),
+    (0x1289, Script::Unknown),
+    (0x128A, Script::Ethiopic),
+    (0x128E, Script::Unknown),
+    (0x1290, Script::Ethiopic),
+    (0x12B1, Script::Unknown),
+    (0x12B2, Script::Ethiopic),
+    (0x12B6, Script::Unknown),
+    (0x12B8, Script::Ethiopic),
+    (0x12BF, Script::Unknown),
+    (0x12C0, Script::Ethiopic),
+    (0x12C1, Script::Unknown),
+    (0x12C2, Script::Ethiopic),
+    (0x12C6, Script::Unknown),
+    (0x12C8, Script::Ethiopic),
+    (0x12D7, Script::Unknown),
+    (0x12D8, Script::Ethiopic),
+    (0x1311, Script::Unknown),
+    (0x1312, Script::Ethiopic),
+    (0x1316, Script::Unknown),
+    (0x1318, Script::Ethiopic),
+    (0x135B, Script::Unknown),
+    (0x135D, Script::Ethiopic),
+    (0x137D, Script::Unknown),
+    (0x1380, Script::Ethiopic),
+    (0x139A, Script::Unknown),
+    (0x13A0, Script::Cherokee),
+    (0x13F6, Script::Unknown),
+    (0x13F8, Script::Cherokee),
+    (0x13FE, Script::Unknown),
+    (0x1400, Script::CanadianAboriginal),
+    (0x1680, Script::Ogham),
+    (0x169D, Script::Unknown),
+    (0x16A0, Script::Runic),
+    (0x16EB, Script::Common),
+    (0x16EE, Script::Runic),
+    (0x16F9, Script::Unknown),
+    (0x1700, Script::Tagalog),
+    (0x1716, Script::Unknown),
+    (0x171F, Script::Tagalog),
+    (0x1720, Script::Hanunoo),
+    (0x1735, Script::Common),
+    (0x1737, Script::Unknown),
+    (0x1740, Script::Buhid),
+    (0x1754, Script::Unknown),
+    (0x1760, Script::Tagbanwa),
+    (0x176D, Script::Unknown),
+    (0x176E, Script::Tagbanwa),
+    (0x1771, Script::Unknown),
+    (0x1772, Script::Tagbanwa),
+    (0x1774, Script::Unknown),
+    (0x1780, Script::Khmer),
+    (0x17DE, Script::Unknown),
+    (0x17E0, Script::Khmer),
+    (0x17EA, Script::Unknown),
+    (0x17F0, Script::Khmer),
+    (0x17FA, Script::Unknown),
+    (0x1800, Script::Mongolian),
+    (0x1802, Script::Common),
+    (0x1804, Script::Mongolian),
+    (0x1805, Script::Common),
+    (0x1806, Script::Mongolian),
+    (0x181A, Script::Unknown),
+    (0x1820, Script::Mongolian),
+    (0x1879, Script::Unknown),
+    (0x1880, Script::Mongolian),
+    (0x18AB, Script::Unknown),
+    (0x18B0, Script::CanadianAboriginal),
+    (0x18F6, Script::Unknown),
+    (0x1900, Script::Limbu),
+    (0x191F, Script::Unknown),
+    (0x1920, Script::Limbu),
+    (0x192C, Script::Unknown),
+    (0x1930, Script::Limbu),
+    (0x193C, Script::Unknown),
+    (0x1940, Script::Limbu),
+    (0x1941, Script::Unknown),
+    (0x1944, Script::Limbu),
+    (0x1950, Script::TaiLe),
+    (0x196E, Script::Unknown),
+    (0x1970, Script::TaiLe),
+    (0x1975, Script::Unknown),
+    (0x1980, Script::NewTaiLue),
+    (0x19AC, Script::Unknown),
+    (0x19B0, Script::NewTaiLue),
+    (0x19CA, Script::Unknown),
+    (0x19D0, Script::NewTaiLue),
+    (0x19DB, Script::Unknown),
+    (0x19DE, Script::NewTaiLue),
+    (0x19E0, Script::Khmer),
+    (0x1A00, Script::Buginese),
+    (0x1A1C, Script::Unknown),
+    (0x1A1E, Script::Buginese),
+    (0x1A20, Script::TaiTham),
+    (0x1A5F, Script::Unknown),
+    (0x1A60, Script::TaiTham),
+    (0x1A7D, Script::Unknown),
+    (0x1A7F, Script::TaiTham),
+    (0x1A8A, Script::Unknown),
+    (0x1A90, Script::TaiTham),
+    (0x1A9A, Script::Unknown),
+    (0x1AA0, Script::TaiTham),
+    (0x1AAE, Script::Unknown),
+    (0x1AB0, Script::Inherited),
+    (0x1ACF, Script::Unknown),
+    (0x1B00, Script::Balinese),
+    (0x1B4D, Script::Unknown),
+    (0x1B50, Script::Balinese),
+    (0x1B7F, Script::Unknown),
+    (0x1B80, Script::Sundanese),
+    (0x1BC0, Script::Batak),
+    (0x1BF4, Script::Unknown),
+    (0x1BFC, Script::Batak),
+    (0x1C00, Script::Lepcha),
+    (0x1C38, Script::Unknown),
+    (0x1C3B, Script::Lepcha),
+    (0x1C4A, Script::Unknown),
+    (0x1C4D, Script::Lepcha),
+    (0x1C50, Script::OlChiki),
+    (0x1C80, Script::Cyrillic),
+    (0x1C89, Script::Unknown),
+    (0x1C90, Script::Georgian),
+    (0x1CBB, Script::Unknown),
+    (0x1CBD, Script::Georgian),
+    (0x1CC0, Script::Sundanese),
+    (0x1CC8, Script::Unknown),
+    (0x1CD0, Script::Inherited),
+    (0x1CD3, Script::Common),
+    (0x1CD4, Script::Inherited),
+    (0x1CE1, Script::Common),
+    (0x1CE2, Script::Inherited),
+    (0x1CE9, Script::Common),
+    (0x1CED, Script::Inherited),
+    (0x1CEE, Script::Common),
+    (0x1CF4, Script::Inherited),
+    (0x1CF5, Script::Common),
+    (0x1CF8, Script::Inherited),
+    (0x1CFA, Script::Common),
+    (0x1CFB, Script::Unknown),
+    (0x1D00, Script::Latin),
+    (0x1D26, Script::Greek),
+    (0x1D2B, Script::Cyrillic),
+    (0x1D2C, Script::Latin),
+    (0x1D5D, Script::Greek),
+    (0x1D62, Script::Latin),
+    (0x1D66, Script::Greek),
+    (0x1D6B, Script::Latin),
+    (0x1D78, Script::Cyrillic),
+    (0x1D79, Script::Latin),
+    (0x1DBF, Script::Greek),
+    (0x1DC0, Script::Inherited),
+    (0x1E00, Script::Latin),
+    (0x1F00, Script::Greek),
+    (0x1F16, Script::Unknown),
+    (0x1F18, Script::Greek),
+    (0x1F1E, Script::Unknown),
+    (0x1F20, Script::Greek),
+    (0x1F46, Script::Unknown),
+    (0x1F48, Script::Greek),
+    (0x1F4E, Script::Unknown),
+    (0x1F50, Script::Greek),
+    (0x1F58, Script::Unknown),
+    (0x1F59, Script::Greek),
+    (0x1F5A, Script::Unknown),
+    (0x1F5B, Script::Greek),
+    (0x1F5C, Script::Unknown),
+    (0x1F5D, Script::Greek),
+    (0x1F5E, Script::Unknown),
+    (0x1F5F, Script::Greek),
+    (0x1F7E, Script::Unknown),
+    (0x1F80, Script::Greek),
+    (0x1FB5, Script::Unknown),
+    (0x1FB6, Script::Greek),
+    (0x1FC5, Script::Unknown),
+    (0x1FC6, Script::Greek),
+    (0x1FD4, Script::Unknown),
+    (0x1FD6, Script::Greek),
+    (0x1FDC, Script::Unknown),
+    (0x1FDD, Script::Greek),
+    (0x1FF0, Script::Unknown),
+    (0x1FF2, Script::Greek),
+    (0x1FF5, Script::Unknown),
+    (0x1FF6, Script::Greek),
+    (0x1FFF, Script::Unknown),
+    (0x2000, Script::Common),
+    (0x200C, Script::Inherited),
+    (0x200E, Script::Common),
+    (0x2065, Script::Unknown),
+    (0x2066, Script::Common),
+    (0x2071, Script::Latin),
+    (0x2072, Script::Unknown),
+    (0x2074, Script::Common),
+    (0x207F, Script::Latin),
+    (0x2080, Script::Common),
+    (0x208F, Script::Unknown),
+    (0x2090, Script::Latin),
+    (0x209D, Script::Unknown),
+    (0x20A0, Script::Common),
+    (0x20C1, Script::Unknown),
+    (0x20D0, Script::Inherited),
+    (0x20F1, Script::Unknown),
+    (0x2100, Script::Common),
+    (0x2126, Script::Greek),
+    (0x2127, Script::Common),
+    (0x212A, Script::Latin),
+    (0x212C, Script::Common),
+    (0x2132, Script::Latin),
+    (0x2133, Script::Common),
+    (0x214E, Script::Latin),
+    (0x214F, Script::Common),
+    (0x2160, Script::Latin),
+    (0x2189, Script::Common),
+    (0x218C, Script::Unknown),
+    (0x2190, Script::Common),
+    (0x2427, Script::Unknown),
+    (0x2440, Script::Common),
+    (0x244B, Script::Unknown),
+    (0x2460, Script::Common),
+    (0x2800, Script::Braille),
+    (0x2900, Script::Common),
+    (0x2B74, Script::Unknown),
+    (0x2B76, Script::Common),
+    (0x2B96, Script::Unknown),
+    (0x2B97, Script::Common),
+    (0x2C00, Script::Glagolitic),
+    (0x2C60, Script::Latin),
+    (0x2C80, Script::Coptic),
+    (0x2CF4, Script::Unknown),
+    (0x2CF9, Script::Coptic),
+    (0x2D00, Script::Georgian),
+    (0x2D26, Script::Unknown),
+    (0x2D27, Script::Georgian),
+    (0x2D28, Script::Unknown),
+    (0x2D2D, Script::Georgian),
+    (0x2D2E, Script::Unknown),
+    (0x2D30, Script::Tifinagh),
+    (0x2D68, Script::Unknown),
+    (0x2D6F, Script::Tifinagh),
+    (0x2D71, Script::Unknown),
+    (0x2D7F, Script::Tifinagh),
+    (0x2D80, Script::Ethiopic),
+    (0x2D97, Script::Unknown),
+    (0x2DA0, Script::Ethiopic),
+    (0x2DA7, Script::Unknown),
+    (0x2DA8, Script::Ethiopic),
+    (0x2DAF, Script::Unknown),
+    (0x2DB0, Script::Ethiopic),
+    (0x2DB7, Script::Unknown),
+    (0x2DB8, Script::Ethiopic),
+    (0x2DBF, Script::Unknown),
+    (0x2DC0, Script::Ethiopic),
+    (0x2DC7, Script::Unknown),
+    (0x2DC8, Script::Ethiopic),
+    (0x2DCF, Script::Unknown),
+    (0x2DD0, Script::Ethiopic),
+    (0x2DD7, Script::Unknown),
+    (0x2DD8, Script::Ethiopic),
+    (0x2DDF, Script::Unknown),
+    (0x2DE0, Script::Cyrillic),
+    (0x2E00, Script::Common),
+    (0x2E5E, Script::Unknown),
+    (0x2E80, Script::Han),
+    (0x2E9A, Script::Unknown),
+    (0x2E9B, Script::Han),
+    (0x2EF4, Script::Unknown),
+    (0x2F00, Script::Han),
+    (0x2FD6, Script::Unknown),
+    (0x2FF0, Script::Common),
+    (0x2FFC, Script::Unknown),
+    (0x3000, Script::Common),
+    (0x3005, Script::Han),
+    (0x3006, Script::Common),
+    (0x3007, Script::Han),
+    (0x3008, Script::Common),
+    (0x3021, Script::Han),
+    (0x302A, Script::Inherited),
+    (0x302E, Script::Hangul),
+    (0x3030, Script::Common),
+    (0x3038, Script::Han),
+    (0x303C, Script::Common),
+    (0x3040, Script::Unknown),
+    (0x3041, Script::Hiragana),
+    (0x3097, Script::Unknown),
+    (0x3099, Script::Inherited),
+    (0x309B, Script::Common),
+    (0x309D, Script::Hiragana),
+    (0x30A0, Script::Common),
+    (0x30A1, Script::Katakana),
+    (0x30FB, Script::Common),
+    (0x30FD, Script::Katakana),
+    (0x3100, Script::Unknown),
+    (0x3105, Script::Bopomofo),
+    (0x3130, Script::Unknown),
+    (0x3131, Script::Hangul),
+    (0x318F, Script::Unknown),
+    (0x3190, Script::Common),
+    (0x31A0, Script::Bopomofo),
+    (0x31C0, Script::Common),
+    (0x31E4, Script::Unknown),
+    (0x31F0, Script::Katakana),
+    (0x3200, Script::Hangul),
+    (0x321F, Script::Unknown),
+    (0x3220, Script::Common),
+    (0x3260, Script::Hangul),
+    (0x327F, Script::Common),
+    (0x32D0, Script::Katakana),
+    (0x32FF, Script::Common),
+    (0x3300, Script::Katakana),
+    (0x3358, Script::Common),
+    (0x3400, Script::Han),
+    (0x4DC0, Script::Common),
+    (0x4E00, Script::Han),
+    (0xA000, Script::Yi),
+    (0xA48D, Script::Unknown),
+    (0xA490, Script::Yi),
+    (0xA4C7, Script::Unknown),
+    (0xA4D0, Script::Lisu),
+    (0xA500, Script::Vai),
+    (0xA62C, Script::Unknown),
+    (0xA640, Script::Cyrillic),
+    (0xA6A0, Script::Bamum),
+    (0xA6F8, Script::Unknown),
+    (0xA700, Script::Common),
+    (0xA722, Script::Latin),
+    (0xA788, Script::Common),
+    (0xA78B, Script::Latin),
+    (0xA7CB, Script::Unknown),
+    (0xA7D0, Script::Latin),
+    (0xA7D2, Script::Unknown),
+    (0xA7D3, Script::Latin),
+    (0xA7D4, Script::Unknown),
+    (0xA7D5, Script::Latin),
+    (0xA7DA, Script::Unknown),
+    (0xA7F2, Script::Latin),
+    (0xA800, Script::SylotiNagri),
+    (0xA82D, Script::Unknown),
+    (0xA830, Script::Common),
+    (0xA83A, Script::Unknown),
+    (0xA840, Script::PhagsPa),
+    (0xA878, Script::Unknown),
+    (0xA880, Script::Saurashtra),
+    (0xA8C6, Script::Unknown),
+    (0xA8CE, Script::Saurashtra),
+    (0xA8DA, Script::Unknown),
+    (0xA8E0, Script::Devanagari),
+    (0xA900, Script::KayahLi),
+    (0xA92E, Script::Common),
+    (0xA92F, Script::KayahLi),
+    (0xA930, Script::Rejang),
+    (0xA954, Script::Unknown),
+    (0xA95F, Script::Rejang),
+    (0xA960, Script::Hangul),
+    (0xA97D, Script::Unknown),
+    (0xA980, Script::Javanese),
+    (0xA9CE, Script::Unknown),
+    (0xA9CF, Script::Common),
+    (0xA9D0, Script::Javanese),
+    (0xA9DA, Script::Unknown),
+    (0xA9DE, Script::Javanese),
+    (0xA9E0, Script::Myanmar),
+    (0xA9FF, Script::Unknown),
+    (0xAA00, Script::Cham),
+    (0xAA37, Script::Unknown),
+    (0xAA40, Script::Cham),
+    (0xAA4E, Script::Unknown),
+    (0xAA50, Script::Cham),
+    (0xAA5A, Script::Unknown),
+    (0xAA5C, Script::Cham),
+    (0xAA60, Script::Myanmar),
+    (0xAA80, Script::TaiViet),
+    (0xAAC3, Script::Unknown),
+    (0xAADB, Script::TaiViet),
+    (0xAAE0, Script::MeeteiMayek),
+    (0xAAF7, Script::Unknown),
+    (0xAB01, Script::Ethiopic),
+    (0xAB07, Script::Unknown),
+    (0xAB09, Script::Ethiopic),
+    (0xAB0F, Script::Unknown),
+    (0xAB11, Script::Ethiopic),
+    (0xAB17, Script::Unknown),
+    (0xAB20, Script::Ethiopic),
+    (0xAB27, Script::Unknown),
+    (0xAB28, Script::Ethiopic),
+    (0xAB2F, Script::Unknown),
+    (0xAB30, Script::Latin),
+    (0xAB5B, Script::Common),
+    (0xAB5C, Script::Latin),
+    (0xAB65, Script::Greek),
+    (0xAB66, Script::Latin),
+    (0xAB6A, Script::Common),
+    (0xAB6C, Script::Unknown),
+    (0xAB70, Script::Cherokee),
+    (0xABC0, Script::MeeteiMayek),
+    (0xABEE, Script::Unknown),
+    (0xABF0, Script::MeeteiMayek),
+    (0xABFA, Script::Unknown),
+    (0xAC00, Script::Hangul),
+    (0xD7A4, Script::Unknown),
+    (0xD7B0, Script::Hangul),
+    (0xD7C7, Script::Unknown),
+    (0xD7CB, Script::Hangul),
+    (0xD7FC, Script::Unknown),
+    (0xF900, Script::Han),
+    (0xFA6E, Script::Unknown),
+    (0xFA70, Script::Han),
+    (0xFADA, Script::Unknown),
+    (0xFB00, Script::Latin),
+    (0xFB07, Script::Unknown),
+    (0xFB13, Script::Armenian),
+    (0xFB18, Script::Unknown),
+    (0xFB1D, Script::Hebrew),
+    (0xFB37, Script::Unknown),
+    (0xFB38, Script::Hebrew),
+    (0xFB3D, Script::Unknown),
+    (0xFB3E, Script::Hebrew),
+    (0xFB3F, Script::Unknown),
+    (0xFB40, Script::Hebrew),
+    (0xFB42, Script::Unknown),
+    (0xFB43, Script::Hebrew),
+    (0xFB45, Script::Unknown),
+    (0xFB46, Script::Hebrew),
+    (0xFB50, Script::Arabic),
+    (0xFBC3, Script::Unknown),
+    (0xFBD3, Script::Arabic),
+    (0xFD3E, Script::Common),
+    (0xFD40, Script::Arabic),
+    (0xFD90, Script::Unknown),
+    (0xFD92, Script::Arabic),
+    (0xFDC8, Script::Unknown),
+    (0xFDCF, Script::Arabic),
+    (0xFDD0, Script::Unknown),
+    (0xFDF0, Script::Arabic),
+    (0xFE00, Script::Inherited),
+    (0xFE10, Script::Common),
+    (0xFE1A, Script::Unknown),
+    (0xFE20, Script::Inherited),
+    (0xFE2E, Script::Cyrillic),
+    (0xFE30, Script::Common),
+    (0xFE53, Script::Unknown),
+    (0xFE54, Script::Common),
+    (0xFE67, Script::Unknown),
+    (0xFE68, Script::Common),
+    (0xFE6C, Script::Unknown),
+    (0xFE70, Script::Arabic),
+    (0xFE75, Script::Unknown),
+    (0xFE76, Script::Arabic),
+    (0xFEFD, Script::Unknown),
+    (0xFEFF, Script::Common),
+    (0xFF00, Script::Unknown),
+    (0xFF01, Script::Common),
+    (0xFF21, Script::Latin),
+    (0xFF3B, Script::Common),
+    (0xFF41, Script::Latin),
+    (0xFF5B, Script::Common),
+    (0xFF66, Script::Katakana),
+    (0xFF70, Script::Common),
+    (0xFF71, Script::Katakana),
+    (0xFF9E, Script::Common),
+    (0xFFA0, Script::Hangul),
+    (0xFFBF, Script::Unknown),
+    (0xFFC2, Script::Hangul),
+    (0xFFC8, Script::Unknown),
+    (0xFFCA, Script::Hangul),
+    (0xFFD0, Script::Unknown),
+    (0xFFD2, Script::Hangul),
+    (0xFFD8, Script::Unknown),
+    (0xFFDA, Script::Hangul),
+    (0xFFDD, Script::Unknown),
+    (0xFFE0, Script::Common),
+    (0xFFE7, Script::Unknown),
+    (0xFFE8, Script::Common),
+    (0xFFEF, Script::Unknown),
+    (0xFFF9, Script::Common),
+    (0xFFFE, Script::Unknown),
+    (0x10000, Script::LinearB),
+    (0x1000C, Script::Unknown),
+    (0x1000D, Script::LinearB),
+    (0x10027, Script::Unknown),
+    (0x10028, Script::LinearB),
+    (0x1003B, Script::Unknown),
+    (0x1003C, Script::LinearB),
+    (0x1003E, Script::Unknown),
+    (0x1003F, Script::LinearB),
+    (0x1004E, Script::Unknown),
+    (0x10050, Script::LinearB),
+    (0x1005E, Script::Unknown),
+    (0x10080, Script::LinearB),
+    (0x100FB, Script::Unknown),
+    (0x10100, Script::Common),
+    (0x10103, Script::Unknown),
+    (0x10107, Script::Common),
+    (0x10134, Script::Unknown),
+    (0x10137, Script::Common),
+    (0x10140, Script::Greek),
+    (0x1018F, Script::Unknown),
+    (0x10190, Script::Common),
+    (0x1019D, Script::Unknown),
+    (0x101A0, Script::Greek),
+    (0x101A1, Script::Unknown),
+    (0x101D0, Script::Common),
+    (0x101FD, Script::Inherited),
+    (0x101FE, Script::Unknown),
+    (0x10280, Script::Lycian),
+    (0x1029D, Script::Unknown),
+    (0x102A0, Script::Carian),
+    (0x102D1, Script::Unknown),
+    (0x102E0, Script::Inherited),
+    (0x102E1, Script::Common),
+    (0x102FC, Script::Unknown),
+    (0x10300, Script::OldItalic),
+    (0x10324, Script::Unknown),
+    (0x1032D, Script::OldItalic),
+    (0x10330, Script::Gothic),
+    (0x1034B, Script::Unknown),
+    (0x10350, Script::OldPermic),
+    (0x1037B, Script::Unknown),
+    (0x10380, Script::Ugaritic),
+    (0x1039E, Script::Unknown),
+    (0x1039F, Script::Ugaritic),
+    (0x103A0, Script::OldPersian),
+    (0x103C4, Script::Unknown),
+    (0x103C8, Script::OldPersian),
+    (0x103D6, Script::Unknown),
+    (0x10400, Script::Deseret),
+    (0x10450, Script::Shavian),
+    (0x10480, Script::Osmanya),
+    (0x1049E, Script::Unknown),
+    (0x104A0, Script::Osmanya),
+    (0x104AA, Script::Unknown),
+    (0x104B0, Script::Osage),
+    (0x104D4, Script::Unknown),
+    (0x104D8, Script::Osage),
+    (0x104FC, Script::Unknown),
+    (0x10500, Script::Elbasan),
+    (0x10528, Script::Unknown),
+    (0x10530, Script::CaucasianAlbanian),
+    (0x10564, Script::Unknown),
+    (0x1056F, Script::CaucasianAlbanian),
+    (0x10570, Script::Vithkuqi),
+    (0x1057B, Script::Unknown),
+    (0x1057C, Script::Vithkuqi),
+    (0x1058B, Script::Unknown),
+    (0x1058C, Script::Vithkuqi),
+    (0x10593, Script::Unknown),
+    (0x10594, Script::Vithkuqi),
+    (0x10596, Script::Unknown),
+    (0x10597, Script::Vithkuqi),
+    (0x105A2, Script::Unknown),
+    (0x105A3, Script::Vithkuqi),
+    (0x105B2, Script::Unknown),
+    (0x105B3, Script::Vithkuqi),
+    (0x105BA, Script::Unknown),
+    (0x105BB, Script::Vithkuqi),
+    (0x105BD, Script::Unknown),
+    (0x10600, Script::LinearA),
+    (0x10737, Script::Unknown),
+    (0x10740, Script::LinearA),
+    (0x10756, Script::Unknown),
+    (0x10760, Script::LinearA),
+    (0x10768, Script::Unknown),
+    (0x10780, Script::Latin),
+    (0x10786, Script::Unknown),
+    (0x10787, Script::Latin),
+    (0x107B1, Script::Unknown),
+    (0x107B2, Script::Latin),
+    (0x107BB, Script::Unknown),
+    (0x10800, Script::Cypriot),
+    (0x10806, Script::Unknown),
+    (0x10808, Script::Cypriot),
+    (0x10809, Script::Unknown),
+    (0x1080A, Script::Cypriot),
+    (0x10836, Script::Unknown),
+    (0x10837, Script::Cypriot),
+    (0x10839, Script::Unknown),
+    (0x1083C, Script::Cypriot),
+    (0x1083D, Script::Unknown),
+    (0x1083F, Script::Cypriot),
+    (0x10840, Script::ImperialAramaic),
+    (0x10856, Script::Unknown),
+    (0x10857, Script::ImperialAramaic),
+    (0x10860, Script::Palmyrene),
+    (0x10880, Script::Nabataean),
+    (0x1089F, Script::Unknown),
+    (0x108A7, Script::Nabataean),
+    (0x108B0, Script::Unknown),
+    (0x108E0, Script::Hatran),
+    (0x108F3, Script::Unknown),
+    (0x108F4, Script::Hatran),
+    (0x108F6, Script::Unknown),
+    (0x108FB, Script::Hatran),
+    (0x10900, Script::Phoenician),
+    (0x1091C, Script::Unknown),
+    (0x1091F, Script::Phoenician),
+    (0x10920, Script::Lydian),
+    (0x1093A, Script::Unknown),
+    (0x1093F, Script::Lydian),
+    (0x10940, Script::Unknown),
+    (0x10980, Script::MeroiticHieroglyphs),
+    (0x109A0, Script::MeroiticCursive),
+    (0x109B8, Script::Unknown),
+    (0x109BC, Script::MeroiticCursive),
+    (0x109D0, Script::Unknown),
+    (0x109D2, Script::MeroiticCursive),
+    (0x10A00, Script::Kharoshthi),
+    (0x10A04, Script::Unknown),
+    (0x10A05, Script::Kharoshthi),
+    (0x10A07, Script::Unknown),
+    (0x10A0C, Script::Kharoshthi),
+    (0x10A14, Script::Unknown),
+    (0x10A15, Script::Kharoshthi),
+    (0x10A18, Script::Unknown),
+    (0x10A19, Script::Kharoshthi),
+    (0x10A36, Script::Unknown),
+    (0x10A38, Script::Kharoshthi),
+    (0x10A3B, Script::Unknown),
+    (0x10A3F, Script::Kharoshthi),
+    (0x10A49, Script::Unknown),
+    (0x10A50, Script::Kharoshthi),
+    (0x10A59, Script::Unknown),
+    (0x10A60, Script::OldSouthArabian),
+    (0x10A80, Script::OldNorthArabian),
+    (0x10AA0, Script::Unknown),
+    (0x10AC0, Script::Manichaean),
+    (0x10AE7, Script::Unknown),
+    (0x10AEB, Script::Manichaean),
+    (0x10AF7, Script::Unknown),
+    (0x10B00, Script::Avestan),
+    (0x10B36, Script::Unknown),
+    (0x10B39, Script::Avestan),
+    (0x10B40, Script::InscriptionalParthian),
+    (0x10B56, Script::Unknown),
+    (0x10B58, Script::InscriptionalParthian),
+    (0x10B60, Script::InscriptionalPahlavi),
+    (0x10B73, Script::Unknown),
+    (0x10B78, Script::InscriptionalPahlavi),
+    (0x10B80, Script::PsalterPahlavi),
+    (0x10B92, Script::Unknown),
+    (0x10B99, Script::PsalterPahlavi),
+    (0x10B9D, Script::Unknown),
+    (0x10BA9, Script::PsalterPahlavi),
+    (0x10BB0, Script::Unknown),
+    (0x10C00, Script::OldTurkic),
+    (0x10C49, Script::Unknown),
+    (0x10C80, Script::OldHungarian),
+    (0x10CB3, Script::Unknown),
+    (0x10CC0, Script::OldHungarian),
+    (0x10CF3, Script::Unknown),
+    (0x10CFA, Script::OldHungarian),
+    (0x10D00, Script::HanifiRohingya),
+    (0x10D28, Script::Unknown),
+    (0x10D30, Script::HanifiRohingya),
+    (0x10D3A, Script::Unknown),
+    (0x10E60, Script::Arabic),
+    (0x10E7F, Script::Unknown),
+    (0x10E80, Script::Yezidi),
+    (0x10EAA, Script::Unknown),
+    (0x10EAB, Script::Yezidi),
+    (0x10EAE, Script::Unknown),
+    (0x10EB0, Script::Yezidi),
+    (0x10EB2, Script::Unknown),
+    (0x10F00, Script::OldSogdian),
+    (0x10F28, Script::Unknown),
+    (0x10F30, Script::Sogdian),
+    (0x10F5A, Script::Unknown),
+    (0x10F70, Script::OldUyghur),
+    (0x10F8A, Script::Unknown),
+    (0x10FB0, Script::Chorasmian),
+    (0x10FCC, Script::Unknown),
+    (0x10FE0, Script::Elymaic),
+    (0x10FF7, Script::Unknown),
+    (0x11000, Script::Brahmi),
+    (0x1104E, Script::Unknown),
+    (0x11052, Script::Brahmi),
+    (0x11076, Script::Unknown),
+    (0x1107F, Script::Brahmi),
+    (0x11080, Script::Kaithi),
+    (0x110C3, Script::Unknown),
+    (0x110CD, Script::Kaithi),
+    (0x110CE, Script::Unknown),
+    (0x110D0, Script::SoraSompeng),
+    (0x110E9, Script::Unknown),
+    (0x110F0, Script::SoraSompeng),
+    (0x110FA, Script::Unknown),
+    (0x11100, Script::Chakma),
+    (0x11135, Script::Unknown),
+    (0x11136, Script::Chakma),
+    (0x11148, Script::Unknown),
+    (0x11150, Script::Mahajani),
+    (0x11177, Script::Unknown),
+    (0x11180, Script::Sharada),
+    (0x111E0, Script::Unknown),
+    (0x111E1, Script::Sinhala),
+    (0x111F5, Script::Unknown),
+    (0x11200, Script::Khojki),
+    (0x11212, Script::Unknown),
+    (0x11213, Script::Khojki),
+    (0x1123F, Script::Unknown),
+    (0x11280, Script::Multani),
+    (0x11287, Script::Unknown),
+    (0x11288, Script::Multani),
+    (0x11289, Script::Unknown),
+    (0x1128A, Script::Multani),
+    (0x1128E, Script::Unknown),
+    (0x1128F, Script::Multani),
+    (0x1129E, Script::Unknown),
+    (0x1129F, Script::Multani),
+    (0x112AA, Script::Unknown),
+    (0x112B0, Script::Khudawadi),
+    (0x112EB, Script::Unknown),
+    (0x112F0, Script::Khudawadi),
+    (0x112FA, Script::Unknown),
+    (0x11300, Script::Grantha),
+    (0x11304, Script::Unknown),
+    (0x11305, Script::Grantha),
+    (0x1130D, Script::Unknown),
+    (0x1130F, Script::Grantha),
+    (0x11311, Script::Unknown),
+    (0x11313, Script::Grantha),
+    (0x11329, Script::Unknown),
+    (0x1132A, Script::Grantha),
+    (0x11331, Script::Unknown),
+    (0x11332, Script::Grantha),
+    (0x11334, Script::Unknown),
+    (0x11335, Script::Grantha),
+    (0x1133A, Script::Unknown),
+    (0x1133B, Script::Inherited),
+    (0x1133C, Script::Grantha),
+    (0x11345, Script::Unknown),
+    (0x11347, Script::Grantha),
+    (0x11349, Script::Unknown),
+    (0x1134B, Script::Grantha),
+    (0x1134E, Script::Unknown),
+    (0x11350, Script::Grantha),
+    (0x11351, Script::Unknown),
+    (0x11357, Script::Grantha),
+    (0x11358, Script::Unknown),
+    (0x1135D, Script::Grantha),
+    (0x11364, Script::Unknown),
+    (0x11366, Script::Grantha),
+    (0x1136D, Script::Unknown),
+    (0x11370, Script::Grantha),
+    (0x11375, Script::Unknown),
+    (0x11400, Script::Newa),
+    (0x1145C, Script::Unknown),
+    (0x1145D, Script::Newa),
+    (0x11462, Script::Unknown),
+    (0x11480, Script::Tirhuta),
+    (0x114C8, Script::Unknown),
+    (0x114D0, Script::Tirhuta),
+    (0x114DA, Script::Unknown),
+    (0x11580, Script::Siddham),
+    (0x115B6, Script::Unknown),
+    (0x115B8, Script::Siddham),
+    (0x115DE, Script::Unknown),
+    (0x11600, Script::Modi),
+    (0x11645, Script::Unknown),
+    (0x11650, Script::Modi),
+    (0x1165A, Script::Unknown),
+    (0x11660, Script::Mongolian),
+    (0x1166D, Script::Unknown),
+    (0x11680, Script::Takri),
+    (0x116BA, Script::Unknown),
+    (0x116C0, Script::Takri),
+    (0x116CA, Script::Unknown),
+    (0x11700, Script::Ahom),
+    (0x1171B, Script::Unknown),
+    (0x1171D, Script::Ahom),
+    (0x1172C, Script::Unknown),
+    (0x11730, Script::Ahom),
+    (0x11747, Script::Unknown),
+    (0x11800, Script::Dogra),
+    (0x1183C, Script::Unknown),
+    (0x118A0, Script::WarangCiti),
+    (0x118F3, Script::Unknown),
+    (0x118FF, Script::WarangCiti),
+    (0x11900, Script::DivesAkuru),
+    (0x11907, Script::Unknown),
+    (0x11909, Script::DivesAkuru),
+    (0x1190A, Script::Unknown),
+    (0x1190C, Script::DivesAkuru),
+    (0x11914, Script::Unknown),
+    (0x11915, Script::DivesAkuru),
+    (0x11917, Script::Unknown),
+    (0x11918, Script::DivesAkuru),
+    (0x11936, Script::Unknown),
+    (0x11937, Script::DivesAkuru),
+    (0x11939, Script::Unknown),
+    (0x1193B, Script::DivesAkuru),
+    (0x11947, Script::Unknown),
+    (0x11950, Script::DivesAkuru),
+    (0x1195A, Script::Unknown),
+    (0x119A0, Script::Nandinagari),
+    (0x119A8, Script::Unknown),
+    (0x119AA, Script::Nandinagari),
+    (0x119D8, Script::Unknown),
+    (0x119DA, Script::Nandinagari),
+    (0x119E5, Script::Unknown),
+    (0x11A00, Script::ZanabazarSquare),
+    (0x11A48, Script::Unknown),
+    (0x11A50, Script::Soyombo),
+    (0x11AA3, Script::Unknown),
+    (0x11AB0, Script::CanadianAboriginal),
+    (0x11AC0, Script::PauCinHau),
+    (0x11AF9, Script::Unknown),
+    (0x11C00, Script::Bhaiksuki),
+    (0x11C09, Script::Unknown),
+    (0x11C0A, Script::Bhaiksuki),
+    (0x11C37, Script::Unknown),
+    (0x11C38, Script::Bhaiksuki),
+    (0x11C46, Script::Unknown),
+    (0x11C50, Script::Bhaiksuki),
+    (0x11C6D, Script::Unknown),
+    (0x11C70, Script::Marchen),
+    (0x11C90, Script::Unknown),
+    (0x11C92, Script::Marchen),
+    (0x11CA8, Script::Unknown),
+    (0x11CA9, Script::Marchen),
+    (0x11CB7, Script::Unknown),
+    (0x11D00, Script::MasaramGondi),
+    (0x11D07, Script::Unknown),
+    (0x11D08, Script::MasaramGondi),
+    (0x11D0A, Script::Unknown),
+    (0x11D0B, Script::MasaramGondi),
+    (0x11D37, Script::Unknown),
+    (0x11D3A, Script::MasaramGondi),
+    (0x11D3B, Script::Unknown),
+    (0x11D3C, Script::MasaramGondi),
+    (0x11D3E, Script::Unknown),
+    (0x11D3F, Script::MasaramGondi),
+    (0x11D48, Script::Unknown),
+    (0x11D50, Script::MasaramGondi),
+    (0x11D5A, Script::Unknown),
+    (0x11D60, Script::GunjalaGondi),
+    (0x11D66, Script::Unknown),
+    (0x11D67, Script::GunjalaGondi),
+    (0x11D69, Script::Unknown),
+    (0x11D6A, Script::GunjalaGondi),
+    (0x11D8F, Script::Unknown),
+    (0x11D90, Script::GunjalaGondi),
+    (0x11D92, Script::Unknown),
+    (0x11D93, Script::GunjalaGondi),
+    (0x11D99, Script::Unknown),
+    (0x11DA0, Script::GunjalaGondi),
+    (0x11DAA, Script::Unknown),
+    (0x11EE0, Script::Makasar),
+    (0x11EF9, Script::Unknown),
+    (0x11FB0, Script::Lisu),
+    (0x11FB1, Script::Unknown),
+    (0x11FC0, Script::Tamil),
+    (0x11FF2, Script::Unknown),
+    (0x11FFF, Script::Tamil),
+    (0x12000, Script::Cuneiform),
+    (0x1239A, Script::Unknown),
+    (0x12400, Script::Cuneiform),
+    (0x1246F, Script::Unknown),
+    (0x12470, Script::Cuneiform),
+    (0x12475, Script::Unknown),
+    (0x12480, Script::Cuneiform),
+    (0x12544, Script::Unknown),
+    (0x12F90, Script::CyproMinoan),
+    (0x12FF3, Script::Unknown),
+    (0x13000, Script::EgyptianHieroglyphs),
+    (0x1342F, Script::Unknown),
+    (0x13430, Script::EgyptianHieroglyphs),
+    (0x13439, Script::Unknown),
+    (0x14400, Script::AnatolianHieroglyphs),
+    (0x14647, Script::Unknown),
+    (0x16800, Script::Bamum),
+    (0x16A39, Script::Unknown),
+    (0x16A40, Script::Mro),
+    (0x16A5F, Script::Unknown),
+    (0x16A60, Script::Mro),
+    (0x16A6A, Script::Unknown),
+    (0x16A6E, Script::Mro),
+    (0x16A70, Script::Tangsa),
+    (0x16ABF, Script::Unknown),
+    (0x16AC0, Script::Tangsa),
+    (0x16ACA, Script::Unknown),
+    (0x16AD0, Script::BassaVah),
+    (0x16AEE, Script::Unknown),
+    (0x16AF0, Script::BassaVah),
+    (0x16AF6, Script::Unknown),
+    (0x16B00, Script::PahawhHmong),
+    (0x16B46, Script::Unknown),
+    (0x16B50, Script::PahawhHmong),
+    (0x16B5A, Script::Unknown),
+    (0x16B5B, Script::PahawhHmong),
+    (0x16B62, Script::Unknown),
+    (0x16B63, Script::PahawhHmong),
+    (0x16B78, Script::Unknown),
+    (0x16B7D, Script::PahawhHmong),
+    (0x16B90, Script::Unknown),
+    (0x16E40, Script::Medefaidrin),
+    (0x16E9B, Script::Unknown),
+    (0x16F00, Script::Miao),
+    (0x16F4B, Script::Unknown),
+    (0x16F4F, Script::Miao),
+    (0x16F88, Script::Unknown),
+    (0x16F8F, Script::Miao),
+    (0x16FA0, Script::Unknown),
+    (0x16FE0, Script::Tangut),
+    (0x16FE1, Script::Nushu),
+    (0x16FE2, Script::Han),
+    (0x16FE4, Script::KhitanSmallScript),
+    (0x16FE5, Script::Unknown),
+    (0x16FF0, Script::Han),
+    (0x16FF2, Script::Unknown),
+    (0x17000, Script::Tangut),
+    (0x187F8, Script::Unknown),
+    (0x18800, Script::Tangut),
+    (0x18B00, Script::KhitanSmallScript),
+    (0x18CD6, Script::Unknown),
+    (0x18D00, Script::Tangut),
+    (0x18D09, Script::Unknown),
+    (0x1AFF0, Script::Katakana),
+    (0x1AFF4, Script::Unknown),
+    (0x1AFF5, Script::Katakana),
+    (0x1AFFC, Script::Unknown),
+    (0x1AFFD, Script::Katakana),
+    (0x1AFFF, Script::Unknown),
+    (0x1B000, Script::Katakana),
+    (0x1B001, Script::Hiragana),
+    (0x1B120, Script::Katakana),
+    (0x1B123, Script::Unknown),
+    (0x1B150, Script::Hiragana),
+    (0x1B153, Script::Unknown),
+    (0x1B164, Script::Katakana),
+    (0x1B168, Script::Unknown),
+    (0x1B170, Script::Nushu),
+    (0x1B2FC, Script::Unknown),
+    (0x1BC00, Script::Duployan),
+    (0x1BC6B, Script::Unknown),
+    (0x1BC70, Script::Duployan),
+    (0x1BC7D, Script::Unknown),
+    (0x1BC80, Script::Duployan),
+    (0x1BC89, Script::Unknown),
+    (0x1BC90, Script::Duployan),
+    (0x1BC9A, Script::Unknown),
+    (0x1BC9C, Script::Duployan),
+    (0x1BCA0, Script::Common),
+    (0x1BCA4, Script::Unknown),
+    (0x1CF00, Script::Inherited),
+    (0x1CF2E, Script::Unknown),
+    (0x1CF30, Script::Inherited),
+    (0x1CF47, Script::Unknown),
+    (0x1CF50, Script::Common),
+    (0x1CFC4, Script::Unknown),
+    (0x1D000, Script::Common),
+    (0x1D0F6, Script::Unknown),
+    (0x1D100, Script::Common),
+    (0x1D127, Script::Unknown),
+    (0x1D129, Script::Common),
+    (0x1D167, Script::Inherited),
+    (0x1D16A, Script::Common),
+    (0x1D17B, Script::Inherited),
+    (0x1D183, Script::Common),
+    (0x1D185, Script::Inherited),
+    (0x1D18C, Script::Common),
+    (0x1D1AA, Script::Inherited),
+    (0x1D1AE, Script::Common),
+    (0x1D1EB, Script::Unknown),
+    (0x1D200, Script::Greek),
+    (0x1D246, Script::Unknown),
+    (0x1D2E0, Script::Common),
+    (0x1D2F4, Script::Unknown),
+    (0x1D300, Script::Common),
+    (0x1D357, Script::Unknown),
+    (0x1D360, Script::Common),
+    (0x1D379, Script::Unknown),
+    (0x1D400, Script::Common),
+    (0x1D455, Script::Unknown),
+    (0x1D456, Script::Common),
+    (0x1D49D, Script::Unknown),
+    (0x1D49E, Script::Common),
+    (0x1D4A0, Script::Unknown),
+    (0x1D4A2, Script::Common),
+    (0x1D4A3, Script::Unknown),
+    (0x1D4A5, Script::Common),
+    (0x1D4A7, Script::Unknown),
+    (0x1D4A9, Script::Common),
+    (0x1D4AD, Script::Unknown),
+    (0x1D4AE, Script::Common),
+    (0x1D4BA, Script::Unknown),
+    (0x1D4BB, Script::Common),
+    (0x1D4BC, Script::Unknown),
+    (0x1D4BD, Script::Common),
+    (0x1D4C4, Script::Unknown),
+    (0x1D4C5, Script::Common),
+    (0x1D506, Script::Unknown),
+    (0x1D507, Script::Common),
+    (0x1D50B, Script::Unknown),
+    (0x1D50D, Script::Common),
+    (0x1D515, Script::Unknown),
+    (0x1D516, Script::Common),
+    (0x1D51D, Script::Unknown),
+    (0x1D51E, Script::Common),
+    (0x1D53A, Script::Unknown),
+    (0x1D53B, Script::Common),
+    (0x1D53F, Script::Unknown),
+    (0x1D540, Script::Common),
+    (0x1D545, Script::Unknown),
+    (0x1D546, Script::Common),
+    (0x1D547, Script::Unknown),
+    (0x1D54A, Script::Common),
+    (0x1D551, Script::Unknown),
+    (0x1D552, Script::Common),
+    (0x1D6A6, Script::Unknown),
+    (0x1D6A8, Script::Common),
+    (0x1D7CC, Script::Unknown),
+    (0x1D7CE, Script::Common),
+    (0x1D800, Script::SignWriting),
+    (0x1DA8C, Script::Unknown),
+    (0x1DA9B, Script::SignWriting),
+    (0x1DAA0, Script::Unknown),
+    (0x1DAA1, Script::SignWriting),
+    (0x1DAB0, Script::Unknown),
+    (0x1DF00, Script::Latin),
+    (0x1DF1F, Script::Unknown),
+    (0x1E000, Script::Glagolitic),
+    (0x1E007, Script::Unknown),
+    (0x1E008, Script::Glagolitic),
+    (0x1E019, Script::Unknown),
+    (0x1E01B, Script::Glagolitic),
+    (0x1E022, Script::Unknown),
+    (0x1E023, Script::Glagolitic),
+    (0x1E025, Script::Unknown),
+    (0x1E026, Script::Glagolitic),
+    (0x1E02B, Script::Unknown),
+    (0x1E100, Script::NyiakengPuachueHmong),
+    (0x1E12D, Script::Unknown),
+    (0x1E130, Script::NyiakengPuachueHmong),
+    (0x1E13E, Script::Unknown),
+    (0x1E140, Script::NyiakengPuachueHmong),
+    (0x1E14A, Script::Unknown),
+    (0x1E14E, Script::NyiakengPuachueHmong),
+    (0x1E150, Script::Unknown),
+    (0x1E290, Script::Toto),
+    (0x1E2AF, Script::Unknown),
+    (0x1E2C0, Script::Wancho),
+    (0x1E2FA, Script::Unknown),
+    (0x1E2FF, Script::Wancho),
+    (0x1E300, Script::Unknown),
+    (0x1E7E0, Script::Ethiopic),
+    (0x1E7E7, Script::Unknown),
+    (0x1E7E8, Script::Ethiopic),
+    (0x1E7EC, Script::Unknown),
+    (0x1E7ED, Script::Ethiopic),
+    (0x1E7EF, Script::Unknown),
+    (0x1E7F0, Script::Ethiopic),
+    (0x1E7FF, Script::Unknown),
+    (0x1E800, Script::MendeKikakui),
+    (0x1E8C5, Script::Unknown),
+    (0x1E8C7, Script::MendeKikakui),
+    (0x1E8D7, Script::Unknown),
+    (0x1E900, Script::Adlam),
+    (0x1E94C, Script::Unknown),
+    (0x1E950, Script::Adlam),
+    (0x1E95A, Script::Unknown),
+    (0x1E95E, Script::Adlam),
+    (0x1E960, Script::Unknown),
+    (0x1EC71, Script::Common),
+    (0x1ECB5, Script::Unknown),
+    (0x1ED01, Script::Common),
+    (0x1ED3E, Script::Unknown),
+    (0x1EE00, Script::Arabic),
+    (0x1EE04, Script::Unknown),
+    (0x1EE05, Script::Arabic),
+    (0x1EE20, Script::Unknown),
+    (0x1EE21, Script::Arabic),
+    (0x1EE23, Script::Unknown),
+    (0x1EE24, Script::Arabic),
+    (0x1EE25, Script::Unknown),
+    (0x1EE27, Script::Arabic),
+    (0x1EE28, Script::Unknown),
+    (0x1EE29, Script::Arabic),
+    (0x1EE33, Script::Unknown),
+    (0x1EE34, Script::Arabic),
+    (0x1EE38, Script::Unknown),
+    (0x1EE39, Script::Arabic),
+    (0x1EE3A, Script::Unknown),
+    (0x1EE3B, Script::Arabic),
+    (0x1EE3C, Script::Unknown),
+    (0x1EE42, Script::Arabic),
+    (0x1EE43, Script::Unknown),
+    (0x1EE47, Script::Arabic),
+    (0x1EE48, Script::Unknown),
+    (0x1EE49, Script::Arabic),
+    (0x1EE4A, Script::Unknown),
+    (0x1EE4B, Script::Arabic),
+    (0x1EE4C, Script::Unknown),
+    (0x1EE4D, Script::Arabic),
+    (0x1EE50, Script::Unknown),
+    (0x1EE51, Script::Arabic),
+    (0x1EE53, Script::Unknown),
+    (0x1EE54, Script::Arabic),
+    (0x1EE55, Script::Unknown),
+    (0x1EE57, Script::Arabic),
+    (0x1EE58, Script::Unknown),
+    (0x1EE59, Script::Arabic),
+    (0x1EE5A, Script::Unknown),
+    (0x1EE5B, Script::Arabic),
+    (0x1EE5C, Script::Unknown),
+    (0x1EE5D, Script::Arabic),
+    (0x1EE5E, Script::Unknown),
+    (0x1EE5F, Script::Arabic),
+    (0x1EE60, Script::Unknown),
+    (0x1EE61, Script::Arabic),
+    (0x1EE63, Script::Unknown),
+    (0x1EE64, Script::Arabic),
+    (0x1EE65, Script::Unknown),
+    (0x1EE67, Script::Arabic),
+    (0x1EE6B, Script::Unknown),
+    (0x1EE6C, Script::Arabic),
+    (0x1EE73, Script::Unknown),
+    (0x1EE74, Script::Arabic),
+    (0x1EE78, Script::Unknown),
+    (0x1EE79, Script::Arabic),
+    (0x1EE7D, Script::Unknown),
+    (0x1EE7E, Script::Arabic),
+    (0x1EE7F, Script::Unknown),
+    (0x1EE80, Script::Arabic),
+    (0x1EE8A, Script::Unknown),
+    (0x1EE8B, Script::Arabic),
+    (0x1EE9C, Script::Unknown),
+    (0x1EEA1, Script::Arabic),
+    (0x1EEA4, Script::Unknown),
+    (0x1EEA5, Script::Arabic),
+    (0x1EEAA, Script::Unknown),
+    (0x1EEAB, Script::Arabic),
+    (0x1EEBC, Script::Unknown),
+    (0x1EEF0, Script::Arabic),
+    (0x1EEF2, Script::Unknown),
+    (0x1F000, Script::Common),
+    (0x1F02C, Script::Unknown),
+    (0x1F030, Script::Common),
+    (0x1F094, Script::Unknown),
+    (0x1F0A0, Script::Common),
+    (0x1F0AF, Script::Unknown),
+    (0x1F0B1, Script::Common),
+    (0x1F0C0, Script::Unknown),
+    (0x1F0C1, Script::Common),
+    (0x1F0D0, Script::Unknown),
+    (0x1F0D1, Script::Common),
+    (0x1F0F6, Script::Unknown),
+    (0x1F100, Script::Common),
+    (0x1F1AE, Script::Unknown),
+    (0x1F1E6, Script::Common),
+    (0x1F200, Script::Hiragana),
+    (0x1F201, Script::Common),
+    (0x1F203, Script::Unknown),
+    (0x1F210, Script::Common),
+    (0x1F23C, Script::Unknown),
+    (0x1F240, Script::Common),
+    (0x1F249, Script::Unknown),
+    (0x1F250, Script::Common),
+    (0x1F252, Script::Unknown),
+    (0x1F260, Script::Common),
+    (0x1F266, Script::Unknown),
+    (0x1F300, Script::Common),
+    (0x1F6D8, Script::Unknown),
+    (0x1F6DD, Script::Common),
+    (0x1F6ED, Script::Unknown),
+    (0x1F6F0, Script::Common),
+    (0x1F6FD, Script::Unknown),
+    (0x1F700, Script::Common),
+    (0x1F774, Script::Unknown),
+    (0x1F780, Script::Common),
+    (0x1F7D9, Script::Unknown),
+    (0x1F7E0, Script::Common),
+    (0x1F7EC, Script::Unknown),
+    (0x1F7F0, Script::Common),
+    (0x1F7F1, Script::Unknown),
+    (0x1F800, Script::Common),
+    (0x1F80C, Script::Unknown),
+    (0x1F810, Script::Common),
+    (0x1F848, Script::Unknown),
+    (0x1F850, Script::Common),
+    (0x1F85A, Script::Unknown),
+    (0x1F860, Script::Common),
+    (0x1F888, Script::Unknown),
+    (0x1F890, Script::Common),
+    (0x1F8AE, Script::Unknown),
+    (0x1F8B0, Script::Common),
+    (0x1F8B2, Script::Unknown),
+    (0x1F900, Script::Common),
+    (0x1FA54, Script::Unknown),
+    (0x1FA60, Script::Common),
+    (0x1FA6E, Script::Unknown),
+    (0x1FA70, Script::Common),
+    (0x1FA75, Script::Unknown),
+    (0x1FA78, Script::Common),
+    (0x1FA7D, Script::Unknown),
+    (0x1FA80, Script::Common),
+    (0x1FA87, Script::Unknown),
+    (0x1FA90, Script::Common),
+    (0x1FAAD, Script::Unknown),
+    (0x1FAB0, Script::Common),
+    (0x1FABB, Script::Unknown),
+    (0x1FAC0, Script::Common),
+    (0x1FAC6, Script::Unknown),
+    (0x1FAD0, Script::Common),
+    (0x1FADA, Script::Unknown),
+    (0x1FAE0, Script::Common),
+    (0x1FAE8, Script::Unknown),
+    (0x1FAF0, Script::Common),
+    (0x1FAF7, Script::Unknown),
+    (0x1FB00, Script::Common),
+    (0x1FB93, Script::Unknown),
+    (0x1FB94, Script::Common),
+    (0x1FBCB, Script::Unknown),
+    (0x1FBF0, Script::Common),
+    (0x1FBFA, Script::Unknown),
+    (0x20000, Script::Han),
+    (0x2A6E0, Script::Unknown),
+    (0x2A700, Script::Han),
+    (0x2B739, Script::Unknown),
+    (0x2B740, Script::Han),
+    (0x2B81E, Script::Unknown),
+    (0x2B820, Script::Han),
+    (0x2CEA2, Script::Unknown),
+    (0x2CEB0, Script::Han),
+    (0x2EBE1, Script::Unknown),
+    (0x2F800, Script::Han),
+    (0x2FA1E, Script::Unknown),
+    (0x30000, Script::Han),
+    (0x3134B, Script::Unknown),
+    (0xE0001, Script::Common),
+    (0xE0002, Script::Unknown),
+    (0xE0020, Script::Common),
+    (0xE0080, Script::Unknown),
+    (0xE0100, Script::Inherited),
+    (0xE01F0, Script::Unknown),
+    (0x110000, Script::Unknown),
+];